@@ -0,0 +1,121 @@
+//! Bloom filters.
+//!
+//! [`BloomFilter`] derives all `k` bit indices of an item from a single
+//! [`Digest`] invocation using the Kirsch–Mitzenmacher double hashing
+//! technique: the first 16 bytes of the hash are split into two 64-bit
+//! values `h1` and `h2`, and the `i`-th index is equal to
+//! `(h1 + i * h2) mod m`.
+//!
+//! # Example
+//! ```
+//! use digest::bloom::BloomFilter;
+//! use sha2::Sha256;
+//!
+//! let mut filter = BloomFilter::<Sha256>::new(1 << 16, 7);
+//! filter.insert(b"foo");
+//! filter.insert(b"bar");
+//! assert!(filter.contains(b"foo"));
+//! assert!(filter.contains(b"bar"));
+//! ```
+
+use crate::Digest;
+use alloc::{vec, vec::Vec};
+use core::{fmt, marker::PhantomData};
+
+/// Bloom filter with `m` bits and `k` indices per item computed using `D`.
+///
+/// Filter never reports false negatives, i.e. [`BloomFilter::contains`]
+/// always returns `true` for inserted items. For a filter containing `n`
+/// items the false positive probability is approximately
+/// `(1 - exp(-k * n / m))^k`.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct BloomFilter<D: Digest> {
+    bits: Vec<u64>,
+    m: u64,
+    k: u32,
+    _pd: PhantomData<fn() -> D>,
+}
+
+impl<D: Digest> BloomFilter<D> {
+    /// Create new empty filter with `m` bits and `k` indices per item.
+    ///
+    /// # Panics
+    /// If `m` or `k` is equal to zero, or if output size of `D` is smaller
+    /// than 16 bytes.
+    pub fn new(m: usize, k: u32) -> Self {
+        assert!(m != 0, "number of bits must not be zero");
+        assert!(k != 0, "number of indices must not be zero");
+        assert!(
+            D::output_size() >= 16,
+            "hash output size must be at least 16 bytes"
+        );
+        let rem = m % 64;
+        let words = m / 64 + (rem != 0) as usize;
+        Self {
+            bits: vec![0; words],
+            m: m as u64,
+            k,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Number of bits in the filter.
+    pub fn m(&self) -> usize {
+        self.m as usize
+    }
+
+    /// Number of indices computed for each item.
+    pub fn k(&self) -> u32 {
+        self.k
+    }
+
+    /// Insert `item` into the filter.
+    pub fn insert(&mut self, item: &[u8]) {
+        let (h1, h2) = Self::hash(item);
+        for i in 0..self.k {
+            let idx = self.index(h1, h2, i);
+            self.bits[idx / 64] |= 1 << (idx % 64);
+        }
+    }
+
+    /// Check whether `item` may have been inserted into the filter.
+    ///
+    /// Returns `false` only if `item` was definitely not inserted.
+    pub fn contains(&self, item: &[u8]) -> bool {
+        let (h1, h2) = Self::hash(item);
+        (0..self.k).all(|i| {
+            let idx = self.index(h1, h2, i);
+            self.bits[idx / 64] & (1 << (idx % 64)) != 0
+        })
+    }
+
+    /// Remove all items from the filter.
+    pub fn clear(&mut self) {
+        for w in self.bits.iter_mut() {
+            *w = 0;
+        }
+    }
+
+    fn hash(item: &[u8]) -> (u64, u64) {
+        let hash = D::digest(item);
+        let mut h1 = [0u8; 8];
+        let mut h2 = [0u8; 8];
+        h1.copy_from_slice(&hash[..8]);
+        h2.copy_from_slice(&hash[8..16]);
+        (u64::from_le_bytes(h1), u64::from_le_bytes(h2))
+    }
+
+    fn index(&self, h1: u64, h2: u64, i: u32) -> usize {
+        (h1.wrapping_add(u64::from(i).wrapping_mul(h2)) % self.m) as usize
+    }
+}
+
+impl<D: Digest> fmt::Debug for BloomFilter<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BloomFilter")
+            .field("m", &self.m)
+            .field("k", &self.k)
+            .finish()
+    }
+}
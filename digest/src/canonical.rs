@@ -0,0 +1,188 @@
+use crate::{Digest, Output, Update};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+/// Types which define a canonical byte encoding used for hashing.
+///
+/// The encoding must be deterministic and unambiguous, i.e. different values
+/// should produce different byte sequences, including when values are
+/// concatenated. Helper methods of [`Update`] (e.g.
+/// [`update_with_len`][Update::update_with_len] and `update_u*_le`) can be
+/// used for constructing it.
+///
+/// Implementations provided by this crate use the following encoding:
+/// - integers are encoded in little-endian byte order, `usize` and `isize`
+///   are extended to 64 bits;
+/// - `bool` is encoded as one byte equal to 0 or 1;
+/// - slices, `str` (and `Vec`, `String` if `alloc` feature is enabled)
+///   are encoded as number of elements encoded as `u64` followed by
+///   encodings of the elements;
+/// - `Option` is encoded as 0 for `None` or 1 followed by the value encoding
+///   for `Some`;
+/// - tuples are encoded as concatenation of their elements' encodings;
+/// - references (and `Box` if `alloc` feature is enabled) are encoded as
+///   the referenced value.
+///
+/// # Example
+/// ```
+/// use digest::{HashCanonical, Update};
+///
+/// struct Record {
+///     id: u64,
+///     name: &'static str,
+/// }
+///
+/// impl HashCanonical for Record {
+///     fn update_canonical<U: Update>(&self, state: &mut U) {
+///         self.id.update_canonical(state);
+///         self.name.update_canonical(state);
+///     }
+/// }
+/// ```
+///
+/// # Serialization formats
+///
+/// This crate intentionally does not integrate with `serde` data formats:
+/// their releases would become part of its public API and require a newer
+/// compiler than its MSRV. Hashers implement `std::io::Write` if the `std`
+/// feature is enabled, so writer-based serializers (e.g. `postcard::to_io`)
+/// can be used for streaming encoding of a value into a hasher.
+///
+/// Deterministic encodings which sort map entries by their encoded keys
+/// (e.g. RFC 8949 canonical CBOR used by COSE) can not be streamed, so
+/// such values should be encoded into a buffer by a dedicated encoder and
+/// the buffer hashed afterwards.
+pub trait HashCanonical {
+    /// Update `state` with the canonical encoding of `self`.
+    fn update_canonical<U: Update>(&self, state: &mut U);
+
+    /// Compute hash of the canonical encoding of `self`.
+    fn hash_canonical<D: Digest>(&self) -> Output<D> {
+        let mut hasher = D::new();
+        self.update_canonical(&mut DigestUpdate(&mut hasher));
+        hasher.finalize()
+    }
+}
+
+/// Adapter which implements [`Update`] for [`Digest`] implementors.
+struct DigestUpdate<'a, D>(&'a mut D);
+
+impl<D: Digest> Update for DigestUpdate<'_, D> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(self.0, data);
+    }
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl HashCanonical for $t {
+                #[inline]
+                fn update_canonical<U: Update>(&self, state: &mut U) {
+                    state.update(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl HashCanonical for usize {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        state.update_u64_le(*self as u64);
+    }
+}
+
+impl HashCanonical for isize {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        (*self as i64).update_canonical(state);
+    }
+}
+
+impl HashCanonical for bool {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        state.update_u8(*self as u8);
+    }
+}
+
+impl HashCanonical for str {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        state.update_with_len(self.as_bytes());
+    }
+}
+
+impl<T: HashCanonical> HashCanonical for [T] {
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        state.update_u64_le(self.len() as u64);
+        for v in self {
+            v.update_canonical(state);
+        }
+    }
+}
+
+impl<T: HashCanonical> HashCanonical for Option<T> {
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        match self {
+            None => state.update_u8(0),
+            Some(v) => {
+                state.update_u8(1);
+                v.update_canonical(state);
+            }
+        }
+    }
+}
+
+impl<T: HashCanonical + ?Sized> HashCanonical for &T {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        (**self).update_canonical(state);
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: HashCanonical),+> HashCanonical for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn update_canonical<U: Update>(&self, state: &mut U) {
+                let ($($name,)+) = self;
+                $($name.update_canonical(state);)+
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+
+#[cfg(feature = "alloc")]
+impl HashCanonical for String {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        self.as_str().update_canonical(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: HashCanonical> HashCanonical for Vec<T> {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        self.as_slice().update_canonical(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: HashCanonical + ?Sized> HashCanonical for Box<T> {
+    #[inline]
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        (**self).update_canonical(state);
+    }
+}
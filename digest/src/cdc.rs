@@ -0,0 +1,160 @@
+//! Content-defined chunking.
+//!
+//! [`Chunker`] splits a byte stream into variable-size chunks with boundaries
+//! determined by the content (using the Gear rolling hash), so inserting or
+//! removing data affects only the neighbouring chunks. Each chunk is hashed
+//! with a [`Digest`] to obtain its identity, e.g. for deduplication.
+//!
+//! # Example
+//! ```
+//! use digest::cdc::Chunker;
+//! use sha2::{Digest, Sha256};
+//!
+//! let data = vec![42u8; 100_000];
+//! let mut chunker = Chunker::<Sha256>::new(1024, 4096, 16384);
+//! chunker.update(&data[..30_000]);
+//! chunker.update(&data[30_000..]);
+//! let chunks = chunker.finalize();
+//!
+//! let mut start = 0;
+//! for (end, hash) in chunks {
+//!     let end = end as usize;
+//!     assert_eq!(hash, Sha256::digest(&data[start..end]));
+//!     start = end;
+//! }
+//! assert_eq!(start, data.len());
+//! ```
+
+use crate::Digest;
+use alloc::vec::Vec;
+use core::fmt;
+use crypto_common::Output;
+
+/// Content-defined chunker which hashes chunks using `D`.
+///
+/// Chunks are reported as `(boundary_offset, hash)` pairs, where
+/// `boundary_offset` is the offset in the stream at which the chunk ends
+/// (i.e. the offset at which the next chunk starts) and `hash` is the hash
+/// of the chunk data computed with `D`.
+///
+/// Boundaries depend only on the stream content and chunker parameters,
+/// not on how the stream was split between calls to [`Chunker::update`].
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct Chunker<D: Digest> {
+    gear: [u64; 256],
+    mask: u64,
+    min_size: u64,
+    max_size: u64,
+    hasher: D,
+    rolling: u64,
+    chunk_len: u64,
+    offset: u64,
+    chunks: Vec<(u64, Output<D>)>,
+}
+
+impl<D: Digest> Chunker<D> {
+    /// Create new chunker producing chunks of `min_size..=max_size` bytes with
+    /// average size of approximately `avg_size` bytes.
+    ///
+    /// # Panics
+    /// If `avg_size` is not a power of two or if the
+    /// `0 < min_size <= avg_size <= max_size` condition does not hold.
+    pub fn new(min_size: usize, avg_size: usize, max_size: usize) -> Self {
+        assert!(
+            avg_size.is_power_of_two(),
+            "avg_size must be a power of two"
+        );
+        assert!(
+            0 < min_size && min_size <= avg_size && avg_size <= max_size,
+            "chunk sizes must satisfy 0 < min_size <= avg_size <= max_size",
+        );
+        let mut gear = [0u64; 256];
+        let mut x = 0u64;
+        for g in gear.iter_mut() {
+            // splitmix64
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            *g = z ^ (z >> 31);
+        }
+        Self {
+            gear,
+            // use the most significant bits, since lower bits of the Gear
+            // hash depend only on the last few bytes
+            mask: !(!0u64 >> avg_size.trailing_zeros()),
+            min_size: min_size as u64,
+            max_size: max_size as u64,
+            hasher: D::new(),
+            rolling: 0,
+            chunk_len: 0,
+            offset: 0,
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Process the next part of the stream.
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            match self.find_boundary(data) {
+                Some(n) => {
+                    self.hasher.update(&data[..n]);
+                    self.offset += n as u64;
+                    self.finish_chunk();
+                    data = &data[n..];
+                }
+                None => {
+                    self.hasher.update(data);
+                    self.offset += data.len() as u64;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Take chunks completed so far.
+    ///
+    /// Returned chunks are not included in the result of following calls
+    /// of this method and of [`Chunker::finalize`].
+    pub fn take_chunks(&mut self) -> Vec<(u64, Output<D>)> {
+        core::mem::take(&mut self.chunks)
+    }
+
+    /// Finish the stream and return all chunks which were not taken with
+    /// [`Chunker::take_chunks`], including the last (possibly shorter
+    /// than `min_size`) chunk.
+    ///
+    /// An empty stream does not produce any chunks.
+    pub fn finalize(mut self) -> Vec<(u64, Output<D>)> {
+        if self.chunk_len != 0 {
+            self.finish_chunk();
+        }
+        self.chunks
+    }
+
+    /// Find length of the `data` prefix which completes the current chunk.
+    fn find_boundary(&mut self, data: &[u8]) -> Option<usize> {
+        for (i, &b) in data.iter().enumerate() {
+            self.rolling = (self.rolling << 1).wrapping_add(self.gear[b as usize]);
+            self.chunk_len += 1;
+            let cut = self.chunk_len >= self.min_size && self.rolling & self.mask == 0;
+            if cut || self.chunk_len == self.max_size {
+                return Some(i + 1);
+            }
+        }
+        None
+    }
+
+    fn finish_chunk(&mut self) {
+        let hash = core::mem::replace(&mut self.hasher, D::new()).finalize();
+        self.chunks.push((self.offset, hash));
+        self.rolling = 0;
+        self.chunk_len = 0;
+    }
+}
+
+impl<D: Digest> fmt::Debug for Chunker<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Chunker { .. }")
+    }
+}
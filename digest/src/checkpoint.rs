@@ -0,0 +1,101 @@
+use crate::{FixedOutput, Update};
+use alloc::vec::Vec;
+use crypto_common::{Output, OutputSizeUser};
+
+/// Wrapper around [`Update`] implementors which records copies of the
+/// hasher state every `interval` bytes.
+///
+/// Recorded checkpoints allow to resume hashing from an earlier position
+/// without re-processing all preceding data, e.g. for verification of
+/// a partially re-downloaded file. The initial state is recorded as
+/// a checkpoint at offset 0.
+///
+/// Checkpoints are stored in memory as clones of the hasher, so memory
+/// usage grows linearly with amount of processed data.
+#[derive(Clone, Debug)]
+pub struct CheckpointingHasher<D> {
+    hasher: D,
+    interval: u64,
+    pos: u64,
+    checkpoints: Vec<(u64, D)>,
+}
+
+impl<D: Clone> CheckpointingHasher<D> {
+    /// Create new wrapper around `hasher` which records a checkpoint
+    /// every `interval` bytes.
+    ///
+    /// # Panics
+    /// If `interval` is equal to zero.
+    pub fn new(hasher: D, interval: u64) -> Self {
+        assert!(interval != 0, "checkpoint interval must not be zero");
+        let checkpoints = vec![(0, hasher.clone())];
+        Self {
+            hasher,
+            interval,
+            pos: 0,
+            checkpoints,
+        }
+    }
+
+    /// Get number of bytes processed by the hasher.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Get recorded checkpoints as `(offset, state)` pairs sorted by offset.
+    #[inline]
+    pub fn checkpoints(&self) -> &[(u64, D)] {
+        &self.checkpoints
+    }
+
+    /// Rewind hasher to the last checkpoint at or before `offset` and
+    /// return offset of the checkpoint.
+    ///
+    /// Checkpoints after the returned offset are discarded. Data starting
+    /// from the returned offset must be fed to the hasher again.
+    pub fn resume_from(&mut self, offset: u64) -> u64 {
+        let i = match self.checkpoints.binary_search_by_key(&offset, |c| c.0) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        self.checkpoints.truncate(i + 1);
+        let (pos, state) = &self.checkpoints[i];
+        self.pos = *pos;
+        self.hasher = state.clone();
+        self.pos
+    }
+
+    /// Get the wrapped hasher and consume the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.hasher
+    }
+}
+
+impl<D: Update + Clone> Update for CheckpointingHasher<D> {
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let to_next = self.interval - self.pos % self.interval;
+            let n = core::cmp::min(to_next, data.len() as u64) as usize;
+            let (head, tail) = data.split_at(n);
+            self.hasher.update(head);
+            self.pos += n as u64;
+            data = tail;
+            if n as u64 == to_next {
+                self.checkpoints.push((self.pos, self.hasher.clone()));
+            }
+        }
+    }
+}
+
+impl<D: OutputSizeUser> OutputSizeUser for CheckpointingHasher<D> {
+    type OutputSize = D::OutputSize;
+}
+
+impl<D: FixedOutput + Clone> FixedOutput for CheckpointingHasher<D> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.hasher.finalize_into(out);
+    }
+}
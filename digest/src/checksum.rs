@@ -0,0 +1,132 @@
+use crate::{encoding::Alphabet, Digest};
+use crypto_common::Output;
+use std::{
+    fs::File,
+    io,
+    path::Path,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Verify files listed in the checksum file at `checksum_path`, similarly
+/// to `sha256sum -c`.
+///
+/// Both GNU coreutils (`<hex>  <filename>`, or `<hex> *<filename>` for
+/// binary mode) and BSD (`<ALGO> (<filename>) = <hex>`) line formats are
+/// supported, including escaping of backslashes and newlines in file names
+/// marked by a leading backslash. Empty lines are ignored. Hex digests are
+/// case-insensitive and the algorithm name in BSD lines is not checked.
+///
+/// File names are resolved relative to `base_dir`. For each entry the
+/// function returns the (unescaped) file name and whether the file hash
+/// computed with `D` matches the listed one. Hashes are compared in
+/// constant time. Missing files are reported as failed entries.
+///
+/// Returns an error of kind [`InvalidData`][io::ErrorKind::InvalidData]
+/// if the checksum file contains an improperly formatted line or a digest
+/// with length not equal to output size of `D`. Other I/O errors
+/// encountered while reading the checksum file or the listed files
+/// are propagated.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn verify_checksum_file<D, P, B>(
+    checksum_path: P,
+    base_dir: B,
+) -> io::Result<Vec<(String, bool)>>
+where
+    D: Digest,
+    P: AsRef<Path>,
+    B: AsRef<Path>,
+{
+    let checksums = std::fs::read_to_string(checksum_path)?;
+    let mut res = Vec::new();
+    for (i, line) in checksums.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                std::format!("improperly formatted checksum line {}", i + 1),
+            )
+        };
+        let (hex, name) = parse_line(line).ok_or_else(invalid)?;
+        let mut expected = Output::<D>::default();
+        if Alphabet::Hex.decode(hex.as_bytes(), &mut expected, false) != 1 {
+            return Err(invalid());
+        }
+
+        let file = match File::open(base_dir.as_ref().join(&name)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                res.push((name, false));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let hash = D::digest_reader(file)?;
+        let diff = hash
+            .iter()
+            .zip(expected.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        res.push((name, diff == 0));
+    }
+    Ok(res)
+}
+
+/// Parse checksum line into hex digest and file name.
+fn parse_line(line: &str) -> Option<(&str, String)> {
+    let escaped = line.starts_with('\\');
+    let line = if escaped { &line[1..] } else { line };
+    let (hex, name) = parse_gnu(line).or_else(|| parse_bsd(line))?;
+    if name.is_empty() {
+        return None;
+    }
+    let name = if escaped {
+        unescape(name)?
+    } else {
+        name.to_string()
+    };
+    Some((hex, name))
+}
+
+/// Parse `<hex>  <filename>` or `<hex> *<filename>`.
+fn parse_gnu(line: &str) -> Option<(&str, &str)> {
+    let pos = line.find(' ')?;
+    let (hex, rest) = line.split_at(pos);
+    if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    if rest.starts_with("  ") || rest.starts_with(" *") {
+        Some((hex, &rest[2..]))
+    } else {
+        None
+    }
+}
+
+/// Parse `<ALGO> (<filename>) = <hex>`.
+fn parse_bsd(line: &str) -> Option<(&str, &str)> {
+    let start = line.find(" (")?;
+    let end = line.rfind(") = ")?;
+    if start == 0 || end < start + 2 || line[..start].contains(' ') {
+        return None;
+    }
+    Some((&line[end + 4..], &line[start + 2..end]))
+}
+
+/// Unescape `\\` and `\n` sequences.
+fn unescape(name: &str) -> Option<String> {
+    let mut res = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next()? {
+                '\\' => res.push('\\'),
+                'n' => res.push('\n'),
+                _ => return None,
+            }
+        } else {
+            res.push(c);
+        }
+    }
+    Some(res)
+}
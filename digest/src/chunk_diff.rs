@@ -0,0 +1,27 @@
+use crate::Digest;
+use alloc::vec::Vec;
+use crypto_common::Output;
+
+/// Compute digests of consecutive `chunk`-sized pieces of `data`.
+///
+/// The last piece may be shorter than `chunk`. Empty `data` produces an
+/// empty vector.
+///
+/// # Panics
+/// If `chunk` is equal to zero.
+pub fn chunk_digests<D: Digest>(data: &[u8], chunk: usize) -> Vec<Output<D>> {
+    assert!(chunk != 0, "chunk size must not be zero");
+    data.chunks(chunk).map(D::digest).collect()
+}
+
+/// Return indices of chunks which differ between `a` and `b`.
+///
+/// Indices are returned in ascending order. If one of the slices is
+/// longer, all its trailing indices are considered different.
+///
+/// Comparison is not performed in constant time, so this function should
+/// not be used for verification of secret values.
+pub fn diff_chunks<T: PartialEq>(a: &[T], b: &[T]) -> Vec<usize> {
+    let n = core::cmp::max(a.len(), b.len());
+    (0..n).filter(|&i| a.get(i) != b.get(i)).collect()
+}
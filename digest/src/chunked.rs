@@ -0,0 +1,114 @@
+use crate::{FixedOutput, FixedOutputReset, Reset, Update};
+use core::fmt;
+use crypto_common::{Output, OutputSizeUser};
+
+/// Wrapper around [`Update`] implementors which splits data into chunks
+/// of bounded size.
+///
+/// Every [`Update::update`] call is forwarded to the inner type as a number
+/// of calls with at most `chunk_size` bytes each. An optional callback is
+/// invoked between chunks of the same call, e.g. to cooperatively yield
+/// or to poll for cancellation while hashing huge buffers.
+///
+/// Splitting does not affect hashing results.
+#[derive(Clone)]
+pub struct ChunkedUpdate<U, F = fn()> {
+    inner: U,
+    chunk_size: usize,
+    callback: F,
+}
+
+impl<U> ChunkedUpdate<U> {
+    /// Create new wrapper around `inner` without a callback.
+    ///
+    /// # Panics
+    /// If `chunk_size` is equal to zero.
+    #[inline]
+    pub fn new(inner: U, chunk_size: usize) -> Self {
+        fn noop() {}
+        Self::with_callback(inner, chunk_size, noop)
+    }
+}
+
+impl<U, F: FnMut()> ChunkedUpdate<U, F> {
+    /// Create new wrapper around `inner` which invokes `callback` between
+    /// chunks.
+    ///
+    /// # Panics
+    /// If `chunk_size` is equal to zero.
+    #[inline]
+    pub fn with_callback(inner: U, chunk_size: usize, callback: F) -> Self {
+        assert!(chunk_size != 0, "chunk size must not be zero");
+        Self {
+            inner,
+            chunk_size,
+            callback,
+        }
+    }
+
+    /// Get maximum size of chunks passed to the inner type.
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Get reference to the inner type.
+    #[inline]
+    pub fn get_ref(&self) -> &U {
+        &self.inner
+    }
+
+    /// Get the inner type and consume the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> U {
+        self.inner
+    }
+}
+
+impl<U: Update, F: FnMut()> Update for ChunkedUpdate<U, F> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        let mut chunks = data.chunks(self.chunk_size);
+        if let Some(chunk) = chunks.next() {
+            self.inner.update(chunk);
+        }
+        for chunk in chunks {
+            (self.callback)();
+            self.inner.update(chunk);
+        }
+    }
+}
+
+impl<U: Reset, F> Reset for ChunkedUpdate<U, F> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<U: OutputSizeUser, F> OutputSizeUser for ChunkedUpdate<U, F> {
+    type OutputSize = U::OutputSize;
+}
+
+impl<U: FixedOutput, F: FnMut()> FixedOutput for ChunkedUpdate<U, F> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.inner.finalize_into(out);
+    }
+}
+
+impl<U: FixedOutputReset, F: FnMut()> FixedOutputReset for ChunkedUpdate<U, F> {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.inner.finalize_into_reset(out);
+    }
+}
+
+impl<U: fmt::Debug, F> fmt::Debug for ChunkedUpdate<U, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkedUpdate")
+            .field("inner", &self.inner)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
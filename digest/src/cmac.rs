@@ -0,0 +1,47 @@
+use generic_array::{ArrayLength, GenericArray};
+
+/// Derive CMAC subkeys `K1` and `K2` from `l`, the block cipher output for
+/// the all-zero block, as defined in [NIST SP 800-38B] and [RFC 4493].
+///
+/// `K1` is equal to `l` doubled in GF(2^n) and `K2` is equal to `K1`
+/// doubled. Doubling is performed in constant time using the `R_b`
+/// constant for the given block size (i.e. the lexicographically first
+/// minimal polynomial).
+///
+/// # Panics
+/// If block size is not equal to 8, 16, 32, or 64 bytes.
+///
+/// [NIST SP 800-38B]: https://csrc.nist.gov/publications/detail/sp/800-38b/final
+/// [RFC 4493]: https://tools.ietf.org/html/rfc4493#section-2.3
+pub fn cmac_subkeys<N: ArrayLength<u8>>(
+    l: &GenericArray<u8, N>,
+) -> (GenericArray<u8, N>, GenericArray<u8, N>) {
+    let mut k1 = l.clone();
+    dbl(&mut k1);
+    let mut k2 = k1.clone();
+    dbl(&mut k2);
+    (k1, k2)
+}
+
+/// Multiply big-endian `block` by `x` in GF(2^n).
+pub(crate) fn dbl(block: &mut [u8]) {
+    let poly: &[u8] = match block.len() {
+        8 => &[0x1b],
+        16 => &[0x87],
+        32 => &[0x04, 0x25],
+        64 => &[0x01, 0x25],
+        n => panic!("unsupported block size: {}", n),
+    };
+    let carry = block[0] >> 7;
+    for i in 0..block.len() - 1 {
+        block[i] = (block[i] << 1) | (block[i + 1] >> 7);
+    }
+    let last = block.len() - 1;
+    block[last] <<= 1;
+    // constant-time conditional reduction
+    let mask = 0u8.wrapping_sub(carry);
+    let off = block.len() - poly.len();
+    for (b, p) in block[off..].iter_mut().zip(poly) {
+        *b ^= p & mask;
+    }
+}
@@ -0,0 +1,75 @@
+//! Commitment scheme built on top of extendable-output functions.
+//!
+//! A commitment to `msg` is computed as
+//! `XOF(prefix || len(c) || len(r) || r || msg)` truncated to `len(c)` bytes,
+//! where `prefix` is a fixed domain-separation string, `c` is the commitment,
+//! `r` is secret randomness chosen by the committer, and lengths are encoded
+//! in bytes as 64-bit little-endian integers. Binding of `len(c)` ensures
+//! that truncated commitments are rejected. The commitment can be
+//! published, while `(r, msg)` is kept secret until the reveal phase, after
+//! which anyone can check it using [`verify`].
+//!
+//! Hiding property of the scheme depends on `r`, so it must be generated
+//! using a cryptographically secure random number generator and should be
+//! at least 32 bytes long.
+
+use crate::{ExtendableOutput, XofReader};
+use alloc::boxed::Box;
+use subtle::ConstantTimeEq;
+
+/// Domain-separation prefix absorbed before commitment inputs.
+pub const DOMAIN_PREFIX: &[u8] = b"RustCrypto digest commitment v1\x00";
+
+fn reader<X: ExtendableOutput + Default>(
+    randomness: &[u8],
+    msg: &[u8],
+    out_len: usize,
+) -> X::Reader {
+    let mut xof = X::default();
+    xof.update(DOMAIN_PREFIX);
+    xof.update(&(out_len as u64).to_le_bytes());
+    xof.update(&(randomness.len() as u64).to_le_bytes());
+    xof.update(randomness);
+    xof.update(msg);
+    xof.finalize_xof()
+}
+
+/// Commit to `msg` using `randomness` and get `out_len` bytes of commitment.
+///
+/// # Panics
+/// If `out_len` is equal to zero.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "mac"))))]
+pub fn commit<X: ExtendableOutput + Default>(
+    randomness: &[u8],
+    msg: &[u8],
+    out_len: usize,
+) -> Box<[u8]> {
+    assert!(out_len != 0, "commitment length must not be zero");
+    let mut buf = vec![0u8; out_len].into_boxed_slice();
+    reader::<X>(randomness, msg, out_len).read(&mut buf);
+    buf
+}
+
+/// Check that `commitment` was computed over revealed `randomness` and `msg`.
+///
+/// Comparison is performed in constant time. Empty commitments are
+/// always rejected.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "mac"))))]
+pub fn verify<X: ExtendableOutput + Default>(
+    randomness: &[u8],
+    msg: &[u8],
+    commitment: &[u8],
+) -> bool {
+    if commitment.is_empty() {
+        return false;
+    }
+    let mut reader = reader::<X>(randomness, msg, commitment.len());
+    let mut buf = [0u8; 64];
+    let mut res = subtle::Choice::from(1);
+    for chunk in commitment.chunks(buf.len()) {
+        let buf = &mut buf[..chunk.len()];
+        reader.read(buf);
+        res &= buf.ct_eq(chunk);
+    }
+    res.into()
+}
@@ -0,0 +1,100 @@
+use crate::{FixedOutput, FixedOutputReset, HashMarker, Reset, Update};
+use core::ops::Add;
+use crypto_common::{Output, OutputSizeUser};
+use generic_array::{
+    typenum::{Sum, Unsigned},
+    ArrayLength,
+};
+
+/// Combiner of two hash functions which outputs concatenation of their
+/// results, i.e. `A(x) || B(x)`.
+///
+/// The resulting hash function is collision resistant as long as at least
+/// one of the combined functions is, which makes it useful as a robustness
+/// measure, e.g. during transition from one hash function to another.
+///
+/// ```
+/// use digest::{ConcatHash, Digest};
+/// use sha2::{Sha256, Sha512};
+///
+/// let res = ConcatHash::<Sha256, Sha512>::digest(b"data");
+/// assert_eq!(&res[..32], &Sha256::digest(b"data")[..]);
+/// assert_eq!(&res[32..], &Sha512::digest(b"data")[..]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ConcatHash<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> ConcatHash<A, B> {
+    /// Create new combiner from two hashers.
+    #[inline]
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+
+    /// Get the combined hashers and consume the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A: Update, B: Update> Update for ConcatHash<A, B> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.a.update(data);
+        self.b.update(data);
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for ConcatHash<A, B> {
+    #[inline]
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+    }
+}
+
+impl<A, B> OutputSizeUser for ConcatHash<A, B>
+where
+    A: OutputSizeUser,
+    B: OutputSizeUser,
+    A::OutputSize: Add<B::OutputSize>,
+    Sum<A::OutputSize, B::OutputSize>: ArrayLength<u8> + 'static,
+{
+    type OutputSize = Sum<A::OutputSize, B::OutputSize>;
+}
+
+impl<A, B> FixedOutput for ConcatHash<A, B>
+where
+    A: FixedOutput,
+    B: FixedOutput,
+    A::OutputSize: Add<B::OutputSize>,
+    Sum<A::OutputSize, B::OutputSize>: ArrayLength<u8> + 'static,
+{
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let (a_out, b_out) = out.split_at_mut(A::OutputSize::USIZE);
+        a_out.copy_from_slice(&self.a.finalize_fixed());
+        b_out.copy_from_slice(&self.b.finalize_fixed());
+    }
+}
+
+impl<A, B> FixedOutputReset for ConcatHash<A, B>
+where
+    A: FixedOutputReset,
+    B: FixedOutputReset,
+    A::OutputSize: Add<B::OutputSize>,
+    Sum<A::OutputSize, B::OutputSize>: ArrayLength<u8> + 'static,
+{
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let (a_out, b_out) = out.split_at_mut(A::OutputSize::USIZE);
+        a_out.copy_from_slice(&self.a.finalize_fixed_reset());
+        b_out.copy_from_slice(&self.b.finalize_fixed_reset());
+    }
+}
+
+impl<A: HashMarker, B: HashMarker> HashMarker for ConcatHash<A, B> {}
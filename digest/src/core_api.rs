@@ -5,18 +5,23 @@
 //! higher-level traits.
 use crate::InvalidOutputSize;
 use generic_array::typenum::{IsLess, Le, NonZero, U256};
+use generic_array::{ArrayLength, GenericArray};
 
 pub use crypto_common::{AlgorithmName, Block, BlockSizeUser, OutputSizeUser, Reset};
 
-use block_buffer::{BlockBuffer, BufferKind};
+use block_buffer::{BlockBuffer, BufferKind, EagerBuffer};
 use crypto_common::Output;
 
 mod ct_variable;
+mod dirty_guard;
+mod md_padding;
 mod rt_variable;
 mod wrapper;
 mod xof_reader;
 
 pub use ct_variable::CtVariableCoreWrapper;
+pub use dirty_guard::DirtyGuard;
+pub use md_padding::{md_pad_len, write_md_padding};
 pub use rt_variable::RtVariableCoreWrapper;
 pub use wrapper::{CoreProxy, CoreWrapper};
 pub use xof_reader::XofReaderCoreWrapper;
@@ -29,6 +34,21 @@ pub type Buffer<S> =
 pub trait UpdateCore: BlockSizeUser {
     /// Update state using the provided data blocks.
     fn update_blocks(&mut self, blocks: &[Block<Self>]);
+
+    /// Update state using a bulk of data blocks.
+    ///
+    /// This method is the entry point used by the buffering wrappers defined
+    /// in this module. The resulting state must be identical to calling
+    /// [`update_blocks`][UpdateCore::update_blocks] with the same blocks,
+    /// but implementations may process several blocks at once when it's
+    /// beneficial (e.g. using multi-block SIMD compression or interleaving
+    /// independent parts of computation in parallel lanes).
+    ///
+    /// The default implementation delegates to `update_blocks`.
+    #[inline]
+    fn update_blocks_par(&mut self, blocks: &[Block<Self>]) {
+        self.update_blocks(blocks);
+    }
 }
 
 /// Types which use [`BlockBuffer`] functionality.
@@ -46,6 +66,68 @@ where
     /// Finalize state using remaining data stored in the provided block buffer,
     /// write result into provided array and leave `self` in a dirty state.
     fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>);
+
+    /// Write result for data processed so far, including remaining data
+    /// stored in the provided block buffer, into `out` without modifying
+    /// `self` or `buffer`.
+    ///
+    /// The default implementation finalizes copies of the state and
+    /// the buffer, so it's available only for cores which implement
+    /// [`Clone`]. Cores with expensive state copies may override it to copy
+    /// only the parts of the state affected by finalization.
+    #[inline]
+    fn finalize_fixed_core_peek(&self, buffer: &Buffer<Self>, out: &mut Output<Self>)
+    where
+        Self: Clone,
+    {
+        let mut core = self.clone();
+        let mut buffer = buffer.clone();
+        core.finalize_fixed_core(&mut buffer, out);
+    }
+}
+
+/// Extension of [`FixedOutputCore`] for algorithms which produce a side
+/// output (e.g. a tag derived from the capacity part of a sponge state)
+/// in addition to the main hash result.
+///
+/// Most algorithms do not need this trait, so it is kept separate from
+/// [`FixedOutputCore`].
+pub trait FixedOutputCoreExt: FixedOutputCore
+where
+    Self::BlockSize: IsLess<U256>,
+    Le<Self::BlockSize, U256>: NonZero,
+{
+    /// Finalize state using remaining data stored in the provided block buffer,
+    /// write result into `out`, side output into `side` and leave `self`
+    /// in a dirty state.
+    ///
+    /// Supported lengths of `side` are algorithm-specific. Implementations
+    /// may panic if length of `side` is not supported.
+    fn finalize_fixed_core_ext(
+        &mut self,
+        buffer: &mut Buffer<Self>,
+        out: &mut Output<Self>,
+        side: &mut [u8],
+    );
+}
+
+/// Core trait for sponge-based algorithms which are able to produce
+/// an authentication tag derived from the capacity part of their state.
+///
+/// The tag is distinct from the squeezed output and can be used e.g. by
+/// duplex-based authenticated encryption built on top of existing cores.
+pub trait CapacityTag: UpdateCore + BufferKindUser
+where
+    Self::BlockSize: IsLess<U256>,
+    Le<Self::BlockSize, U256>: NonZero,
+{
+    /// Write tag derived from the capacity part of the state after
+    /// processing remaining data stored in `buffer` into `out`.
+    ///
+    /// State of `self` must not be affected, while `buffer` may be left in
+    /// a dirty state. Supported lengths of `out` are algorithm-specific.
+    /// Implementations may panic if length of `out` is not supported.
+    fn capacity_tag(&self, buffer: &mut Buffer<Self>, out: &mut [u8]);
 }
 
 /// Core trait for hash functions with extendable (XOF) output size.
@@ -66,6 +148,18 @@ where
 pub trait XofReaderCore: BlockSizeUser {
     /// Read next XOF block.
     fn read_block(&mut self) -> Block<Self>;
+
+    /// Skip `n` XOF blocks.
+    ///
+    /// The default implementation reads and discards blocks. Cores which
+    /// are able to seek in the output stream (e.g. counter-based ones)
+    /// should override it.
+    #[inline]
+    fn skip_blocks(&mut self, n: u64) {
+        for _ in 0..n {
+            self.read_block();
+        }
+    }
 }
 
 /// Core trait for hash functions with variable output size.
@@ -106,6 +200,64 @@ where
     fn finalize_variable_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>);
 }
 
+/// Trait for types which define a domain-separation byte applied during
+/// finalization.
+///
+/// Some algorithm families (e.g. Keccak-based SHA-3, SHAKE, and cSHAKE)
+/// share the same core and differ only by the suffix byte appended to
+/// message during padding. Instead of duplicating cores, a single core can
+/// be made generic over a type implementing this trait and consult it in
+/// its `finalize_*_core` methods.
+pub trait FinalizationTweak {
+    /// Domain-separation byte appended to message data during finalization.
+    const FINALIZATION_SUFFIX: u8;
+
+    /// Write [`FINALIZATION_SUFFIX`] after remaining data stored in `buffer`,
+    /// pad the rest with zeros and return the resulting block.
+    ///
+    /// Cores are expected to apply any additional algorithm-specific padding
+    /// (e.g. the final bit of the Keccak `pad10*1` rule) to the returned
+    /// block before processing it.
+    ///
+    /// [`FINALIZATION_SUFFIX`]: FinalizationTweak::FINALIZATION_SUFFIX
+    #[inline]
+    fn pad_tweaked<BS>(buffer: &mut EagerBuffer<BS>) -> &mut GenericArray<u8, BS>
+    where
+        BS: ArrayLength<u8> + IsLess<U256>,
+        Le<BS, U256>: NonZero,
+    {
+        let pos = buffer.get_pos();
+        let block = buffer.pad_with_zeros();
+        block[pos] = Self::FINALIZATION_SUFFIX;
+        block
+    }
+}
+
+/// Types which expose their initial state (e.g. IV constants) for
+/// verification against specification.
+///
+/// This trait is purely introspective and is intended for auditing and
+/// debugging purposes. It's not used by the wrappers defined in this module.
+pub trait InitState {
+    /// Return serialized initial state of the algorithm.
+    ///
+    /// Serialization format is algorithm-specific, but it should follow
+    /// the one used by the algorithm specification, e.g. big-endian words
+    /// for SHA-256 as defined in FIPS 180-4 section 5.3.3.
+    fn init_state_bytes() -> &'static [u8];
+}
+
+/// Types which count block compressions performed so far.
+///
+/// This trait is purely diagnostic and is intended for profiling. Cores
+/// usually implement it by incrementing a counter in
+/// [`UpdateCore::update_blocks`] (and [`UpdateCore::update_blocks_par`] if
+/// it's overridden). The counter must not affect the algorithm output.
+pub trait CompressCount: UpdateCore {
+    /// Return number of block compressions performed so far.
+    fn compress_count(&self) -> u64;
+}
+
 /// Type which used for defining truncation side in the [`VariableOutputCore`]
 /// trait.
 #[derive(Copy, Clone, Debug)]
@@ -71,6 +71,11 @@ where
     fn update_blocks(&mut self, blocks: &[Block<Self>]) {
         self.inner.update_blocks(blocks);
     }
+
+    #[inline]
+    fn update_blocks_par(&mut self, blocks: &[Block<Self>]) {
+        self.inner.update_blocks_par(blocks);
+    }
 }
 
 impl<T, OutSize> OutputSizeUser for CtVariableCoreWrapper<T, OutSize>
@@ -0,0 +1,163 @@
+use super::{
+    AlgorithmName, Buffer, BufferKindUser, ExtendableOutputCore, FixedOutputCore, Reset, TruncSide,
+    UpdateCore, VariableOutputCore,
+};
+#[cfg(feature = "mac")]
+use crate::MacMarker;
+use crate::{HashMarker, InvalidOutputSize};
+use core::fmt;
+use crypto_common::{Block, BlockSizeUser, Output, OutputSizeUser};
+use generic_array::typenum::{IsLess, Le, NonZero, U256};
+
+/// Wrapper around core types which catches use of a core in a dirty state.
+///
+/// Finalization methods of core traits leave the core in a dirty state,
+/// i.e. it must be reset before it can be used again. In debug builds this
+/// wrapper tracks whether the inner core was finalized and panics if it's
+/// updated or finalized again without a preceding [`Reset::reset`] call.
+///
+/// In release builds (i.e. without `debug_assertions`) the wrapper does not
+/// store any additional state and simply forwards all calls to the inner core.
+#[derive(Clone, Default)]
+pub struct DirtyGuard<T> {
+    inner: T,
+    #[cfg(debug_assertions)]
+    dirty: bool,
+}
+
+impl<T> DirtyGuard<T> {
+    /// Wrap `inner` core.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            #[cfg(debug_assertions)]
+            dirty: false,
+        }
+    }
+
+    /// Get the inner core and consume the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    #[inline(always)]
+    fn check(&self) {
+        #[cfg(debug_assertions)]
+        assert!(!self.dirty, "core used after finalization without reset");
+    }
+
+    #[inline(always)]
+    fn mark_dirty(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            self.dirty = true;
+        }
+    }
+}
+
+impl<T: HashMarker> HashMarker for DirtyGuard<T> {}
+
+#[cfg(feature = "mac")]
+impl<T: MacMarker> MacMarker for DirtyGuard<T> {}
+
+impl<T: BlockSizeUser> BlockSizeUser for DirtyGuard<T> {
+    type BlockSize = T::BlockSize;
+}
+
+impl<T: BufferKindUser> BufferKindUser for DirtyGuard<T> {
+    type BufferKind = T::BufferKind;
+}
+
+impl<T: OutputSizeUser> OutputSizeUser for DirtyGuard<T> {
+    type OutputSize = T::OutputSize;
+}
+
+impl<T: UpdateCore> UpdateCore for DirtyGuard<T> {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.check();
+        self.inner.update_blocks(blocks);
+    }
+
+    #[inline]
+    fn update_blocks_par(&mut self, blocks: &[Block<Self>]) {
+        self.check();
+        self.inner.update_blocks_par(blocks);
+    }
+}
+
+impl<T: FixedOutputCore> FixedOutputCore for DirtyGuard<T>
+where
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    #[inline]
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        self.check();
+        self.mark_dirty();
+        self.inner.finalize_fixed_core(buffer, out);
+    }
+}
+
+impl<T: ExtendableOutputCore> ExtendableOutputCore for DirtyGuard<T>
+where
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    type ReaderCore = T::ReaderCore;
+
+    #[inline]
+    fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+        self.check();
+        self.mark_dirty();
+        self.inner.finalize_xof_core(buffer)
+    }
+}
+
+impl<T: VariableOutputCore> VariableOutputCore for DirtyGuard<T>
+where
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    const TRUNC_SIDE: TruncSide = T::TRUNC_SIDE;
+
+    #[inline]
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        T::new(output_size).map(Self::new)
+    }
+
+    #[inline]
+    fn finalize_variable_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        self.check();
+        self.mark_dirty();
+        self.inner.finalize_variable_core(buffer, out);
+    }
+}
+
+impl<T: Reset> Reset for DirtyGuard<T> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+        #[cfg(debug_assertions)]
+        {
+            self.dirty = false;
+        }
+    }
+}
+
+impl<T: AlgorithmName> AlgorithmName for DirtyGuard<T> {
+    #[inline]
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::write_alg_name(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DirtyGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DirtyGuard<")?;
+        self.inner.fmt(f)?;
+        f.write_str(">")
+    }
+}
@@ -0,0 +1,47 @@
+/// Compute number of zero bytes used by Merkle–Damgård padding of
+/// a message with length of `msg_len` bytes.
+///
+/// The padding consists of the `0x80` byte, the returned number of zero
+/// bytes and a big-endian message length field of `len_field` bytes, such
+/// that the padded message length is a multiple of `block_size`.
+///
+/// # Panics
+/// If `block_size` is equal to zero.
+#[inline]
+pub fn md_pad_len(msg_len: usize, block_size: usize, len_field: usize) -> usize {
+    assert!(block_size != 0, "block size must not be zero");
+    let used = (msg_len % block_size + 1 + len_field % block_size) % block_size;
+    (block_size - used) % block_size
+}
+
+/// Write Merkle–Damgård padding of a message with length of `msg_len`
+/// bytes into `out` and return number of written bytes.
+///
+/// The padding consists of the `0x80` byte, zero bytes computed using
+/// [`md_pad_len`] and message length in bits encoded as a big-endian
+/// integer in `len_field` bytes (e.g. 8 for SHA-256 and 16 for SHA-512).
+///
+/// # Panics
+/// If `block_size` is equal to zero, `len_field` is bigger than 16 or `out`
+/// is not big enough to contain the padding.
+pub fn write_md_padding(
+    out: &mut [u8],
+    msg_len: usize,
+    block_size: usize,
+    len_field: usize,
+) -> usize {
+    assert!(
+        len_field <= 16,
+        "length field must not be bigger than 16 bytes"
+    );
+    let zeros = md_pad_len(msg_len, block_size, len_field);
+    let n = 1 + zeros + len_field;
+    let out = &mut out[..n];
+    out[0] = 0x80;
+    for b in out[1..1 + zeros].iter_mut() {
+        *b = 0;
+    }
+    let bit_len = (msg_len as u128).wrapping_mul(8).to_be_bytes();
+    out[1 + zeros..].copy_from_slice(&bit_len[16 - len_field..]);
+    n
+}
@@ -67,6 +67,11 @@ where
 {
 }
 
+/// Output size selected at construction is preserved on reset.
+///
+/// The core's [`Reset`] implementation must restore the state initialized
+/// for the selected output size (and parameters, if any) instead of
+/// reinitializing it with a default one, e.g. by storing the initial state.
 impl<T> Reset for RtVariableCoreWrapper<T>
 where
     T: VariableOutputCore + UpdateCore + Reset,
@@ -89,7 +94,7 @@ where
     #[inline]
     fn update(&mut self, input: &[u8]) {
         let Self { core, buffer, .. } = self;
-        buffer.digest_blocks(input, |blocks| core.update_blocks(blocks));
+        buffer.digest_blocks(input, |blocks| core.update_blocks_par(blocks));
     }
 }
 
@@ -159,6 +164,16 @@ where
         Ok(buf.len())
     }
 
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let mut n = 0;
+        for buf in bufs {
+            Update::update(self, buf);
+            n += buf.len();
+        }
+        Ok(n)
+    }
+
     #[inline]
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
@@ -1,9 +1,11 @@
 use super::{
-    AlgorithmName, Buffer, BufferKindUser, ExtendableOutputCore, FixedOutputCore, OutputSizeUser,
-    Reset, UpdateCore, XofReaderCoreWrapper,
+    AlgorithmName, Buffer, BufferKindUser, CapacityTag, CompressCount, ExtendableOutputCore,
+    FixedOutputCore, FixedOutputCoreExt, InitState, OutputSizeUser, Reset, UpdateCore,
+    XofReaderCoreWrapper,
 };
 use crate::{
-    ExtendableOutput, ExtendableOutputReset, FixedOutput, FixedOutputReset, HashMarker, Update,
+    ExtendableOutput, ExtendableOutputReset, FixedOutput, FixedOutputReset, HashMarker,
+    LengthExtendable, Update,
 };
 use block_buffer::BlockBuffer;
 use core::fmt;
@@ -35,6 +37,14 @@ where
 {
 }
 
+impl<T> LengthExtendable for CoreWrapper<T>
+where
+    T: BufferKindUser + LengthExtendable,
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+}
+
 #[cfg(feature = "mac")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
 impl<T> MacMarker for CoreWrapper<T>
@@ -121,6 +131,18 @@ where
     }
 }
 
+impl<T> InitState for CoreWrapper<T>
+where
+    T: BufferKindUser + InitState,
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    #[inline]
+    fn init_state_bytes() -> &'static [u8] {
+        T::init_state_bytes()
+    }
+}
+
 impl<T> Reset for CoreWrapper<T>
 where
     T: BufferKindUser + Reset,
@@ -143,7 +165,7 @@ where
     #[inline]
     fn update(&mut self, input: &[u8]) {
         let Self { core, buffer } = self;
-        buffer.digest_blocks(input, |blocks| core.update_blocks(blocks));
+        buffer.digest_blocks(input, |blocks| core.update_blocks_par(blocks));
     }
 }
 
@@ -169,6 +191,58 @@ where
     }
 }
 
+impl<T> CoreWrapper<T>
+where
+    T: FixedOutputCoreExt,
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    /// Consume value, write side output into `side` and return the main result.
+    ///
+    /// See [`FixedOutputCoreExt`] for more information.
+    #[inline]
+    pub fn finalize_fixed_ext(mut self, side: &mut [u8]) -> Output<T> {
+        let mut out = Default::default();
+        let Self { core, buffer } = &mut self;
+        core.finalize_fixed_core_ext(buffer, &mut out, side);
+        out
+    }
+}
+
+impl<T> CoreWrapper<T>
+where
+    T: CapacityTag,
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    /// Write capacity tag of data processed so far into `out`.
+    ///
+    /// Unlike finalization, this method does not affect the hasher state,
+    /// so processing of data can be continued after it.
+    /// See [`CapacityTag`] for more information.
+    #[inline]
+    pub fn capacity_tag(&self, out: &mut [u8]) {
+        let mut buffer = self.buffer.clone();
+        self.core.capacity_tag(&mut buffer, out);
+    }
+}
+
+impl<T> CoreWrapper<T>
+where
+    T: BufferKindUser + CompressCount,
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    /// Return number of block compressions performed by the core so far.
+    ///
+    /// Data stored in the buffer is not accounted for until the respective
+    /// block gets processed. See [`CompressCount`] for more information.
+    #[inline]
+    pub fn compress_count(&self) -> u64 {
+        self.core.compress_count()
+    }
+}
+
 impl<T> FixedOutputReset for CoreWrapper<T>
 where
     T: FixedOutputCore + Reset,
@@ -239,6 +313,16 @@ where
         Ok(buf.len())
     }
 
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let mut n = 0;
+        for buf in bufs {
+            Update::update(self, buf);
+            n += buf.len();
+        }
+        Ok(n)
+    }
+
     #[inline]
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
@@ -1,12 +1,16 @@
-use super::{AlgorithmName, XofReaderCore};
+use super::{AlgorithmName, Block, XofReaderCore};
 use crate::XofReader;
 use block_buffer::EagerBuffer;
 use core::fmt;
-use generic_array::typenum::{IsLess, Le, NonZero, U256};
+use generic_array::typenum::{IsLess, Le, NonZero, Unsigned, U256};
 
 /// Wrapper around [`XofReaderCore`] implementations.
 ///
 /// It handles data buffering and implements the mid-level traits.
+///
+/// Cloning the wrapper (see also [`XofReaderCoreWrapper::fork`]) forks
+/// the output stream deterministically, i.e. both readers produce the same
+/// bytes from the position at which the clone was made.
 #[derive(Clone, Default)]
 pub struct XofReaderCoreWrapper<T>
 where
@@ -18,6 +22,23 @@ where
     pub(super) buffer: EagerBuffer<T::BlockSize>,
 }
 
+impl<T> XofReaderCoreWrapper<T>
+where
+    T: XofReaderCore + Clone,
+    T::BlockSize: IsLess<U256>,
+    Le<T::BlockSize, U256>: NonZero,
+{
+    /// Fork reader at the current position.
+    ///
+    /// This method is equivalent to [`Clone::clone`] and exists to clarify
+    /// intent: the returned reader and `self` will produce identical output
+    /// from this point, and advancing one does not affect the other.
+    #[inline]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+}
+
 impl<T> fmt::Debug for XofReaderCoreWrapper<T>
 where
     T: XofReaderCore + AlgorithmName,
@@ -45,6 +66,22 @@ where
             }
         });
     }
+
+    #[inline]
+    fn skip(&mut self, n: u64) {
+        let bs = T::BlockSize::U64;
+        let pos = self.buffer.get_pos();
+        let buffered = if pos == 0 { 0 } else { self.buffer.remaining() };
+        let mut scratch = Block::<T>::default();
+        if n < buffered as u64 {
+            XofReader::read(self, &mut scratch[..n as usize]);
+            return;
+        }
+        XofReader::read(self, &mut scratch[..buffered]);
+        let n = n - buffered as u64;
+        self.core.skip_blocks(n / bs);
+        XofReader::read(self, &mut scratch[..(n % bs) as usize]);
+    }
 }
 
 #[cfg(feature = "std")]
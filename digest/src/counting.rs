@@ -0,0 +1,74 @@
+use crate::{FixedOutput, FixedOutputReset, Reset, Update};
+use crypto_common::{Output, OutputSizeUser};
+
+/// Wrapper around [`Update`] implementors which counts number of processed
+/// bytes.
+///
+/// All data is forwarded to the inner type unchanged, so the wrapper can be
+/// used for collecting metrics without affecting hashing results.
+#[derive(Clone, Debug, Default)]
+pub struct CountingUpdate<U> {
+    inner: U,
+    count: u64,
+}
+
+impl<U> CountingUpdate<U> {
+    /// Create new wrapper around `inner`.
+    #[inline]
+    pub fn new(inner: U) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    /// Get number of bytes processed since construction or the last reset.
+    #[inline]
+    pub fn bytes_hashed(&self) -> u64 {
+        self.count
+    }
+
+    /// Get reference to the inner type.
+    #[inline]
+    pub fn get_ref(&self) -> &U {
+        &self.inner
+    }
+
+    /// Get the inner type and consume the wrapper.
+    #[inline]
+    pub fn into_inner(self) -> U {
+        self.inner
+    }
+}
+
+impl<U: Update> Update for CountingUpdate<U> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.count = self.count.wrapping_add(data.len() as u64);
+        self.inner.update(data);
+    }
+}
+
+impl<U: Reset> Reset for CountingUpdate<U> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.count = 0;
+    }
+}
+
+impl<U: OutputSizeUser> OutputSizeUser for CountingUpdate<U> {
+    type OutputSize = U::OutputSize;
+}
+
+impl<U: FixedOutput> FixedOutput for CountingUpdate<U> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.inner.finalize_into(out);
+    }
+}
+
+impl<U: FixedOutputReset> FixedOutputReset for CountingUpdate<U> {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.inner.finalize_into_reset(out);
+        self.count = 0;
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{FixedOutput, Update};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use core::marker::PhantomData;
+
+/// Method used for reducing hash output to `u64`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OutputReduction {
+    /// Interpret the first 8 bytes of output as a little-endian integer.
+    Truncate,
+    /// XOR all 8-byte chunks of output interpreted as little-endian
+    /// integers. The last chunk is padded with zeros if necessary.
+    Fold,
+}
+
+// `#[default]` attribute on enum variants requires Rust 1.62
+#[allow(clippy::derivable_impls)]
+impl Default for OutputReduction {
+    fn default() -> Self {
+        Self::Truncate
+    }
+}
+
+impl OutputReduction {
+    fn reduce(self, output: &[u8]) -> u64 {
+        let mut res = 0u64;
+        for chunk in output.chunks(8) {
+            let mut buf = [0u8; 8];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            res ^= u64::from_le_bytes(buf);
+            if self == Self::Truncate {
+                break;
+            }
+        }
+        res
+    }
+}
+
+/// [`Hasher`] implementation backed by a cryptographic hash function.
+///
+/// Integers passed to the `write_*` methods are absorbed in little-endian
+/// byte order (`usize` and `isize` are extended to 64 bits), so results
+/// do not depend on the target platform.
+#[derive(Clone)]
+pub struct CryptoHasher<D> {
+    inner: D,
+    reduction: OutputReduction,
+}
+
+impl<D: Default> CryptoHasher<D> {
+    /// Create new hasher using the given output reduction method.
+    pub fn new(reduction: OutputReduction) -> Self {
+        Self {
+            inner: D::default(),
+            reduction,
+        }
+    }
+}
+
+impl<D: Default> Default for CryptoHasher<D> {
+    fn default() -> Self {
+        Self::new(OutputReduction::default())
+    }
+}
+
+impl<D: Update + FixedOutput + Clone> Hasher for CryptoHasher<D> {
+    fn finish(&self) -> u64 {
+        let output = self.inner.clone().finalize_fixed();
+        self.reduction.reduce(&output)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.inner.update(bytes);
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+
+    fn write_i16(&mut self, n: i16) {
+        self.write_u16(n as u16);
+    }
+
+    fn write_i32(&mut self, n: i32) {
+        self.write_u32(n as u32);
+    }
+
+    fn write_i64(&mut self, n: i64) {
+        self.write_u64(n as u64);
+    }
+
+    fn write_i128(&mut self, n: i128) {
+        self.write_u128(n as u128);
+    }
+
+    fn write_isize(&mut self, n: isize) {
+        self.write_u64(n as i64 as u64);
+    }
+}
+
+impl<D> fmt::Debug for CryptoHasher<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CryptoHasher")
+            .field("reduction", &self.reduction)
+            .finish()
+    }
+}
+
+/// [`BuildHasher`] which creates [`CryptoHasher`] instances.
+///
+/// It can be used with hash maps keyed by content when collision resistance
+/// is worth the cost of computing a cryptographic hash. Note that
+/// collision resistance of the result is limited by its 64-bit size.
+pub struct CryptoBuildHasher<D> {
+    reduction: OutputReduction,
+    _pd: PhantomData<fn() -> D>,
+}
+
+impl<D> CryptoBuildHasher<D> {
+    /// Create new builder using the given output reduction method.
+    pub fn new(reduction: OutputReduction) -> Self {
+        Self {
+            reduction,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<D: Update + FixedOutput + Default + Clone> BuildHasher for CryptoBuildHasher<D> {
+    type Hasher = CryptoHasher<D>;
+
+    fn build_hasher(&self) -> CryptoHasher<D> {
+        CryptoHasher::new(self.reduction)
+    }
+}
+
+impl<D> Default for CryptoBuildHasher<D> {
+    fn default() -> Self {
+        Self::new(OutputReduction::default())
+    }
+}
+
+impl<D> Clone for CryptoBuildHasher<D> {
+    fn clone(&self) -> Self {
+        Self::new(self.reduction)
+    }
+}
+
+impl<D> fmt::Debug for CryptoBuildHasher<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CryptoBuildHasher")
+            .field("reduction", &self.reduction)
+            .finish()
+    }
+}
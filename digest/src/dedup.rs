@@ -0,0 +1,23 @@
+use crate::{Digest, Mac};
+use crypto_common::{Key, KeyInit, Output};
+
+/// Compute deduplication key of `content` for a tenant identified by
+/// `tenant_key`.
+///
+/// The result is equal to `M(tenant_key, D(content))`. Identical content
+/// stored by the same tenant produces identical keys, while keys of
+/// different tenants are unrelated, so a tenant can not check whether some
+/// content was stored by others without knowing their keys.
+///
+/// The result is intended for use as a lookup key, so it's returned as
+/// plain bytes instead of [`CtOutput`][crate::CtOutput].
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub fn dedup_key<D: Digest, M: Mac + KeyInit + Clone>(
+    tenant_key: &Key<M>,
+    content: &[u8],
+) -> Output<M> {
+    let hash = D::digest(content);
+    let mut mac = <M as Mac>::new(tenant_key);
+    mac.update(&hash);
+    mac.finalize().into_bytes()
+}
@@ -2,39 +2,41 @@
 
 pub use blobby;
 
+use core::fmt;
+
+#[cfg(feature = "std")]
+mod bench;
 mod fixed;
 mod mac;
+mod mock;
 mod rng;
 mod variable;
 mod xof;
 
+#[cfg(feature = "std")]
+pub use bench::*;
 pub use fixed::*;
 pub use mac::*;
+pub use mock::*;
 pub use variable::*;
 pub use xof::*;
 
 /// Define hash function test
+///
+/// Vectors are read from `data/<test_name>.blb` as `(input, output)` pairs
+/// and passed to `$test_func`. A failure panics with a message of the form
+/// `vector #N (label): expected ..` in hex. If `$test_func` is one of
+/// the `*_detailed` functions, the produced output is reported as well.
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
 macro_rules! new_test {
     ($name:ident, $test_name:expr, $hasher:ty, $test_func:ident $(,)?) => {
         #[test]
         fn $name() {
-            use digest::dev::blobby::Blob2Iterator;
             let data = include_bytes!(concat!("data/", $test_name, ".blb"));
-
-            for (i, row) in Blob2Iterator::new(data).unwrap().enumerate() {
-                let [input, output] = row.unwrap();
-                if let Some(desc) = $test_func::<$hasher>(input, output) {
-                    panic!(
-                        "\n\
-                         Failed test №{}: {}\n\
-                         input:\t{:?}\n\
-                         output:\t{:?}\n",
-                        i, desc, input, output,
-                    );
-                }
-            }
+            digest::dev::run_blob2_tests(data, |input, output| {
+                $test_func::<$hasher>(input, output).map(digest::dev::Mismatch::from)
+            });
         }
     };
 }
@@ -76,3 +78,164 @@ pub fn feed_rand_16mib<D: crate::Update>(d: &mut D) {
         d.update(&[42]);
     }
 }
+
+/// Maximum number of output bytes recorded by [`Mismatch`].
+const MISMATCH_MAX_LEN: usize = 128;
+
+/// Failed check reported by the `*_detailed` test functions in this module.
+///
+/// Holds the label of the failed check (e.g. `"message in chunks"`) and
+/// optionally the output produced by the tested algorithm. Outputs longer
+/// than 128 bytes are truncated.
+#[derive(Clone)]
+pub struct Mismatch {
+    label: &'static str,
+    got: [u8; MISMATCH_MAX_LEN],
+    len: Option<usize>,
+}
+
+impl Mismatch {
+    /// Create new mismatch report from a check label and the produced output.
+    pub fn new(label: &'static str, got: &[u8]) -> Self {
+        let len = core::cmp::min(got.len(), MISMATCH_MAX_LEN);
+        let mut buf = [0u8; MISMATCH_MAX_LEN];
+        buf[..len].copy_from_slice(&got[..len]);
+        Self {
+            label,
+            got: buf,
+            len: Some(len),
+        }
+    }
+
+    /// Label of the failed check.
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Output produced by the tested algorithm, if it was recorded.
+    pub fn got(&self) -> Option<&[u8]> {
+        self.len.map(|len| &self.got[..len])
+    }
+}
+
+impl From<&'static str> for Mismatch {
+    /// Create mismatch report which holds only the check label.
+    fn from(label: &'static str) -> Self {
+        Self {
+            label,
+            got: [0u8; MISMATCH_MAX_LEN],
+            len: None,
+        }
+    }
+}
+
+impl PartialEq for Mismatch {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.got() == other.got()
+    }
+}
+
+impl Eq for Mismatch {}
+
+impl fmt::Debug for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mismatch")
+            .field("label", &self.label)
+            .field("got", &self.got().map(Hex))
+            .finish()
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label)
+    }
+}
+
+/// Wrapper which formats a byte slice as lowercase hex.
+#[derive(Copy, Clone)]
+pub struct Hex<'a>(pub &'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Formats the ` got ..` part of a failure message.
+struct Got<'a>(&'a Mismatch);
+
+impl fmt::Display for Got<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.got() {
+            Some(got) => write!(f, " got {}", Hex(got)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Run test function `f` over `(input, output)` pairs stored in blobby `data`.
+///
+/// # Panics
+/// If `data` is malformed or `f` reports a [`Mismatch`]. The panic message
+/// has the form `vector #N (label): expected .. got ..` followed by
+/// the vector input, all in hex. The `got ..` part is present only if
+/// the mismatch holds the produced output.
+pub fn run_blob2_tests<F>(data: &[u8], f: F)
+where
+    F: Fn(&[u8], &[u8]) -> Option<Mismatch>,
+{
+    for (i, row) in blobby::Blob2Iterator::new(data).unwrap().enumerate() {
+        let [input, output] = row.unwrap();
+        if let Some(m) = f(input, output) {
+            panic!(
+                "vector #{} ({}): expected {}{}\n\
+                 input:\t{}\n",
+                i,
+                m.label(),
+                Hex(output),
+                Got(&m),
+                Hex(input),
+            );
+        }
+    }
+}
+
+/// Run MAC test function `f` over `(key, input, tag)` triples stored in
+/// blobby `data`.
+///
+/// # Panics
+/// If `data` is malformed or `f` reports a [`Mismatch`]. The panic message
+/// has the form `vector #N (label): expected .. got ..` followed by
+/// the vector key and input, all in hex. The `got ..` part is present only
+/// if the mismatch holds the produced output.
+pub fn run_blob3_tests<F>(data: &[u8], f: F)
+where
+    F: Fn(&[u8], &[u8], &[u8]) -> Option<Mismatch>,
+{
+    for (i, row) in blobby::Blob3Iterator::new(data).unwrap().enumerate() {
+        let [key, input, tag] = row.unwrap();
+        if let Some(m) = f(key, input, tag) {
+            panic!(
+                "vector #{} ({}): expected {}{}\n\
+                 key:\t{}\n\
+                 input:\t{}\n",
+                i,
+                m.label(),
+                Hex(tag),
+                Got(&m),
+                Hex(key),
+                Hex(input),
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Digest;
+use std::{time::Instant, vec, vec::Vec};
+
+/// Number of input bytes hashed in one timed round of [`bench_throughput`].
+const ROUND_BYTES: usize = 1 << 18;
+/// Number of timed rounds performed for every size by [`bench_throughput`].
+const ROUNDS: usize = 5;
+
+/// Measure throughput of `D` for buffers of the given `sizes`.
+///
+/// For every size a buffer is hashed repeatedly in five rounds of
+/// at least 256 KiB of input each, with every message processed by
+/// a fresh hasher. The fastest round is used to reduce influence of
+/// scheduling jitter. Each result is returned as a `(size, MB/s)` pair,
+/// where a megabyte is equal to 10^6 bytes.
+///
+/// Time is measured using [`Instant`], i.e. a monotonic clock.
+///
+/// # Panics
+/// If `sizes` contains zero.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "dev", feature = "std"))))]
+pub fn bench_throughput<D: Digest + Default>(sizes: &[usize]) -> Vec<(usize, f64)> {
+    sizes
+        .iter()
+        .map(|&size| {
+            assert!(size != 0, "buffer size must not be zero");
+            let mut buf = vec![0u8; size];
+            for (i, b) in buf.iter_mut().enumerate() {
+                *b = i as u8;
+            }
+            let iters = core::cmp::max(1, ROUND_BYTES / size);
+
+            let mut best = None;
+            for _ in 0..ROUNDS {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    let mut hasher = D::default();
+                    hasher.update(&buf);
+                    // feed result back into the input, so the work
+                    // can not be optimized out or hoisted
+                    buf[0] ^= hasher.finalize()[0];
+                }
+                let secs = start.elapsed().as_secs_f64();
+                best = Some(match best {
+                    Some(b) if b <= secs => b,
+                    _ => secs,
+                });
+            }
+            // guard against clocks with coarse resolution
+            let secs = best.unwrap_or(0.0).max(1e-9);
+            (size, (iters * size) as f64 / secs / 1e6)
+        })
+        .collect()
+}
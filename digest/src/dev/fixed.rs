@@ -1,8 +1,17 @@
+use super::Mismatch;
 use crate::{Digest, FixedOutput, FixedOutputReset, HashMarker, Update};
 use core::fmt::Debug;
 
 /// Fixed-output resettable digest test via the `Digest` trait
 pub fn fixed_reset_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: FixedOutputReset + Debug + Clone + Default + Update + HashMarker,
+{
+    fixed_reset_test_detailed::<D>(input, output).map(|m| m.label())
+}
+
+/// Same as [`fixed_reset_test`], but reports the produced output on failure
+pub fn fixed_reset_test_detailed<D>(input: &[u8], output: &[u8]) -> Option<Mismatch>
 where
     D: FixedOutputReset + Debug + Clone + Default + Update + HashMarker,
 {
@@ -10,15 +19,17 @@ where
     // Test that it works when accepting the message all at once
     hasher.update(input);
     let mut hasher2 = hasher.clone();
-    if hasher.finalize()[..] != output[..] {
-        return Some("whole message");
+    let res = hasher.finalize();
+    if res[..] != output[..] {
+        return Some(Mismatch::new("whole message", &res));
     }
 
     // Test if reset works correctly
     hasher2.reset();
     hasher2.update(input);
-    if hasher2.finalize_reset()[..] != output[..] {
-        return Some("whole message after reset");
+    let res = hasher2.finalize_reset();
+    if res[..] != output[..] {
+        return Some(Mismatch::new("whole message after reset", &res));
     }
 
     // Test that it works when accepting the message in chunks
@@ -28,11 +39,13 @@ where
             hasher.update(chunk);
             hasher2.update(chunk);
         }
-        if hasher.finalize()[..] != output[..] {
-            return Some("message in chunks");
+        let res = hasher.finalize();
+        if res[..] != output[..] {
+            return Some(Mismatch::new("message in chunks", &res));
         }
-        if hasher2.finalize_reset()[..] != output[..] {
-            return Some("message in chunks");
+        let res = hasher2.finalize_reset();
+        if res[..] != output[..] {
+            return Some(Mismatch::new("message in chunks", &res));
         }
     }
 
@@ -41,14 +54,23 @@ where
 
 /// Variable-output resettable digest test
 pub fn fixed_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: FixedOutput + Default + Debug + Clone,
+{
+    fixed_test_detailed::<D>(input, output).map(|m| m.label())
+}
+
+/// Same as [`fixed_test`], but reports the produced output on failure
+pub fn fixed_test_detailed<D>(input: &[u8], output: &[u8]) -> Option<Mismatch>
 where
     D: FixedOutput + Default + Debug + Clone,
 {
     let mut hasher = D::default();
     // Test that it works when accepting the message all at once
     hasher.update(input);
-    if hasher.finalize_fixed()[..] != output[..] {
-        return Some("whole message");
+    let res = hasher.finalize_fixed();
+    if res[..] != output[..] {
+        return Some(Mismatch::new("whole message", &res));
     }
 
     // Test that it works when accepting the message in chunks
@@ -57,9 +79,101 @@ where
         for chunk in input.chunks(n) {
             hasher.update(chunk);
         }
-        if hasher.finalize_fixed()[..] != output[..] {
-            return Some("message in chunks");
+        let res = hasher.finalize_fixed();
+        if res[..] != output[..] {
+            return Some(Mismatch::new("message in chunks", &res));
         }
     }
     None
 }
+
+/// Statically check that `D` implements the full set of traits expected
+/// from a resettable fixed-output hash function.
+///
+/// The function does nothing at runtime, its purpose is to produce
+/// a compile error listing missing trait implementations, e.g. in a test:
+///
+/// ```
+/// use digest::{consts::U16, dev::{assert_impl_all_traits, MockFixedHash}};
+///
+/// assert_impl_all_traits::<MockFixedHash<U16>>();
+/// ```
+///
+/// A hash which implements every other trait, but not [`Reset`][crate::Reset]
+/// (and therefore not [`FixedOutputReset`]), is rejected:
+///
+/// ```compile_fail
+/// use digest::{
+///     consts::U4, dev::assert_impl_all_traits, FixedOutput, Output, OutputSizeUser, Update,
+/// };
+///
+/// #[derive(Clone, Default)]
+/// struct NoReset(u32);
+///
+/// impl Update for NoReset {
+///     fn update(&mut self, data: &[u8]) {
+///         data.iter().for_each(|&b| self.0 = self.0.rotate_left(5) ^ u32::from(b));
+///     }
+/// }
+///
+/// impl OutputSizeUser for NoReset {
+///     type OutputSize = U4;
+/// }
+///
+/// impl FixedOutput for NoReset {
+///     fn finalize_into(self, out: &mut Output<Self>) {
+///         out.copy_from_slice(&self.0.to_le_bytes());
+///     }
+/// }
+///
+/// assert_impl_all_traits::<NoReset>();
+/// ```
+///
+/// Adding the missing implementations makes the same type pass:
+///
+/// ```
+/// # use digest::{
+/// #     consts::U4, dev::assert_impl_all_traits, FixedOutput, Output, OutputSizeUser, Update,
+/// # };
+/// use digest::{FixedOutputReset, Reset};
+/// #
+/// # #[derive(Clone, Default)]
+/// # struct NoReset(u32);
+/// #
+/// # impl Update for NoReset {
+/// #     fn update(&mut self, data: &[u8]) {
+/// #         data.iter().for_each(|&b| self.0 = self.0.rotate_left(5) ^ u32::from(b));
+/// #     }
+/// # }
+/// #
+/// # impl OutputSizeUser for NoReset {
+/// #     type OutputSize = U4;
+/// # }
+/// #
+/// # impl FixedOutput for NoReset {
+/// #     fn finalize_into(self, out: &mut Output<Self>) {
+/// #         out.copy_from_slice(&self.0.to_le_bytes());
+/// #     }
+/// # }
+///
+/// impl Reset for NoReset {
+///     fn reset(&mut self) {
+///         self.0 = 0;
+///     }
+/// }
+///
+/// impl FixedOutputReset for NoReset {
+///     fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+///         self.clone().finalize_into(out);
+///         self.reset();
+///     }
+/// }
+///
+/// assert_impl_all_traits::<NoReset>();
+/// ```
+#[inline(always)]
+pub fn assert_impl_all_traits<D>()
+where
+    D: Update + FixedOutputReset + Clone + Default,
+{
+}
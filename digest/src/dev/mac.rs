@@ -16,23 +16,26 @@ macro_rules! new_mac_test {
         #[test]
         fn $name() {
             use core::cmp::min;
-            use digest::dev::blobby::Blob3Iterator;
+            use digest::dev::Mismatch;
             use digest::Mac;
 
-            fn run_test(key: &[u8], input: &[u8], tag: &[u8]) -> Option<&'static str> {
+            fn trunc<'a>(result: &'a [u8], n: usize) -> &'a [u8] {
+                match $trunc {
+                    "left" => &result[..n],
+                    "right" => &result[result.len() - n..],
+                    _ => result,
+                }
+            }
+
+            fn run_test(key: &[u8], input: &[u8], tag: &[u8]) -> Option<Mismatch> {
                 let mac0 = <$mac as Mac>::new_from_slice(key).unwrap();
 
                 let mut mac = mac0.clone();
                 mac.update(input);
                 let result = mac.finalize().into_bytes();
-                let n = tag.len();
-                let result_bytes = match $trunc {
-                    "left" => &result[..n],
-                    "right" => &result[result.len() - n..],
-                    _ => &result[..],
-                };
+                let result_bytes = trunc(&result, tag.len());
                 if result_bytes != tag {
-                    return Some("whole message");
+                    return Some(Mismatch::new("whole message", result_bytes));
                 }
 
                 // test reading different chunk sizes
@@ -42,12 +45,14 @@ macro_rules! new_mac_test {
                         mac.update(chunk);
                     }
                     let res = match $trunc {
-                        "left" => mac.verify_truncated_left(tag),
-                        "right" => mac.verify_truncated_right(tag),
-                        _ => mac.verify_slice(tag),
+                        "left" => mac.clone().verify_truncated_left(tag),
+                        "right" => mac.clone().verify_truncated_right(tag),
+                        _ => mac.clone().verify_slice(tag),
                     };
                     if res.is_err() {
-                        return Some("chunked message");
+                        let result = mac.finalize().into_bytes();
+                        let result_bytes = trunc(&result, tag.len());
+                        return Some(Mismatch::new("chunked message", result_bytes));
                     }
                 }
 
@@ -55,20 +60,7 @@ macro_rules! new_mac_test {
             }
 
             let data = include_bytes!(concat!("data/", $test_name, ".blb"));
-
-            for (i, row) in Blob3Iterator::new(data).unwrap().enumerate() {
-                let [key, input, tag] = row.unwrap();
-                if let Some(desc) = run_test(key, input, tag) {
-                    panic!(
-                        "\n\
-                         Failed test №{}: {}\n\
-                         key:\t{:?}\n\
-                         input:\t{:?}\n\
-                         tag:\t{:?}\n",
-                        i, desc, key, input, tag,
-                    );
-                }
-            }
+            digest::dev::run_blob3_tests(data, run_test);
         }
     };
 }
@@ -91,34 +83,39 @@ macro_rules! new_resettable_mac_test {
         #[test]
         fn $name() {
             use core::cmp::min;
-            use digest::dev::blobby::Blob3Iterator;
+            use digest::dev::Mismatch;
             use digest::Mac;
 
-            fn run_test(key: &[u8], input: &[u8], tag: &[u8]) -> Option<&'static str> {
+            fn trunc<'a>(result: &'a [u8], n: usize) -> &'a [u8] {
+                match $trunc {
+                    "left" => &result[..n],
+                    "right" => &result[result.len() - n..],
+                    _ => result,
+                }
+            }
+
+            fn run_test(key: &[u8], input: &[u8], tag: &[u8]) -> Option<Mismatch> {
                 let mac0 = <$mac as Mac>::new_from_slice(key).unwrap();
 
                 let mut mac = mac0.clone();
                 mac.update(input);
                 let result = mac.finalize_reset().into_bytes();
-                let n = tag.len();
-                let result_bytes = match $trunc {
-                    "left" => &result[..n],
-                    "right" => &result[result.len() - n..],
-                    _ => &result[..],
-                };
+                let result_bytes = trunc(&result, tag.len());
                 if result_bytes != tag {
-                    return Some("whole message");
+                    return Some(Mismatch::new("whole message", result_bytes));
                 }
 
                 // test if reset worked correctly
                 mac.update(input);
                 let res = match $trunc {
-                    "left" => mac.verify_truncated_left(tag),
-                    "right" => mac.verify_truncated_right(tag),
-                    _ => mac.verify_slice(tag),
+                    "left" => mac.clone().verify_truncated_left(tag),
+                    "right" => mac.clone().verify_truncated_right(tag),
+                    _ => mac.clone().verify_slice(tag),
                 };
                 if res.is_err() {
-                    return Some("after reset");
+                    let result = mac.finalize().into_bytes();
+                    let result_bytes = trunc(&result, tag.len());
+                    return Some(Mismatch::new("after reset", result_bytes));
                 }
 
                 // test reading different chunk sizes
@@ -128,32 +125,118 @@ macro_rules! new_resettable_mac_test {
                         mac.update(chunk);
                     }
                     let res = match $trunc {
-                        "left" => mac.verify_truncated_left(tag),
-                        "right" => mac.verify_truncated_right(tag),
-                        _ => mac.verify_slice(tag),
+                        "left" => mac.clone().verify_truncated_left(tag),
+                        "right" => mac.clone().verify_truncated_right(tag),
+                        _ => mac.clone().verify_slice(tag),
                     };
                     if res.is_err() {
-                        return Some("chunked message");
+                        let result = mac.finalize().into_bytes();
+                        let result_bytes = trunc(&result, tag.len());
+                        return Some(Mismatch::new("chunked message", result_bytes));
                     }
                 }
                 None
             }
 
             let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+            digest::dev::run_blob3_tests(data, run_test);
+        }
+    };
+}
 
-            for (i, row) in Blob3Iterator::new(data).unwrap().enumerate() {
-                let [key, input, tag] = row.unwrap();
-                if let Some(desc) = run_test(key, input, tag) {
-                    panic!(
-                        "\n\
-                         Failed test №{}: {}\n\
-                         key:\t{:?}\n\
-                         input:\t{:?}\n\
-                         tag:\t{:?}\n",
-                        i, desc, key, input, tag,
-                    );
-                }
-            }
+/// Number of timed batches used by [`assert_constant_time`].
+#[cfg(all(feature = "mac", feature = "std"))]
+const TIMING_ROUNDS: usize = 101;
+/// Number of `verify` calls in one timed batch.
+#[cfg(all(feature = "mac", feature = "std"))]
+const TIMING_BATCH: usize = 64;
+
+/// Check that tag verification of `M` does not short-circuit.
+///
+/// Computes tag of `msg` using `key` and passes it together with
+/// [`Mac::verify_slice`][crate::Mac::verify_slice] to [`assert_constant_time`].
+///
+/// # Panics
+/// If `key` has invalid length for `M` or if the timing check fails.
+#[cfg(all(feature = "mac", feature = "std"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "dev", feature = "mac", feature = "std")))
+)]
+pub fn assert_verify_constant_time<M>(key: &[u8], msg: &[u8])
+where
+    M: crate::Mac + crypto_common::KeyInit + Clone,
+{
+    let mut mac = <M as crypto_common::KeyInit>::new_from_slice(key).expect("invalid key length");
+    mac.update(msg);
+    let tag = mac.clone().finalize().into_bytes();
+    assert_constant_time(&tag, |t| mac.clone().verify_slice(t).is_ok());
+}
+
+/// Best-effort statistical check that time taken by `verify` does not
+/// depend on the position at which a candidate tag differs from `tag`.
+///
+/// Batches of `verify` calls are timed alternately for tags which differ
+/// from `tag` in the first and in the last byte, and the check fails if
+/// the median batch timings differ by more than 50%. This is not
+/// a rigorous side-channel test, it's intended only for catching gross
+/// regressions such as an early-exit comparison. Results are unreliable in
+/// debug builds and on loaded machines, so tests using it are best marked
+/// with `#[ignore]` and run explicitly in release mode.
+///
+/// # Panics
+/// If `tag` is empty, if `verify` accepts a tampered tag, or if
+/// the timing check fails.
+#[cfg(all(feature = "mac", feature = "std"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "dev", feature = "mac", feature = "std")))
+)]
+pub fn assert_constant_time<F: FnMut(&[u8]) -> bool>(tag: &[u8], mut verify: F) {
+    use std::{time::Instant, vec::Vec};
+
+    assert!(!tag.is_empty(), "tag must not be empty");
+    let mut first = tag.to_vec();
+    first[0] ^= 1;
+    let mut last = tag.to_vec();
+    last[tag.len() - 1] ^= 1;
+
+    let mut accepted = 0usize;
+    let mut time = |t: &[u8]| {
+        let start = Instant::now();
+        for _ in 0..TIMING_BATCH {
+            accepted += verify(t) as usize;
+        }
+        start.elapsed()
+    };
+    let mut first_times = Vec::with_capacity(TIMING_ROUNDS);
+    let mut last_times = Vec::with_capacity(TIMING_ROUNDS);
+    for round in 0..TIMING_ROUNDS {
+        // alternate order to reduce bias caused by e.g. frequency scaling
+        if round % 2 == 0 {
+            first_times.push(time(&first));
+            last_times.push(time(&last));
+        } else {
+            last_times.push(time(&last));
+            first_times.push(time(&first));
         }
+    }
+    assert_eq!(accepted, 0, "tampered tag was accepted");
+
+    first_times.sort_unstable();
+    last_times.sort_unstable();
+    let first = first_times[TIMING_ROUNDS / 2].as_nanos();
+    let last = last_times[TIMING_ROUNDS / 2].as_nanos();
+    let (min, max) = if first < last {
+        (first, last)
+    } else {
+        (last, first)
     };
+    assert!(
+        2 * max <= 3 * min,
+        "verification timing depends on tag contents: median of {} ns for \
+        difference in the first byte, {} ns for difference in the last byte",
+        first,
+        last,
+    );
 }
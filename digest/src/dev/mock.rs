@@ -0,0 +1,67 @@
+use crate::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+use generic_array::{typenum::NonZero, ArrayLength, GenericArray};
+
+/// Mock hash function with `N` bytes of output.
+///
+/// Input bytes are XOR-folded into the state with a byte-wise rotation,
+/// so the result depends on both content and position of the data.
+///
+/// **WARNING:** it's NOT a cryptographic hash function! It's intended only
+/// for testing code generic over the hash traits without depending on
+/// a concrete hash implementation.
+#[doc(hidden)]
+#[derive(Clone, Debug)]
+pub struct MockFixedHash<N: ArrayLength<u8> + NonZero> {
+    state: GenericArray<u8, N>,
+    pos: usize,
+    len: u64,
+}
+
+impl<N: ArrayLength<u8> + NonZero> Default for MockFixedHash<N> {
+    fn default() -> Self {
+        Self {
+            state: Default::default(),
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<N: ArrayLength<u8> + NonZero> HashMarker for MockFixedHash<N> {}
+
+impl<N: ArrayLength<u8> + NonZero> Update for MockFixedHash<N> {
+    fn update(&mut self, data: &[u8]) {
+        for &b in data {
+            let s = &mut self.state[self.pos];
+            *s = s.rotate_left(3) ^ b;
+            self.pos = (self.pos + 1) % N::USIZE;
+        }
+        self.len = self.len.wrapping_add(data.len() as u64);
+    }
+}
+
+impl<N: ArrayLength<u8> + NonZero> Reset for MockFixedHash<N> {
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
+impl<N: ArrayLength<u8> + NonZero + 'static> OutputSizeUser for MockFixedHash<N> {
+    type OutputSize = N;
+}
+
+impl<N: ArrayLength<u8> + NonZero + 'static> FixedOutput for MockFixedHash<N> {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let len = self.len.to_le_bytes();
+        for (i, (o, s)) in out.iter_mut().zip(self.state.iter()).enumerate() {
+            *o = s ^ len[i % len.len()];
+        }
+    }
+}
+
+impl<N: ArrayLength<u8> + NonZero + 'static> FixedOutputReset for MockFixedHash<N> {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.clone().finalize_into(out);
+        self.reset();
+    }
+}
@@ -1,8 +1,17 @@
+use super::Mismatch;
 use crate::{VariableOutput, VariableOutputReset};
 use core::fmt::Debug;
 
 /// Variable-output resettable digest test
 pub fn variable_reset_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: VariableOutputReset + Debug + Clone,
+{
+    variable_reset_test_detailed::<D>(input, output).map(|m| m.label())
+}
+
+/// Same as [`variable_reset_test`], but reports the produced output on failure
+pub fn variable_reset_test_detailed<D>(input: &[u8], output: &[u8]) -> Option<Mismatch>
 where
     D: VariableOutputReset + Debug + Clone,
 {
@@ -14,7 +23,7 @@ where
     let mut hasher2 = hasher.clone();
     hasher.finalize_variable(buf).unwrap();
     if buf != output {
-        return Some("whole message");
+        return Some(Mismatch::new("whole message", buf));
     }
     buf.iter_mut().for_each(|b| *b = 0);
 
@@ -23,7 +32,7 @@ where
     hasher2.update(input);
     hasher2.finalize_variable_reset(buf).unwrap();
     if buf != output {
-        return Some("whole message after reset");
+        return Some(Mismatch::new("whole message after reset", buf));
     }
     buf.iter_mut().for_each(|b| *b = 0);
 
@@ -36,13 +45,13 @@ where
         }
         hasher.finalize_variable(buf).unwrap();
         if buf != output {
-            return Some("message in chunks");
+            return Some(Mismatch::new("message in chunks", buf));
         }
         buf.iter_mut().for_each(|b| *b = 0);
 
         hasher2.finalize_variable_reset(buf).unwrap();
         if buf != output {
-            return Some("message in chunks");
+            return Some(Mismatch::new("message in chunks", buf));
         }
         buf.iter_mut().for_each(|b| *b = 0);
     }
@@ -52,6 +61,14 @@ where
 
 /// Variable-output resettable digest test
 pub fn variable_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: VariableOutput + Debug + Clone,
+{
+    variable_test_detailed::<D>(input, output).map(|m| m.label())
+}
+
+/// Same as [`variable_test`], but reports the produced output on failure
+pub fn variable_test_detailed<D>(input: &[u8], output: &[u8]) -> Option<Mismatch>
 where
     D: VariableOutput + Debug + Clone,
 {
@@ -62,7 +79,7 @@ where
     hasher.update(input);
     hasher.finalize_variable(buf).unwrap();
     if buf != output {
-        return Some("whole message");
+        return Some(Mismatch::new("whole message", buf));
     }
     buf.iter_mut().for_each(|b| *b = 0);
 
@@ -74,7 +91,7 @@ where
         }
         hasher.finalize_variable(buf).unwrap();
         if buf != output {
-            return Some("message in chunks");
+            return Some(Mismatch::new("message in chunks", buf));
         }
         buf.iter_mut().for_each(|b| *b = 0);
     }
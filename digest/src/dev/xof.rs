@@ -1,8 +1,17 @@
+use super::Mismatch;
 use crate::ExtendableOutputReset;
 use core::fmt::Debug;
 
 /// Resettable XOF test
 pub fn xof_reset_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: ExtendableOutputReset + Default + Debug + Clone,
+{
+    xof_reset_test_detailed::<D>(input, output).map(|m| m.label())
+}
+
+/// Same as [`xof_reset_test`], but reports the produced output on failure
+pub fn xof_reset_test_detailed<D>(input: &[u8], output: &[u8]) -> Option<Mismatch>
 where
     D: ExtendableOutputReset + Default + Debug + Clone,
 {
@@ -14,7 +23,7 @@ where
     let mut hasher2 = hasher.clone();
     hasher.finalize_xof_into(buf);
     if buf != output {
-        return Some("whole message");
+        return Some(Mismatch::new("whole message", buf));
     }
     buf.iter_mut().for_each(|b| *b = 0);
 
@@ -23,7 +32,7 @@ where
     hasher2.update(input);
     hasher2.finalize_xof_reset_into(buf);
     if buf != output {
-        return Some("whole message after reset");
+        return Some(Mismatch::new("whole message after reset", buf));
     }
     buf.iter_mut().for_each(|b| *b = 0);
 
@@ -36,13 +45,13 @@ where
         }
         hasher.finalize_xof_into(buf);
         if buf != output {
-            return Some("message in chunks");
+            return Some(Mismatch::new("message in chunks", buf));
         }
         buf.iter_mut().for_each(|b| *b = 0);
 
         hasher2.finalize_xof_reset_into(buf);
         if buf != output {
-            return Some("message in chunks");
+            return Some(Mismatch::new("message in chunks", buf));
         }
         buf.iter_mut().for_each(|b| *b = 0);
     }
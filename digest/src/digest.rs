@@ -2,10 +2,19 @@ use super::{FixedOutput, FixedOutputReset, InvalidBufferSize, Reset, Update};
 use crypto_common::{Output, OutputSizeUser};
 use generic_array::typenum::Unsigned;
 
+#[cfg(feature = "std")]
+use crate::encoding::encode_hex;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
+/// Default size of the buffer used by [`Digest::digest_reader`].
+#[cfg(feature = "std")]
+pub(crate) const READER_BUF_SIZE: usize = 8 * 1024;
+
 /// Marker trait for cryptographic hash functions.
+///
+/// This trait and `MacMarker` are mutually exclusive, i.e. a type must not
+/// implement both of them.
 pub trait HashMarker {}
 
 /// Convinience wrapper trait covering functionality of cryptographic hash
@@ -49,6 +58,60 @@ pub trait Digest: OutputSizeUser {
 
     /// Compute hash of `data`.
     fn digest(data: impl AsRef<[u8]>) -> Output<Self>;
+
+    /// Compute hash of all data read from `reader` until EOF.
+    ///
+    /// Errors returned by `reader` (except [`ErrorKind::Interrupted`],
+    /// on which reading is retried) are propagated to the caller.
+    ///
+    /// [`ErrorKind::Interrupted`]: std::io::ErrorKind::Interrupted
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn digest_reader<R: std::io::Read>(reader: R) -> std::io::Result<Output<Self>>;
+
+    /// Compute hash of all data read from `reader` until EOF using
+    /// a heap-allocated read buffer of `capacity` bytes.
+    ///
+    /// Behaves the same as [`Digest::digest_reader`], but allows to tune
+    /// size of the read buffer, e.g. to reduce number of syscalls
+    /// when hashing large files.
+    ///
+    /// This is the recommended way of hashing very large files. This crate
+    /// intentionally does not provide hashing of memory-mapped files: it is
+    /// built with `#![forbid(unsafe_code)]`, while creating a mapping is
+    /// an `unsafe` operation, which results in undefined behavior if the
+    /// file gets modified by another process while it's being hashed.
+    ///
+    /// # Panics
+    /// If `capacity` is equal to zero.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn digest_reader_with_capacity<R: std::io::Read>(
+        reader: R,
+        capacity: usize,
+    ) -> std::io::Result<Output<Self>>;
+
+    /// Update state with all data from `reader` until EOF and return
+    /// number of processed bytes.
+    ///
+    /// Data is hashed directly from the internal buffer of `reader` using
+    /// [`BufRead::fill_buf`] and [`BufRead::consume`], i.e. without copying
+    /// it into an intermediate buffer. Error handling is the same as in
+    /// [`Digest::digest_reader`].
+    ///
+    /// [`BufRead::fill_buf`]: std::io::BufRead::fill_buf
+    /// [`BufRead::consume`]: std::io::BufRead::consume
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn update_from_bufread<R: std::io::BufRead>(&mut self, reader: &mut R) -> std::io::Result<u64>;
+
+    /// Write lowercase hex representation of `out` into `w`.
+    ///
+    /// Hex characters are encoded using a small stack buffer, i.e. without
+    /// heap allocations.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_hex_to<W: std::io::Write>(out: Output<Self>, w: &mut W) -> std::io::Result<()>;
 }
 
 impl<D: FixedOutput + Default + Update + HashMarker> Digest for D {
@@ -113,6 +176,154 @@ impl<D: FixedOutput + Default + Update + HashMarker> Digest for D {
         hasher.update(data.as_ref());
         hasher.finalize()
     }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn digest_reader<R: std::io::Read>(reader: R) -> std::io::Result<Output<Self>> {
+        Self::digest_reader_with_capacity(reader, READER_BUF_SIZE)
+    }
+
+    #[cfg(feature = "std")]
+    fn digest_reader_with_capacity<R: std::io::Read>(
+        mut reader: R,
+        capacity: usize,
+    ) -> std::io::Result<Output<Self>> {
+        assert!(capacity != 0, "read buffer capacity must not be zero");
+        let mut hasher = Self::default();
+        let mut buf = vec![0u8; capacity];
+        update_reader(&mut hasher, &mut reader, &mut buf)?;
+        Ok(hasher.finalize())
+    }
+
+    #[cfg(feature = "std")]
+    fn update_from_bufread<R: std::io::BufRead>(&mut self, reader: &mut R) -> std::io::Result<u64> {
+        let mut total = 0u64;
+        loop {
+            let n = match reader.fill_buf() {
+                Ok([]) => return Ok(total),
+                Ok(buf) => {
+                    Update::update(self, buf);
+                    buf.len()
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            reader.consume(n);
+            total += n as u64;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn write_hex_to<W: std::io::Write>(out: Output<Self>, w: &mut W) -> std::io::Result<()> {
+        let mut buf = [0u8; 64];
+        for chunk in out.chunks(buf.len() / 2) {
+            w.write_all(encode_hex(chunk, &mut buf).as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// Compute hash of the file at `path` bound to its size.
+///
+/// The hasher is updated with file size in bytes encoded as a 64-bit
+/// little-endian integer followed by the file contents. Binding the size
+/// prevents ambiguities when hashes of several files are combined.
+///
+/// Returns an error of kind [`InvalidData`][std::io::ErrorKind::InvalidData]
+/// if the file size changes while it's being hashed. Other errors are
+/// handled the same way as in [`Digest::digest_reader`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn digest_file<D: Digest + Update, P: AsRef<std::path::Path>>(
+    path: P,
+) -> std::io::Result<Output<D>> {
+    let mut file = std::fs::File::open(path)?;
+    let size = file.metadata()?.len();
+    let mut hasher = D::new();
+    Update::update(&mut hasher, &size.to_le_bytes());
+
+    let mut hasher = crate::CountingUpdate::new(hasher);
+    let mut buf = vec![0u8; READER_BUF_SIZE];
+    update_reader(&mut hasher, &mut file, &mut buf)?;
+    if hasher.bytes_hashed() != size {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "file size changed while hashing",
+        ));
+    }
+    Ok(hasher.into_inner().finalize())
+}
+
+/// Compute hash of `data` processing it in `chunk`-sized pieces.
+///
+/// After each piece `progress` is called with the total number of bytes
+/// processed so far, so it's called `ceil(data.len() / chunk)` times and
+/// the last call receives `data.len()`. The result is equal to
+/// [`Digest::digest`].
+///
+/// # Panics
+/// If `chunk` is equal to zero.
+pub fn digest_with_progress<D: Digest, F: FnMut(u64)>(
+    data: &[u8],
+    chunk: usize,
+    mut progress: F,
+) -> Output<D> {
+    assert!(chunk != 0, "chunk size must not be zero");
+    let mut hasher = D::new();
+    let mut total = 0u64;
+    for piece in data.chunks(chunk) {
+        hasher.update(piece);
+        total += piece.len() as u64;
+        progress(total);
+    }
+    hasher.finalize()
+}
+
+/// Compute hash of `input` truncated to its leftmost `bits` bits.
+///
+/// The result is `ceil(bits / 8)` bytes long. If `bits` is not a multiple
+/// of 8, the excess low-order bits of the last byte are set to zero, i.e.
+/// the result is a big-endian bit string padded with zeros on the right.
+/// This follows the "leftmost bits" truncation convention used e.g. by
+/// FIPS 186-4 and [RFC 6979].
+///
+/// # Panics
+/// If `bits` is bigger than the hash output size in bits.
+///
+/// [RFC 6979]: https://tools.ietf.org/html/rfc6979#section-2.3.2
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn digest_bits<D: Digest>(input: &[u8], bits: usize) -> Box<[u8]> {
+    let hash = D::digest(input);
+    assert!(bits <= 8 * hash.len(), "bit length exceeds output size");
+    let rem = bits % 8;
+    let len = bits / 8 + (rem != 0) as usize;
+    let mut res: Box<[u8]> = hash[..len].into();
+    if rem != 0 {
+        res[len - 1] &= 0xFF << (8 - rem);
+    }
+    res
+}
+
+/// Update `hasher` with all data read from `reader` until EOF using `buf`
+/// as an intermediate buffer.
+///
+/// Reading is retried on [`ErrorKind::Interrupted`][std::io::ErrorKind::Interrupted]
+/// errors, all other errors are propagated.
+#[cfg(feature = "std")]
+pub(crate) fn update_reader<U: Update, R: std::io::Read>(
+    hasher: &mut U,
+    reader: &mut R,
+    buf: &mut [u8],
+) -> std::io::Result<()> {
+    loop {
+        match reader.read(buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => hasher.update(&buf[..n]),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
 }
 
 /// Modification of the [`Digest`] trait suitable for trait objects.
@@ -0,0 +1,247 @@
+//! Constant-time encoding and decoding of binary data into hex, base32
+//! ([RFC 4648 section 6]) and base64 ([RFC 4648 sections 4 and 5]).
+//!
+//! Decoding does not branch on or index by the encoded data, so it can be
+//! used for secret values such as MAC tags. Lengths are considered public.
+//! Chunked decoding returns early on invalid chunks, so it should be used
+//! only for non-secret data.
+//!
+//! [RFC 4648 section 6]: https://tools.ietf.org/html/rfc4648#section-6
+//! [RFC 4648 sections 4 and 5]: https://tools.ietf.org/html/rfc4648#section-4
+
+use crate::{DecodeError, Update};
+#[cfg(all(feature = "alloc", feature = "mac"))]
+use alloc::string::String;
+
+/// Supported alphabets.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Alphabet {
+    /// Case-insensitive hex.
+    Hex,
+    /// Case-insensitive base32.
+    #[cfg_attr(not(feature = "mac"), allow(dead_code))]
+    Base32,
+    /// Standard base64.
+    Base64,
+    /// URL-safe base64.
+    #[cfg_attr(not(all(feature = "alloc", feature = "mac")), allow(dead_code))]
+    Base64Url,
+}
+
+impl Alphabet {
+    /// Number of bits encoded by one character.
+    fn bits(self) -> usize {
+        match self {
+            Alphabet::Hex => 4,
+            Alphabet::Base32 => 5,
+            Alphabet::Base64 | Alphabet::Base64Url => 6,
+        }
+    }
+
+    /// Decode a single character, returning a negative value for invalid
+    /// characters.
+    #[inline]
+    fn decode_char(self, c: u8) -> i16 {
+        let c = i16::from(c);
+        let mut ret = -1;
+        match self {
+            Alphabet::Hex => {
+                ret += range(c, b'0', b'9', 0);
+                ret += range(c, b'a', b'f', 10);
+                ret += range(c, b'A', b'F', 10);
+            }
+            Alphabet::Base32 => {
+                ret += range(c, b'A', b'Z', 0);
+                ret += range(c, b'a', b'z', 0);
+                ret += range(c, b'2', b'7', 26);
+            }
+            Alphabet::Base64 | Alphabet::Base64Url => {
+                let (c62, c63) = if self == Alphabet::Base64 {
+                    (b'+', b'/')
+                } else {
+                    (b'-', b'_')
+                };
+                ret += range(c, b'A', b'Z', 0);
+                ret += range(c, b'a', b'z', 26);
+                ret += range(c, b'0', b'9', 52);
+                ret += range(c, c62, c62, 62);
+                ret += range(c, c63, c63, 63);
+            }
+        }
+        ret
+    }
+
+    /// Number of characters in the unpadded encoding of `n` bytes.
+    pub(crate) fn encoded_len(self, n: usize) -> usize {
+        let bits = self.bits();
+        let rem = 8 * n % bits;
+        8 * n / bits + (rem != 0) as usize
+    }
+
+    /// Strip padding from `src` if the alphabet uses it.
+    ///
+    /// Returns `None` if the padding is invalid.
+    fn strip_padding(self, src: &[u8]) -> Option<&[u8]> {
+        let block = match self {
+            Alphabet::Hex => return Some(src),
+            Alphabet::Base32 => 8,
+            Alphabet::Base64 | Alphabet::Base64Url => 4,
+        };
+        let unpadded = src.iter().rposition(|&c| c != b'=').map_or(0, |i| i + 1);
+        let pad = src.len() - unpadded;
+        let rem = src.len() % block;
+        if pad == 0 || (rem == 0 && pad < block) {
+            Some(&src[..unpadded])
+        } else {
+            None
+        }
+    }
+
+    /// Decode `src` into `dst`, optionally accepting padding.
+    ///
+    /// Returns 0 if length of `src` does not correspond to length of `dst`,
+    /// `src` contains invalid characters, or the encoding is not canonical
+    /// (i.e. unused trailing bits are not zero), and 1 otherwise. Characters
+    /// are processed without branching on their values.
+    pub(crate) fn decode(self, src: &[u8], dst: &mut [u8], padding: bool) -> u8 {
+        let src = if padding {
+            match self.strip_padding(src) {
+                Some(src) => src,
+                None => return 0,
+            }
+        } else {
+            src
+        };
+        if src.len() != self.encoded_len(dst.len()) {
+            return 0;
+        }
+
+        let bits = self.bits();
+        let mask = (1u32 << bits) - 1;
+        let mut err = 0i16;
+        let mut acc = 0u32;
+        let mut acc_bits = 0;
+        let mut pos = 0;
+        for &c in src {
+            let v = self.decode_char(c);
+            err |= v;
+            acc = (acc << bits) | (v as u32 & mask);
+            acc_bits += bits;
+            if acc_bits >= 8 {
+                acc_bits -= 8;
+                dst[pos] = (acc >> acc_bits) as u8;
+                pos += 1;
+                acc &= (1 << acc_bits) - 1;
+            }
+        }
+        // `acc` contains only unused trailing bits at this point
+        let trailing = (acc | acc.wrapping_neg()) >> 31;
+        err |= -(trailing as i16);
+        (((err as u16) >> 15) ^ 1) as u8
+    }
+
+    /// Decode `src` in chunks, optionally accepting padding, and pass
+    /// decoded chunks to `f`.
+    ///
+    /// Returns `false` if `src` is not a valid encoding. Chunks decoded
+    /// before an invalid one are still passed to `f`.
+    fn decode_chunked(self, src: &[u8], padding: bool, mut f: impl FnMut(&[u8])) -> bool {
+        // multiple of number of characters encoding a whole number of bytes
+        // for all alphabets
+        const CHUNK: usize = 96;
+
+        let src = if padding {
+            match self.strip_padding(src) {
+                Some(src) => src,
+                None => return false,
+            }
+        } else {
+            src
+        };
+        let n = src.len() * self.bits() / 8;
+        if src.len() != self.encoded_len(n) {
+            return false;
+        }
+        let mut buf = [0u8; CHUNK * 6 / 8];
+        for chunk in src.chunks(CHUNK) {
+            let out = &mut buf[..chunk.len() * self.bits() / 8];
+            if self.decode(chunk, out, false) != 1 {
+                return false;
+            }
+            f(out);
+        }
+        true
+    }
+
+    /// Decode `src` and feed decoded data into `state`.
+    ///
+    /// Nothing is fed into `state` if `src` is not a valid encoding.
+    pub(crate) fn update_decoded<U: Update + ?Sized>(
+        self,
+        state: &mut U,
+        src: &str,
+        padding: bool,
+    ) -> Result<(), DecodeError> {
+        let src = src.as_bytes();
+        if !self.decode_chunked(src, padding, |_| {}) {
+            return Err(DecodeError);
+        }
+        self.decode_chunked(src, padding, |data| state.update(data));
+        Ok(())
+    }
+
+    /// Encode `src` without padding and append the result to `dst`.
+    ///
+    /// Supported only for the URL-safe base64 alphabet.
+    #[cfg(all(feature = "alloc", feature = "mac"))]
+    pub(crate) fn encode(self, src: &[u8], dst: &mut String) {
+        assert_eq!(self, Alphabet::Base64Url);
+        for chunk in src.chunks(3) {
+            let mut buf = [0u8; 3];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            let n = (u32::from(buf[0]) << 16) | (u32::from(buf[1]) << 8) | u32::from(buf[2]);
+            for i in 0..=chunk.len() {
+                let sextet = (n >> (18 - 6 * i)) & 0x3f;
+                dst.push(char::from(encode_base64url(sextet as i16)));
+            }
+        }
+    }
+}
+
+/// Encode `src` as lower-case hex into `dst` and return the encoded string.
+///
+/// # Panics
+/// If `dst` is shorter than `2 * src.len()`.
+pub(crate) fn encode_hex<'a>(src: &[u8], dst: &'a mut [u8]) -> &'a str {
+    let dst = &mut dst[..2 * src.len()];
+    for (pair, &b) in dst.chunks_exact_mut(2).zip(src) {
+        pair[0] = encode_hex_nibble(i16::from(b >> 4));
+        pair[1] = encode_hex_nibble(i16::from(b & 0x0f));
+    }
+    core::str::from_utf8(dst).expect("hex is valid UTF-8")
+}
+
+/// Encode 4 bits into a lower-case hex character.
+#[inline]
+fn encode_hex_nibble(src: i16) -> u8 {
+    (src + 0x30 + (((9 - src) >> 8) & 0x27)) as u8
+}
+
+/// Return `c - lo + base + 1` if `c` is in `lo..=hi` and 0 otherwise.
+#[inline]
+fn range(c: i16, lo: u8, hi: u8, base: i16) -> i16 {
+    let (lo, hi) = (i16::from(lo), i16::from(hi));
+    (((lo - 1 - c) & (c - hi - 1)) >> 8) & (c - lo + base + 1)
+}
+
+/// Encode 6 bits into a URL-safe base64 character.
+#[cfg(all(feature = "alloc", feature = "mac"))]
+#[inline]
+fn encode_base64url(src: i16) -> u8 {
+    let mut diff = 0x41i16;
+    diff += ((25 - src) >> 8) & 6;
+    diff -= ((51 - src) >> 8) & 75;
+    diff -= ((61 - src) >> 8) & 13;
+    diff += ((62 - src) >> 8) & 49;
+    (src + diff) as u8
+}
@@ -0,0 +1,46 @@
+use crate::Digest;
+use alloc::string::String;
+
+/// Crockford's base32 alphabet.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Compute human-readable fingerprint of `data`.
+///
+/// The fingerprint is equal to the first `bytes` bytes of
+/// `D(salt || data)` encoded using [Crockford's base32] without padding and
+/// check symbol, i.e. its length is equal to `ceil(8 * bytes / 5)`.
+/// Since `salt` and `data` are simply concatenated, `salt` should be
+/// a fixed application-specific value.
+///
+/// Fingerprints are intended for display and manual comparison, they are
+/// not computed in constant time.
+///
+/// # Panics
+/// If `bytes` is bigger than output size of `D`.
+///
+/// [Crockford's base32]: https://www.crockford.com/base32.html
+pub fn fingerprint<D: Digest>(salt: &[u8], data: &[u8], bytes: usize) -> String {
+    let hash = D::new().chain_update(salt).chain_update(data).finalize();
+    assert!(
+        bytes <= hash.len(),
+        "fingerprint length is bigger than hash output size"
+    );
+
+    let mut res = String::with_capacity(8 * bytes / 5 + 1);
+    let mut acc = 0u16;
+    let mut acc_bits = 0;
+    for &b in &hash[..bytes] {
+        acc = (acc << 8) | u16::from(b);
+        acc_bits += 8;
+        while acc_bits >= 5 {
+            acc_bits -= 5;
+            res.push(char::from(ALPHABET[usize::from((acc >> acc_bits) & 0x1f)]));
+        }
+    }
+    if acc_bits != 0 {
+        res.push(char::from(
+            ALPHABET[usize::from((acc << (5 - acc_bits)) & 0x1f)],
+        ));
+    }
+    res
+}
@@ -0,0 +1,139 @@
+use crate::{Digest, FixedOutput, FixedOutputReset, MacMarker, Update};
+use core::fmt;
+use crypto_common::{
+    Block, BlockSizeUser, InvalidLength, Key, KeyInit, KeySizeUser, Output, OutputSizeUser, Reset,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5C;
+
+/// Generic implementation of the HMAC construction ([RFC 2104]) over
+/// a [`Digest`].
+///
+/// Keys longer than the hash block size are hashed first, while shorter
+/// keys are padded with zeros, so any key length is accepted by
+/// [`KeyInit::new_from_slice`].
+///
+/// If the `zeroize` feature is enabled, the key-derived pad blocks are
+/// zeroized on drop.
+///
+/// [RFC 2104]: https://tools.ietf.org/html/rfc2104
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub struct Hmac<D: Digest + BlockSizeUser + Clone> {
+    digest: D,
+    ipad_key: Block<D>,
+    opad_key: Block<D>,
+}
+
+impl<D: Digest + BlockSizeUser + Clone> Hmac<D> {
+    fn from_block_key(key: &Block<D>) -> Self {
+        let mut ipad_key = key.clone();
+        let mut opad_key = key.clone();
+        for (i, o) in ipad_key.iter_mut().zip(opad_key.iter_mut()) {
+            *i ^= IPAD;
+            *o ^= OPAD;
+        }
+        let mut digest = D::new();
+        digest.update(&ipad_key);
+        Self {
+            digest,
+            ipad_key,
+            opad_key,
+        }
+    }
+
+    fn finalize_outer(&self, inner: Output<D>, out: &mut Output<D>) {
+        let mut h = D::new();
+        h.update(&self.opad_key);
+        h.update(&inner);
+        h.finalize_into(out);
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Clone> MacMarker for Hmac<D> {}
+
+impl<D: Digest + BlockSizeUser + Clone> KeySizeUser for Hmac<D> {
+    type KeySize = D::BlockSize;
+}
+
+impl<D: Digest + BlockSizeUser + Clone> OutputSizeUser for Hmac<D> {
+    type OutputSize = D::OutputSize;
+}
+
+impl<D: Digest + BlockSizeUser + Clone> KeyInit for Hmac<D> {
+    #[inline]
+    fn new(key: &Key<Self>) -> Self {
+        Self::from_block_key(key)
+    }
+
+    #[inline]
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        let mut block_key = Block::<D>::default();
+        if key.len() <= block_key.len() {
+            block_key[..key.len()].copy_from_slice(key);
+        } else {
+            let hash = D::digest(key);
+            block_key[..hash.len()].copy_from_slice(&hash);
+        }
+        let res = Self::from_block_key(&block_key);
+        #[cfg(feature = "zeroize")]
+        block_key.as_mut_slice().zeroize();
+        Ok(res)
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Clone> Update for Hmac<D> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.digest.update(data);
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Clone> FixedOutput for Hmac<D> {
+    #[inline]
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        let inner = core::mem::replace(&mut self.digest, D::new()).finalize();
+        self.finalize_outer(inner, out);
+    }
+}
+
+impl<D> Reset for Hmac<D>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.digest = D::new();
+        self.digest.update(&self.ipad_key);
+    }
+}
+
+impl<D> FixedOutputReset for Hmac<D>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let inner = core::mem::replace(&mut self.digest, D::new()).finalize();
+        self.digest.update(&self.ipad_key);
+        self.finalize_outer(inner, out);
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Clone> fmt::Debug for Hmac<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Hmac { .. }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<D: Digest + BlockSizeUser + Clone> Drop for Hmac<D> {
+    fn drop(&mut self) {
+        self.ipad_key.as_mut_slice().zeroize();
+        self.opad_key.as_mut_slice().zeroize();
+    }
+}
@@ -0,0 +1,103 @@
+//! Deterministic random bit generator based on HMAC.
+//!
+//! The generator is specified in [NIST SP 800-90A] and is used
+//! e.g. for deterministic generation of ECDSA nonces ([RFC 6979]).
+//!
+//! [NIST SP 800-90A]: https://csrc.nist.gov/publications/detail/sp/800-90a/rev-1/final
+//! [RFC 6979]: https://tools.ietf.org/html/rfc6979#section-3.2
+use crate::{Digest, Hmac, Mac};
+use core::fmt;
+use crypto_common::{BlockSizeUser, Output};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// HMAC_DRBG generic over hash function `D`.
+///
+/// Note that the generator does not track the reseed counter, so it's
+/// the caller's responsibility to reseed it when required by the
+/// application.
+#[derive(Clone)]
+pub struct HmacDrbg<D: Digest + BlockSizeUser + Clone> {
+    k: Output<D>,
+    v: Output<D>,
+}
+
+impl<D: Digest + BlockSizeUser + Clone> HmacDrbg<D> {
+    /// Instantiate generator using the provided entropy input, nonce and
+    /// personalization string.
+    pub fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        let k = Output::<D>::default();
+        let mut v = Output::<D>::default();
+        v.iter_mut().for_each(|b| *b = 0x01);
+        let mut drbg = Self { k, v };
+        drbg.update(&[entropy, nonce, personalization]);
+        drbg
+    }
+
+    /// Reseed generator using the provided entropy input and additional
+    /// input.
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) {
+        self.update(&[entropy, additional_input]);
+    }
+
+    /// Fill `out` with generated bytes.
+    pub fn fill_bytes(&mut self, out: &mut [u8]) {
+        self.generate(out, &[]);
+    }
+
+    /// Fill `out` with bytes generated using the provided additional input.
+    pub fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) {
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in out.chunks_mut(self.v.len()) {
+            self.next_v();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+    }
+
+    /// The HMAC_DRBG_Update function applied to concatenation of `provided`.
+    fn update(&mut self, provided: &[&[u8]]) {
+        self.update_round(0x00, provided);
+        if provided.iter().any(|p| !p.is_empty()) {
+            self.update_round(0x01, provided);
+        }
+    }
+
+    fn update_round(&mut self, sep: u8, provided: &[&[u8]]) {
+        let mut mac = self.mac();
+        mac.update(&self.v);
+        mac.update(&[sep]);
+        for p in provided {
+            mac.update(p);
+        }
+        self.k = mac.finalize().into_bytes();
+        self.next_v();
+    }
+
+    fn next_v(&mut self) {
+        let mut mac = self.mac();
+        mac.update(&self.v);
+        self.v = mac.finalize().into_bytes();
+    }
+
+    fn mac(&self) -> Hmac<D> {
+        <Hmac<D> as Mac>::new_from_slice(&self.k).expect("HMAC accepts keys of any size")
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Clone> fmt::Debug for HmacDrbg<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HmacDrbg { .. }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<D: Digest + BlockSizeUser + Clone> Drop for HmacDrbg<D> {
+    fn drop(&mut self) {
+        self.k.as_mut_slice().zeroize();
+        self.v.as_mut_slice().zeroize();
+    }
+}
@@ -0,0 +1,87 @@
+//! Labeled key derivation used by HPKE.
+//!
+//! This module implements the `LabeledExtract` and `LabeledExpand`
+//! functions defined in [RFC 9180 section 4] on top of HKDF ([RFC 5869])
+//! generically over a MAC, which is usually [`Hmac`] over SHA-256, SHA-384
+//! or SHA-512. Inputs are prefixed with the `HPKE-v1` version label, suite
+//! ID and label, so outputs of different HPKE operations and suites are
+//! independent.
+//!
+//! Suite ID is equal to `"KEM" || I2OSP(kem_id, 2)` inside of a KEM and to
+//! `"HPKE" || I2OSP(kem_id, 2) || I2OSP(kdf_id, 2) || I2OSP(aead_id, 2)`
+//! in the rest of HPKE.
+//!
+//! [`Hmac`]: crate::Hmac
+//! [RFC 9180 section 4]: https://www.rfc-editor.org/rfc/rfc9180#section-4
+//! [RFC 5869]: https://tools.ietf.org/html/rfc5869
+use crate::Mac;
+use crypto_common::{InvalidLength, KeyInit, Output};
+use generic_array::typenum::Unsigned;
+
+/// Version label prepended to all labeled inputs.
+const VERSION_LABEL: &[u8] = b"HPKE-v1";
+
+/// Compute `LabeledExtract(salt, label, ikm)` for `suite_id`.
+///
+/// The result is equal to HKDF-Extract over MAC `M` with `salt` used as
+/// the key and `"HPKE-v1" || suite_id || label || ikm` used as the input
+/// keying material.
+///
+/// Returns [`InvalidLength`] if `salt` has invalid length for `M` (HMAC
+/// accepts keys of any length, including empty ones).
+pub fn labeled_extract<M: Mac + KeyInit>(
+    suite_id: &[u8],
+    salt: &[u8],
+    label: &[u8],
+    ikm: &[u8],
+) -> Result<Output<M>, InvalidLength> {
+    let mut mac = <M as Mac>::new_from_slice(salt)?;
+    mac.update(VERSION_LABEL);
+    mac.update(suite_id);
+    mac.update(label);
+    mac.update(ikm);
+    Ok(mac.finalize().into_bytes())
+}
+
+/// Compute `LabeledExpand(prk, label, info, L)` for `suite_id` and write
+/// the result with length `L` equal to `out.len()` into `out`.
+///
+/// The result is equal to HKDF-Expand over MAC `M` using pseudorandom key
+/// `prk` and `I2OSP(L, 2) || "HPKE-v1" || suite_id || label || info` used
+/// as the info string.
+///
+/// Returns [`InvalidLength`] if `prk` has invalid length for `M` or if
+/// `out` is longer than `255 * Nh` bytes (where `Nh` is the output size of
+/// `M`) or than `65535` bytes.
+pub fn labeled_expand<M: Mac + KeyInit + Clone>(
+    suite_id: &[u8],
+    prk: &[u8],
+    label: &[u8],
+    info: &[u8],
+    out: &mut [u8],
+) -> Result<(), InvalidLength> {
+    let n = M::OutputSize::USIZE;
+    if out.len() > 255 * n || out.len() > 0xFFFF {
+        return Err(InvalidLength);
+    }
+    let mac = <M as Mac>::new_from_slice(prk)?;
+    let len = (out.len() as u16).to_be_bytes();
+
+    let mut prev: Option<Output<M>> = None;
+    for (i, chunk) in out.chunks_mut(n).enumerate() {
+        let mut m = mac.clone();
+        if let Some(t) = &prev {
+            m.update(t);
+        }
+        m.update(&len);
+        m.update(VERSION_LABEL);
+        m.update(suite_id);
+        m.update(label);
+        m.update(info);
+        m.update(&[i as u8 + 1]);
+        let t = m.finalize().into_bytes();
+        chunk.copy_from_slice(&t[..chunk.len()]);
+        prev = Some(t);
+    }
+    Ok(())
+}
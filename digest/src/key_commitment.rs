@@ -0,0 +1,23 @@
+use crate::Mac;
+use crypto_common::{Key, Output};
+
+/// Domain-separation constant authenticated by [`key_commitment`].
+pub const KEY_COMMITMENT_DOMAIN: &[u8] = b"RustCrypto digest key commitment v1\x00";
+
+/// Compute commitment to `key` of MAC `M`.
+///
+/// The commitment is equal to the MAC tag of [`KEY_COMMITMENT_DOMAIN`]
+/// computed using `key`. Publishing it alongside a tag or a ciphertext binds
+/// the key to the output, which prevents attacks relying on a single output
+/// being valid under several keys (e.g. partitioning oracle attacks).
+///
+/// The commitment is binding only if `M` is collision resistant with respect
+/// to its key, which is the case for HMAC instantiated with a collision
+/// resistant hash function, but not for universal hash based MACs such as
+/// GMAC or Poly1305. Commitments should be compared in constant time.
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub fn key_commitment<M: Mac>(key: &Key<M>) -> Output<M> {
+    let mut mac = <M as Mac>::new(key);
+    mac.update(KEY_COMMITMENT_DOMAIN);
+    mac.finalize().into_bytes()
+}
@@ -0,0 +1,24 @@
+use crate::XofReader;
+
+/// XOR output of the XOF `reader` into `data` in place.
+///
+/// The reader is advanced by `data.len()` bytes, so consecutive calls
+/// process a message split into several parts identically to a single call
+/// on the whole message. Since XOR is an involution, applying the same
+/// keystream twice restores the original data.
+///
+/// This function only provides confidentiality, not integrity. It's secure
+/// only if the XOF input contains a secret key and a unique nonce, i.e.
+/// the same keystream must never be used for encryption of different
+/// messages. It's recommended to use a properly designed stream cipher or
+/// AEAD instead.
+pub fn apply_keystream<X: XofReader>(reader: &mut X, data: &mut [u8]) {
+    let mut buf = [0u8; 64];
+    for chunk in data.chunks_mut(buf.len()) {
+        let ks = &mut buf[..chunk.len()];
+        reader.read(ks);
+        for (d, k) in chunk.iter_mut().zip(ks.iter()) {
+            *d ^= k;
+        }
+    }
+}
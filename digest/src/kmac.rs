@@ -0,0 +1,102 @@
+//! KECCAK Message Authentication Code (KMAC).
+//!
+//! KMAC is specified in [NIST SP 800-185] on top of cSHAKE. This module
+//! implements it generically over a type implementing the cSHAKE sponge,
+//! so KMAC128 and KMAC256 are obtained by using cSHAKE128 and cSHAKE256
+//! respectively.
+//!
+//! [NIST SP 800-185]: https://csrc.nist.gov/publications/detail/sp/800-185/final
+use crate::sp800_185::{bytepad_strings, cshake_init, right_encode};
+use crate::{ExtendableOutput, MacError, Update, XofReader};
+use core::fmt;
+use crypto_common::BlockSizeUser;
+use generic_array::typenum::Unsigned;
+use subtle::{Choice, ConstantTimeEq};
+
+/// Function name used by KMAC for cSHAKE domain separation.
+const FUNCTION_NAME: &[u8] = b"KMAC";
+
+/// KMAC generic over the cSHAKE sponge `X`.
+///
+/// `X` must be a Keccak sponge which uses the cSHAKE domain separation
+/// (i.e. `0x04` suffix applied during finalization, see
+/// [`FinalizationTweak`][crate::core_api::FinalizationTweak]) and starts
+/// with an empty state, i.e. cSHAKE with function name and customization
+/// string not absorbed yet. Its block size must be equal to its rate
+/// (168 bytes for KMAC128 and 136 bytes for KMAC256).
+///
+/// The requested output length is bound to the result, so outputs of
+/// different lengths are unrelated. [`Kmac::finalize_xof`] implements
+/// KMACXOF, which does not bind the output length.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub struct Kmac<X> {
+    xof: X,
+}
+
+impl<X> Kmac<X>
+where
+    X: ExtendableOutput + BlockSizeUser + Default,
+{
+    /// Create new KMAC instance using `key` and customization string `s`.
+    pub fn new(key: &[u8], s: &[u8]) -> Self {
+        let mut xof: X = cshake_init(FUNCTION_NAME, s);
+        bytepad_strings(&mut xof, X::BlockSize::USIZE, &[key]);
+        Self { xof }
+    }
+
+    /// Write KMAC result with length equal to `out.len()` into `out`.
+    pub fn finalize_into(mut self, out: &mut [u8]) {
+        let mut buf = [0u8; 9];
+        self.xof
+            .update(right_encode(8 * out.len() as u64, &mut buf));
+        self.xof.finalize_xof_into(out);
+    }
+
+    /// Check `tag` in constant time against KMAC result with length equal
+    /// to `tag.len()`.
+    ///
+    /// Empty tags are rejected, but it's the caller's responsibility to
+    /// enforce a sufficient tag length.
+    pub fn verify(mut self, tag: &[u8]) -> Result<(), MacError> {
+        if tag.is_empty() {
+            return Err(MacError);
+        }
+        let mut buf = [0u8; 9];
+        self.xof
+            .update(right_encode(8 * tag.len() as u64, &mut buf));
+        let mut reader = self.xof.finalize_xof();
+        let mut res = Choice::from(1);
+        let mut out = [0u8; 64];
+        for chunk in tag.chunks(out.len()) {
+            let out = &mut out[..chunk.len()];
+            reader.read(out);
+            res &= out.ct_eq(chunk);
+        }
+        if res.into() {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+
+    /// Retrieve KMACXOF reader.
+    pub fn finalize_xof(mut self) -> X::Reader {
+        let mut buf = [0u8; 9];
+        self.xof.update(right_encode(0, &mut buf));
+        self.xof.finalize_xof()
+    }
+}
+
+impl<X: Update> Update for Kmac<X> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.xof.update(data);
+    }
+}
+
+impl<X> fmt::Debug for Kmac<X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Kmac { .. }")
+    }
+}
@@ -0,0 +1,119 @@
+/// Marker trait for hash functions vulnerable to length extension attacks.
+///
+/// Hash functions based on the Merkle–Damgård construction without output
+/// truncation (e.g. SHA-256 or SHA-512) allow to compute `H(m || p || x)`
+/// for any `x` knowing only `H(m)` and length of `m` (`p` is the padding
+/// of `m`), so `H(secret || msg)` is not a secure MAC with them. Hash
+/// implementation crates are expected to implement this trait for such
+/// algorithms, generic code can reject them using the
+/// [`assert_not_length_extendable!`][crate::assert_not_length_extendable] macro.
+///
+/// Note that the trait is opt-in, so types which do not implement it are
+/// not necessarily resistant to length extension.
+pub trait LengthExtendable {}
+
+/// Helper trait used by [`assert_not_length_extendable!`]. Not a part of
+/// public API.
+///
+/// It works the same way as `AmbiguousIfMac`, but for types implementing
+/// [`LengthExtendable`].
+#[doc(hidden)]
+pub trait AmbiguousIfLengthExtendable<A> {
+    fn some_item() {}
+}
+
+impl<T: ?Sized> AmbiguousIfLengthExtendable<()> for T {}
+
+impl<T: ?Sized + LengthExtendable> AmbiguousIfLengthExtendable<u8> for T {}
+
+/// Assert at compile time that the given type does not implement
+/// [`LengthExtendable`].
+///
+/// ```
+/// # struct MySponge;
+/// digest::assert_not_length_extendable!(MySponge);
+/// ```
+///
+/// Types implementing [`LengthExtendable`] are rejected:
+///
+/// ```compile_fail
+/// struct MyMdHash;
+/// impl digest::LengthExtendable for MyMdHash {}
+///
+/// digest::assert_not_length_extendable!(MyMdHash);
+/// ```
+#[macro_export]
+macro_rules! assert_not_length_extendable {
+    ($t:ty $(,)?) => {
+        const _: fn() = || {
+            let _ = <$t as $crate::AmbiguousIfLengthExtendable<_>>::some_item;
+        };
+    };
+}
+
+/// Compute prefix MAC `D(secret || msg)` using hash function `D`.
+///
+/// Compilation fails if `D` implements [`LengthExtendable`], since for such
+/// hash functions the prefix MAC is insecure and [`Hmac`] should be used
+/// instead. `secret` should have a fixed length, otherwise its boundary
+/// with `msg` is ambiguous.
+///
+/// ```
+/// use digest::Digest;
+///
+/// # #[derive(Default, Clone)]
+/// # struct MySponge(sha2::Sha256);
+/// # impl digest::HashMarker for MySponge {}
+/// # impl digest::OutputSizeUser for MySponge {
+/// #     type OutputSize = digest::consts::U32;
+/// # }
+/// # impl digest::Update for MySponge {
+/// #     fn update(&mut self, data: &[u8]) { digest::Update::update(&mut self.0, data) }
+/// # }
+/// # impl digest::FixedOutput for MySponge {
+/// #     fn finalize_into(self, out: &mut digest::Output<Self>) {
+/// #         digest::FixedOutput::finalize_into(self.0, out)
+/// #     }
+/// # }
+///
+/// let tag = digest::prefix_mac!(MySponge, [0x42; 32], b"message");
+/// let expected = MySponge::new()
+///     .chain_update([0x42; 32])
+///     .chain_update(b"message")
+///     .finalize();
+/// assert_eq!(tag, expected);
+/// ```
+///
+/// Length-extendable hash functions are rejected:
+///
+/// ```compile_fail
+/// # #[derive(Default, Clone)]
+/// # struct MyMdHash(sha2::Sha256);
+/// # impl digest::HashMarker for MyMdHash {}
+/// # impl digest::OutputSizeUser for MyMdHash {
+/// #     type OutputSize = digest::consts::U32;
+/// # }
+/// # impl digest::Update for MyMdHash {
+/// #     fn update(&mut self, data: &[u8]) { digest::Update::update(&mut self.0, data) }
+/// # }
+/// # impl digest::FixedOutput for MyMdHash {
+/// #     fn finalize_into(self, out: &mut digest::Output<Self>) {
+/// #         digest::FixedOutput::finalize_into(self.0, out)
+/// #     }
+/// # }
+/// impl digest::LengthExtendable for MyMdHash {}
+///
+/// let tag = digest::prefix_mac!(MyMdHash, [0x42; 32], b"message");
+/// ```
+///
+/// [`Hmac`]: crate::Hmac
+#[macro_export]
+macro_rules! prefix_mac {
+    ($d:ty, $secret:expr, $msg:expr $(,)?) => {{
+        $crate::assert_not_length_extendable!($d);
+        let mut hasher = <$d as $crate::Digest>::new();
+        $crate::Digest::update(&mut hasher, $secret);
+        $crate::Digest::update(&mut hasher, $msg);
+        $crate::Digest::finalize(hasher)
+    }};
+}
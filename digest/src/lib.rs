@@ -11,14 +11,68 @@
 //!   [`VariableOutput`], [`VariableOutput`], [`Reset`], [`KeyInit`], and
 //!   [`InnerInit`]. These traits atomically describe available functionality
 //!   of an algorithm.
-//! - **Marker traits**: [`HashMarker`], [`MacMarker`]. Used to distinguish
-//!   different algorithm classes.
+//! - **Marker traits**: [`HashMarker`], [`MacMarker`], [`LengthExtendable`].
+//!   Used to distinguish different algorithm classes.
 //! - **Low-level traits** defined in the [`core_api`] module. These traits
 //!   operate at a block-level and do not contain any built-in buffering.
 //!   They are intended to be implemented by low-level algorithm providers only.
 //!   Usually they should not be used in application-level code.
 //!
-//! Additionally hash functions implement traits from the standard library:
+//! Additionally the crate provides:
+//!
+//! - **Generic constructions**: [`Hmac`], [`Prf`], [`s2v`], [`mgf1`],
+//!   [`HmacDrbg`], [`hpke`], [`key_commitment`], [`kmac::Kmac`],
+//!   [`parallel_mac`], [`tuple_hash::TupleHash`],
+//!   [`parallel_hash::ParallelHash`], [`otp`], [`cmac_subkeys`]. Algorithms
+//!   built on top of the traits defined in this crate.
+//! - **Adapters**: [`MultiHash`], [`ConcatHash`], [`DoublePipeHash`],
+//!   [`CountingUpdate`], [`ChunkedUpdate`], [`HasherPool`], [`TeeHash`],
+//!   [`CryptoBuildHasher`], [`VarAsXof`], [`RecordingUpdate`],
+//!   [`CheckpointingHasher`], [`MacWriter`]. Wrappers which combine or
+//!   modify behavior of types implementing the traits defined in this crate.
+//! - **Content-defined chunking** (requires `alloc` feature): [`cdc::Chunker`].
+//!   Splitting of data streams into chunks identified by their hashes.
+//! - **Rolling hashes**: [`rolling::Adler32`]. Non-cryptographic checksums
+//!   over a sliding window, e.g. for boundary detection.
+//! - **Chunk diffing** (requires `alloc` feature): [`chunk_digests`],
+//!   [`diff_chunks`]. Detection of changed fixed-size regions of data.
+//! - **Bloom filters** (requires `alloc` feature): [`bloom::BloomFilter`].
+//!   Probabilistic set membership using a single hash per item.
+//! - **Fingerprints** (requires `alloc` feature): [`fingerprint`]. Short
+//!   human-readable identifiers derived from hashes.
+//! - **Content-addressed paths** (requires `alloc` feature): [`shard_path`].
+//!   Sharded object store paths derived from hashes.
+//! - **Checksum files** (requires `std` feature): [`verify_checksum_file`].
+//!   Verification of files listed in `sha256sum`-style checksum files.
+//! - **Set hashing**: [`set_digest`]. Order-independent digests of
+//!   collections of items.
+//! - **Deduplication keys** (requires `mac` feature): [`dedup_key`].
+//!   Per-tenant content identifiers for multi-tenant storage.
+//! - **Nonce monitoring** (requires `std` and `mac` features):
+//!   [`NonceMonitor`]. Detection of nonce reuse using observed MAC tags.
+//! - **Authenticated framing** (requires `std` and `mac` features):
+//!   [`MacFramedWriter`], [`MacFramedReader`]. Length-prefixed frames
+//!   protected by a MAC tag.
+//! - **Commitments** (requires `alloc` and `mac` features): [`commitment`].
+//!   Commit-reveal scheme built on top of extendable-output functions.
+//! - **Cookie signing** (requires `alloc` and `mac` features): [`cookie`].
+//!   MAC-protected values suitable for storage in HTTP cookies.
+//! - **Expiring tokens** (requires `alloc` and `mac` features): [`token`].
+//!   MAC-protected tokens with expiration time, e.g. for signed URLs.
+//! - **Transcripts**: [`Transcript`], [`XofTranscript`]. Fiat–Shamir
+//!   transcripts built on top of extendable-output functions.
+//! - **Keystreams**: [`apply_keystream`]. XOR of data with output of an
+//!   extendable-output function.
+//! - **Deterministic identifiers**: [`derive_uuid`]. Name-based UUIDs derived
+//!   from output of an extendable-output function.
+//! - **Random number generation**: [`shuffle`], [`next_below`],
+//!   [`keyed_shuffle`] (requires `mac` feature), [`XofRng`] (requires
+//!   `rand_core` feature). Deterministic randomness derived from output of
+//!   an extendable-output function or a MAC.
+//! - **Secure containers** (requires `zeroize` feature): [`ZeroizingOutput`].
+//!   Wrappers which zeroize sensitive finalized data on drop.
+//!
+//! Hash functions also implement traits from the standard library:
 //! [`Default`], [`Clone`], [`Write`][std::io::Write]. The latter is
 //! feature-gated behind `std` feature, which is usually enabled by default
 //! by hash implementation crates.
@@ -42,36 +96,323 @@ extern crate std;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod bloom;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod cdc;
 #[cfg(feature = "dev")]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
 pub mod dev;
 
+mod canonical;
+#[cfg(feature = "alloc")]
+mod checkpoint;
+#[cfg(feature = "std")]
+mod checksum;
+#[cfg(feature = "alloc")]
+mod chunk_diff;
+mod chunked;
+mod cmac;
+#[cfg(all(feature = "alloc", feature = "mac"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "mac"))))]
+pub mod commitment;
+mod concat_hash;
+#[cfg(all(feature = "alloc", feature = "mac"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "mac"))))]
+pub mod cookie;
 #[cfg(feature = "core-api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "core-api")))]
 pub mod core_api;
+mod counting;
+mod crypto_hasher;
+#[cfg(feature = "mac")]
+mod dedup;
 mod digest;
+mod double_pipe;
+mod encoding;
+#[cfg(feature = "alloc")]
+mod fingerprint;
+#[cfg(all(feature = "std", feature = "mac"))]
+mod framed;
+#[cfg(feature = "mac")]
+mod hmac;
+#[cfg(feature = "mac")]
+mod hmac_drbg;
+#[cfg(feature = "mac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub mod hpke;
+#[cfg(feature = "mac")]
+mod key_commitment;
+mod keystream;
+#[cfg(feature = "mac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub mod kmac;
+mod length_extension;
 #[cfg(feature = "mac")]
 mod mac;
+#[cfg(all(feature = "std", feature = "mac"))]
+mod mac_writer;
+mod mgf1;
+mod multi_hash;
+#[cfg(all(feature = "std", feature = "mac"))]
+mod nonce_monitor;
+#[cfg(feature = "mac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub mod otp;
+pub mod parallel_hash;
+#[cfg(feature = "mac")]
+mod parallel_mac;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "mac")]
+mod prf;
+#[cfg(feature = "alloc")]
+mod recording;
+pub mod rolling;
+#[cfg(feature = "mac")]
+mod s2v;
+mod set_digest;
+#[cfg(feature = "alloc")]
+mod shard;
+mod shuffle;
+mod sp800_185;
+#[cfg(feature = "std")]
+mod tee;
+#[cfg(all(feature = "alloc", feature = "mac"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "mac"))))]
+pub mod token;
+mod transcript;
+pub mod tuple_hash;
+mod uuid;
+#[cfg(feature = "alloc")]
+mod var_as_xof;
+#[cfg(feature = "rand_core")]
+mod xof_rng;
+#[cfg(feature = "zeroize")]
+mod zeroizing;
 
 #[cfg(feature = "core-api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "core-api")))]
 pub use block_buffer;
 pub use crypto_common;
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub use rand_core;
 
-pub use crate::digest::{Digest, DynDigest, HashMarker};
+pub use crate::canonical::HashCanonical;
+#[cfg(feature = "alloc")]
+pub use crate::checkpoint::CheckpointingHasher;
+#[cfg(feature = "std")]
+pub use crate::checksum::verify_checksum_file;
+#[cfg(feature = "alloc")]
+pub use crate::chunk_diff::{chunk_digests, diff_chunks};
+pub use crate::chunked::ChunkedUpdate;
+pub use crate::cmac::cmac_subkeys;
+pub use crate::concat_hash::ConcatHash;
+pub use crate::counting::CountingUpdate;
+pub use crate::crypto_hasher::{CryptoBuildHasher, CryptoHasher, OutputReduction};
+#[cfg(feature = "mac")]
+pub use crate::dedup::dedup_key;
+#[cfg(feature = "alloc")]
+pub use crate::digest::digest_bits;
+#[cfg(feature = "std")]
+pub use crate::digest::digest_file;
+pub use crate::digest::{digest_with_progress, Digest, DynDigest, HashMarker};
+pub use crate::double_pipe::DoublePipeHash;
+#[cfg(feature = "alloc")]
+pub use crate::fingerprint::fingerprint;
+#[cfg(all(feature = "std", feature = "mac"))]
+pub use crate::framed::{MacFramedReader, MacFramedWriter};
+#[cfg(feature = "mac")]
+pub use crate::hmac::Hmac;
+#[cfg(feature = "mac")]
+pub use crate::hmac_drbg::HmacDrbg;
+#[cfg(feature = "mac")]
+pub use crate::key_commitment::{key_commitment, KEY_COMMITMENT_DOMAIN};
+pub use crate::keystream::apply_keystream;
+#[doc(hidden)]
+pub use crate::length_extension::AmbiguousIfLengthExtendable;
+pub use crate::length_extension::LengthExtendable;
 #[cfg(feature = "mac")]
 pub use crypto_common::{InnerInit, InvalidLength, Key, KeyInit};
 pub use crypto_common::{Output, OutputSizeUser, Reset};
 pub use generic_array::{self, typenum::consts};
 #[cfg(feature = "mac")]
-pub use mac::{CtOutput, Mac, MacError, MacMarker};
+#[doc(hidden)]
+pub use mac::AmbiguousIfMac;
+#[cfg(feature = "mac")]
+pub use mac::{CtOutput, DigestEq, Mac, MacError, MacMarker, TagEncoding};
+#[cfg(all(feature = "std", feature = "mac"))]
+pub use mac_writer::MacWriter;
+pub use mgf1::mgf1;
+pub use multi_hash::MultiHash;
+#[cfg(all(feature = "std", feature = "mac"))]
+pub use nonce_monitor::{NonceMonitor, NonceStatus};
+#[cfg(feature = "mac")]
+pub use parallel_mac::parallel_mac;
+#[cfg(feature = "std")]
+pub use pool::{HasherPool, PooledHasher};
+#[cfg(feature = "mac")]
+pub use prf::Prf;
+#[cfg(feature = "alloc")]
+pub use recording::RecordingUpdate;
+#[cfg(feature = "mac")]
+pub use s2v::s2v;
+pub use set_digest::set_digest;
+#[cfg(feature = "alloc")]
+pub use shard::shard_path;
+#[cfg(feature = "mac")]
+pub use shuffle::keyed_shuffle;
+pub use shuffle::{next_below, shuffle};
+#[cfg(feature = "std")]
+pub use tee::TeeHash;
+pub use transcript::{Transcript, XofTranscript};
+pub use uuid::derive_uuid;
+#[cfg(feature = "alloc")]
+pub use var_as_xof::{VarAsXof, VarAsXofReader};
+#[cfg(feature = "rand_core")]
+pub use xof_rng::XofRng;
+#[cfg(feature = "zeroize")]
+pub use zeroizing::ZeroizingOutput;
 
 use core::fmt;
+use generic_array::typenum::Unsigned;
 
 /// Types which consume data with byte granularity.
 pub trait Update {
     /// Update state using the provided data.
     fn update(&mut self, data: &[u8]);
+
+    /// Update state using each of the provided slices in order.
+    ///
+    /// Result is equal to updating state with concatenation of `slices`.
+    #[inline]
+    fn update_all(&mut self, slices: &[&[u8]]) {
+        for data in slices {
+            self.update(data);
+        }
+    }
+
+    /// Update state using items produced by a fallible iterator until
+    /// the first error.
+    ///
+    /// Every `Ok` item is processed in order and the first `Err` item is
+    /// returned without consuming the rest of the iterator. On error the
+    /// state is equal to state updated with all items preceding the error.
+    #[inline]
+    fn try_update_iter<I, B, E>(&mut self, iter: I) -> Result<(), E>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Result<B, E>>,
+        B: AsRef<[u8]>,
+    {
+        for item in iter {
+            self.update(item?.as_ref());
+        }
+        Ok(())
+    }
+
+    /// Update state using a single byte.
+    ///
+    /// Convenience wrapper over [`update`][Update::update].
+    #[inline]
+    fn update_u8(&mut self, n: u8) {
+        self.update(&[n]);
+    }
+
+    /// Update state using a single byte equal to 1 if `b` is `true`
+    /// and to 0 otherwise.
+    ///
+    /// Convenience wrapper over [`update_u8`][Update::update_u8], e.g. for
+    /// domain separation of hashed sections using flags.
+    #[inline]
+    fn update_bool(&mut self, b: bool) {
+        self.update_u8(b as u8);
+    }
+
+    /// Update state using big-endian representation of `n`.
+    ///
+    /// Convenience wrapper over [`update`][Update::update].
+    #[inline]
+    fn update_u16_be(&mut self, n: u16) {
+        self.update(&n.to_be_bytes());
+    }
+
+    /// Update state using little-endian representation of `n`.
+    ///
+    /// Convenience wrapper over [`update`][Update::update].
+    #[inline]
+    fn update_u16_le(&mut self, n: u16) {
+        self.update(&n.to_le_bytes());
+    }
+
+    /// Update state using big-endian representation of `n`.
+    ///
+    /// Convenience wrapper over [`update`][Update::update].
+    #[inline]
+    fn update_u32_be(&mut self, n: u32) {
+        self.update(&n.to_be_bytes());
+    }
+
+    /// Update state using little-endian representation of `n`.
+    ///
+    /// Convenience wrapper over [`update`][Update::update].
+    #[inline]
+    fn update_u32_le(&mut self, n: u32) {
+        self.update(&n.to_le_bytes());
+    }
+
+    /// Update state using big-endian representation of `n`.
+    ///
+    /// Convenience wrapper over [`update`][Update::update].
+    #[inline]
+    fn update_u64_be(&mut self, n: u64) {
+        self.update(&n.to_be_bytes());
+    }
+
+    /// Update state using little-endian representation of `n`.
+    ///
+    /// Convenience wrapper over [`update`][Update::update].
+    #[inline]
+    fn update_u64_le(&mut self, n: u64) {
+        self.update(&n.to_le_bytes());
+    }
+
+    /// Update state using length of `data` encoded as a 64-bit
+    /// little-endian integer followed by `data`.
+    ///
+    /// Length prefix makes boundaries between consecutive messages
+    /// unambiguous.
+    #[inline]
+    fn update_with_len(&mut self, data: &[u8]) {
+        self.update_u64_le(data.len() as u64);
+        self.update(data);
+    }
+
+    /// Update state using data decoded from case-insensitive hex string `s`.
+    ///
+    /// Data is decoded on the fly in fixed-size chunks without allocation.
+    /// Returns [`DecodeError`] without updating state if `s` is not a valid
+    /// hex string.
+    #[inline]
+    fn update_hex(&mut self, s: &str) -> Result<(), DecodeError> {
+        encoding::Alphabet::Hex.update_decoded(self, s, false)
+    }
+
+    /// Update state using data decoded from standard base64 ([RFC 4648])
+    /// string `s` with optional padding.
+    ///
+    /// Data is decoded on the fly in fixed-size chunks without allocation.
+    /// Returns [`DecodeError`] without updating state if `s` is not a valid
+    /// canonical base64 string. Whitespace is not accepted.
+    ///
+    /// [RFC 4648]: https://tools.ietf.org/html/rfc4648#section-4
+    #[inline]
+    fn update_base64(&mut self, s: &str) -> Result<(), DecodeError> {
+        encoding::Alphabet::Base64.update_decoded(self, s, true)
+    }
 }
 
 /// Trait for hash functions with fixed-size output.
@@ -86,6 +427,44 @@ pub trait FixedOutput: Update + OutputSizeUser + Sized {
         self.finalize_into(&mut out);
         out
     }
+
+    /// Consume value, write result into the beginning of `out` and return
+    /// number of written bytes.
+    ///
+    /// Returns [`InvalidBufferSize`] without writing anything if `out` is
+    /// shorter than the output size. Bytes after the result are left
+    /// untouched.
+    #[inline]
+    fn try_finalize_into(self, out: &mut [u8]) -> Result<usize, InvalidBufferSize> {
+        let n = Self::OutputSize::USIZE;
+        let out = out.get_mut(..n).ok_or(InvalidBufferSize)?;
+        out.copy_from_slice(&self.finalize_fixed());
+        Ok(n)
+    }
+
+    /// Retrieve result, consume the hasher instance, and compare the result
+    /// with `expected` in constant time.
+    ///
+    /// The whole result is compared before the outcome is inspected.
+    /// On mismatch the computed result is returned in the error.
+    ///
+    /// # Security
+    /// Returning the computed result may leak secret data (e.g. a valid tag
+    /// for an attacker-chosen message), so this method should be used only
+    /// in contexts where the result is not secret, e.g. for integrity checks
+    /// of public data. For MACs use [`Mac::verify`] instead.
+    #[cfg(feature = "mac")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+    fn finalize_and_verify(self, expected: &Output<Self>) -> Result<(), Output<Self>> {
+        use subtle::ConstantTimeEq;
+
+        let out = self.finalize_fixed();
+        if out[..].ct_eq(&expected[..]).into() {
+            Ok(())
+        } else {
+            Err(out)
+        }
+    }
 }
 
 /// Trait for hash functions with fixed-size output able to reset themselves.
@@ -108,6 +487,41 @@ pub trait XofReader {
     /// Read output into the `buffer`. Can be called an unlimited number of times.
     fn read(&mut self, buffer: &mut [u8]);
 
+    /// Skip `n` bytes of output.
+    ///
+    /// The default implementation reads and discards output, while
+    /// block-based readers may override it to fast-forward whole blocks.
+    fn skip(&mut self, mut n: u64) {
+        let mut buf = [0u8; 64];
+        while n != 0 {
+            let len = core::cmp::min(n, buf.len() as u64) as usize;
+            self.read(&mut buf[..len]);
+            n -= len as u64;
+        }
+    }
+
+    /// Read `n` bytes of output and write them to `w` as lower-case hex.
+    ///
+    /// Output is read in small chunks, so no allocation or large
+    /// intermediate buffer is needed. If `w` returns an error, the reader
+    /// may have been advanced by up to 32 bytes past the last written byte.
+    fn read_hex_into<W: fmt::Write>(&mut self, w: &mut W, n: usize) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        let mut bytes = [0u8; 32];
+        let mut buf = [0u8; 64];
+        let mut rem = n;
+        while rem != 0 {
+            let len = core::cmp::min(rem, bytes.len());
+            let chunk = &mut bytes[..len];
+            self.read(chunk);
+            w.write_str(encoding::encode_hex(chunk, &mut buf))?;
+            rem -= len;
+        }
+        Ok(())
+    }
+
     /// Read output into a boxed slice of the specified size.
     ///
     /// Can be called an unlimited number of times in combination with `read`.
@@ -136,6 +550,17 @@ pub trait ExtendableOutput: Sized + Update {
         self.finalize_xof().read(out);
     }
 
+    /// Finalize XOF and write result starting at the byte `offset` into `out`.
+    ///
+    /// Concatenation of results for adjacent ranges is equal to the result
+    /// of a single read covering all of them, which allows to generate
+    /// disjoint parts of the output independently.
+    fn finalize_xof_at(self, offset: u64, out: &mut [u8]) {
+        let mut reader = self.finalize_xof();
+        reader.skip(offset);
+        reader.read(out);
+    }
+
     /// Compute hash of `data` and write it into `output`.
     fn digest_xof(input: impl AsRef<[u8]>, output: &mut [u8])
     where
@@ -195,6 +620,22 @@ pub trait VariableOutput: Sized + Update {
     /// hash of the specified output size.
     fn new(output_size: usize) -> Result<Self, InvalidOutputSize>;
 
+    /// Create new hasher instance with the given output size clamped
+    /// to the `1..=Self::MAX_OUTPUT_SIZE` range.
+    ///
+    /// Note that hashes of different lengths are not related to each other,
+    /// so clamping changes not only the length of the result, but its value
+    /// as well. Use [`output_size`][VariableOutput::output_size] to get
+    /// the actually configured size.
+    ///
+    /// # Panics
+    /// If hasher can not be created with the clamped output size.
+    fn new_clamped(output_size: usize) -> Self {
+        let output_size = core::cmp::min(output_size, Self::MAX_OUTPUT_SIZE);
+        let output_size = core::cmp::max(output_size, 1);
+        Self::new(output_size).expect("clamped output size is supported by hasher")
+    }
+
     /// Get output size of the hasher instance provided to the `new` method
     fn output_size(&self) -> usize;
 
@@ -235,6 +676,28 @@ pub trait VariableOutput: Sized + Update {
     }
 }
 
+/// Trait for hash functions with variable-size output which support
+/// initialization with additional parameters, e.g. salt and personalization
+/// string in BLAKE2.
+///
+/// Algorithms which do not support such parameters should not implement
+/// this trait.
+pub trait ParamsInit: VariableOutput {
+    /// Create new hasher instance with the given output size, salt,
+    /// and personalization string.
+    ///
+    /// Empty `salt` and `personal` are equivalent to their default values.
+    ///
+    /// Returns `Err(InvalidOutputSize)` if hasher can not return hash of
+    /// the specified output size or if `salt` or `personal` has length which
+    /// is not supported by the algorithm.
+    fn new_with_params(
+        output_size: usize,
+        salt: &[u8],
+        personal: &[u8],
+    ) -> Result<Self, InvalidOutputSize>;
+}
+
 /// Trait for hash functions with variable-size output able to reset themselves.
 pub trait VariableOutputReset: VariableOutput + Reset {
     /// Write result into the output buffer and reset the hasher state.
@@ -284,3 +747,16 @@ impl fmt::Display for InvalidBufferSize {
 
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidBufferSize {}
+
+/// Input is not a valid encoding of binary data.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DecodeError;
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid encoding")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
@@ -1,14 +1,82 @@
-use crate::{FixedOutput, FixedOutputReset, Update};
+use crate::{encoding::Alphabet, FixedOutput, FixedOutputReset, Update};
 use crypto_common::{InvalidLength, Key, KeyInit, KeySizeUser, Output, OutputSizeUser, Reset};
 
 use core::fmt;
 use generic_array::typenum::Unsigned;
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(feature = "std")]
+use crate::digest::{update_reader, READER_BUF_SIZE};
 
 /// Marker trait for Message Authentication algorithms.
+///
+/// This trait and [`HashMarker`][crate::HashMarker] are mutually exclusive,
+/// i.e. a type must not implement both of them. Stable Rust does not allow
+/// to express this with negative bounds, but the property can be checked
+/// for concrete types using the [`assert_hash_not_mac!`][crate::assert_hash_not_mac] macro.
 #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
 pub trait MacMarker {}
 
+/// Helper trait used by [`assert_hash_not_mac!`]. Not a part of public API.
+///
+/// It has one blanket impl for all types and another one for types
+/// implementing [`MacMarker`]. Inferring the type parameter is ambiguous,
+/// and thus results in compilation error, only for the latter types.
+#[doc(hidden)]
+pub trait AmbiguousIfMac<A> {
+    fn some_item() {}
+}
+
+impl<T: ?Sized> AmbiguousIfMac<()> for T {}
+
+impl<T: ?Sized + MacMarker> AmbiguousIfMac<u8> for T {}
+
+/// Assert at compile time that the given type implements [`HashMarker`]
+/// and does not implement [`MacMarker`].
+///
+/// It's useful for generic code which must not accept misconfigured types
+/// implementing both marker traits.
+///
+/// ```
+/// # #[derive(Default)]
+/// # struct MyHash;
+/// impl digest::HashMarker for MyHash {}
+///
+/// digest::assert_hash_not_mac!(MyHash);
+/// ```
+///
+/// Types implementing [`MacMarker`] are rejected:
+///
+/// ```compile_fail
+/// struct MyHash;
+/// impl digest::HashMarker for MyHash {}
+/// impl digest::MacMarker for MyHash {}
+///
+/// digest::assert_hash_not_mac!(MyHash);
+/// ```
+///
+/// As well as types which do not implement [`HashMarker`]:
+///
+/// ```compile_fail
+/// struct MyMac;
+/// impl digest::MacMarker for MyMac {}
+///
+/// digest::assert_hash_not_mac!(MyMac);
+/// ```
+///
+/// [`HashMarker`]: crate::HashMarker
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+macro_rules! assert_hash_not_mac {
+    ($t:ty $(,)?) => {
+        const _: fn() = || {
+            fn assert_hash<T: ?Sized + $crate::HashMarker>() {}
+            assert_hash::<$t>();
+            let _ = <$t as $crate::AmbiguousIfMac<_>>::some_item;
+        };
+    };
+}
+
 /// Convinience wrapper trait covering functionality of Message Authentication algorithms.
 ///
 /// This trait wraps [`KeyInit`], [`Update`], [`FixedOutput`], and [`MacMarker`]
@@ -60,6 +128,35 @@ pub trait Mac: KeySizeUser + OutputSizeUser + Sized {
     ///
     /// Returns `Error` if `tag` is not valid or empty.
     fn verify_truncated_right(self, tag: &[u8]) -> Result<(), MacError>;
+
+    /// Check tag correctness for the message read from `reader` until EOF.
+    ///
+    /// The tag is checked in the same way as in [`Mac::verify_slice`].
+    /// Errors returned by `reader` are reported using the outer
+    /// [`std::io::Result`], while the verification result is stored in
+    /// the inner [`Result`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn verify_reader<R: std::io::Read>(
+        self,
+        reader: R,
+        tag: &[u8],
+    ) -> std::io::Result<Result<(), MacError>>;
+
+    /// Check correctness of a textually encoded tag truncated to
+    /// `keep_bytes` left side bytes (i.e. `tag[..keep_bytes]`) of calculated
+    /// tag.
+    ///
+    /// Decoding and comparison are performed in constant time and decoding
+    /// errors are indistinguishable from invalid tags. Returns `Error` if
+    /// `keep_bytes` is zero or bigger than MAC's output size, or if length
+    /// of `encoded_tag` does not correspond to `keep_bytes`.
+    fn verify_encoded(
+        self,
+        encoded_tag: &str,
+        encoding: TagEncoding,
+        keep_bytes: usize,
+    ) -> Result<(), MacError>;
 }
 
 impl<T: KeyInit + Update + FixedOutput + MacMarker> Mac for T {
@@ -150,6 +247,59 @@ impl<T: KeyInit + Update + FixedOutput + MacMarker> Mac for T {
             Err(MacError)
         }
     }
+
+    #[cfg(feature = "std")]
+    fn verify_reader<R: std::io::Read>(
+        mut self,
+        mut reader: R,
+        tag: &[u8],
+    ) -> std::io::Result<Result<(), MacError>> {
+        let mut buf = vec![0u8; READER_BUF_SIZE];
+        update_reader(&mut self, &mut reader, &mut buf)?;
+        Ok(self.verify_slice(tag))
+    }
+
+    fn verify_encoded(
+        self,
+        encoded_tag: &str,
+        encoding: TagEncoding,
+        keep_bytes: usize,
+    ) -> Result<(), MacError> {
+        let n = keep_bytes;
+        if n == 0 || n > Self::OutputSize::USIZE {
+            return Err(MacError);
+        }
+        let mut tag = Output::<Self>::default();
+        let alphabet = match encoding {
+            TagEncoding::Hex => Alphabet::Hex,
+            TagEncoding::Base32 => Alphabet::Base32,
+            TagEncoding::Base64 => Alphabet::Base64,
+        };
+        let valid = Choice::from(alphabet.decode(encoded_tag.as_bytes(), &mut tag[..n], true));
+        let choice = valid & self.finalize_fixed()[..n].ct_eq(&tag[..n]);
+
+        if choice.unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+}
+
+/// Textual encoding of a MAC tag used by [`Mac::verify_encoded`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub enum TagEncoding {
+    /// Case-insensitive hex.
+    Hex,
+    /// Case-insensitive base32 ([RFC 4648 section 6]) with optional padding.
+    ///
+    /// [RFC 4648 section 6]: https://tools.ietf.org/html/rfc4648#section-6
+    Base32,
+    /// Standard base64 ([RFC 4648 section 4]) with optional padding.
+    ///
+    /// [RFC 4648 section 4]: https://tools.ietf.org/html/rfc4648#section-4
+    Base64,
 }
 
 /// Fixed size output value which provides a safe [`Eq`] implementation that
@@ -174,6 +324,20 @@ impl<T: OutputSizeUser> CtOutput<T> {
     pub fn into_bytes(self) -> Output<T> {
         self.bytes
     }
+
+    /// Select `a` or `b` in constant time: returns `b` if `choice` is set
+    /// and `a` otherwise.
+    ///
+    /// Selection does not branch on `choice`, so it does not leak which
+    /// of the values was chosen.
+    #[inline]
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut bytes = Output::<T>::default();
+        for ((r, x), y) in bytes.iter_mut().zip(a.bytes.iter()).zip(b.bytes.iter()) {
+            *r = u8::conditional_select(x, y, choice);
+        }
+        Self { bytes }
+    }
 }
 
 impl<T: OutputSizeUser> From<Output<T>> for CtOutput<T> {
@@ -206,12 +370,68 @@ impl<T: OutputSizeUser> PartialEq for CtOutput<T> {
 
 impl<T: OutputSizeUser> Eq for CtOutput<T> {}
 
+/// Constant-time comparison of outputs produced by different types with
+/// the same output size.
+///
+/// This trait is implemented for all pairs of [`OutputSizeUser`] types with
+/// equal output sizes, e.g. it allows to compare a stored digest against
+/// a recomputed one in generic code which handles outputs of type-erased
+/// sources.
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub trait DigestEq<B: OutputSizeUser>: OutputSizeUser {
+    /// Compare `a` and `b` in constant time.
+    fn digest_eq(a: &Output<Self>, b: &Output<B>) -> bool;
+}
+
+impl<A, B> DigestEq<B> for A
+where
+    A: OutputSizeUser,
+    B: OutputSizeUser<OutputSize = A::OutputSize>,
+{
+    #[inline]
+    fn digest_eq(a: &Output<A>, b: &Output<B>) -> bool {
+        a.ct_eq(b).into()
+    }
+}
+
 /// Error type for when the [`Output`] of a [`Mac`]
 /// is not equal to the expected value.
+///
+/// The error intentionally does not carry any information about the mismatch
+/// (e.g. position of the first differing byte), so its [`Display`][fmt::Display]
+/// and [`Debug`] representations are always the same and can be safely logged.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
 pub struct MacError;
 
+impl MacError {
+    /// Get position of the first byte in which `tag` differs from `expected`.
+    ///
+    /// Returns `None` if the slices are equal. If one slice is a prefix of
+    /// the other, the length of the shorter slice is returned.
+    ///
+    /// **WARNING**: this function is NOT constant-time and its result reveals
+    /// information about the expected tag. It's intended only for debugging
+    /// during development and MUST NOT be used in verification code
+    /// or for logging in production. To prevent accidental use, it's
+    /// available only when the `insecure-diagnostics` feature is explicitly
+    /// enabled, which should be done only for development builds.
+    #[cfg(feature = "insecure-diagnostics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "insecure-diagnostics")))]
+    pub fn insecure_mismatch_position(tag: &[u8], expected: &[u8]) -> Option<usize> {
+        let pos = tag
+            .iter()
+            .zip(expected)
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| core::cmp::min(tag.len(), expected.len()));
+        if pos == tag.len() && pos == expected.len() {
+            None
+        } else {
+            Some(pos)
+        }
+    }
+}
+
 impl fmt::Display for MacError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("MAC tag mismatch")
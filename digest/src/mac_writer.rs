@@ -0,0 +1,65 @@
+use crate::{CtOutput, Mac};
+use core::fmt;
+use std::io;
+
+/// Adapter which allows to use a MAC as an [`io::Write`] sink.
+///
+/// All written data is passed to [`Mac::update`], so a stream can be
+/// authenticated using the standard I/O plumbing, e.g. [`io::copy`].
+/// Writes never fail and always consume the whole buffer.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "mac"))))]
+pub struct MacWriter<M> {
+    mac: M,
+}
+
+impl<M: Mac> MacWriter<M> {
+    /// Create new writer which updates `mac`.
+    pub fn new(mac: M) -> Self {
+        Self { mac }
+    }
+
+    /// Obtain the result of a MAC computation over written data.
+    pub fn finalize(self) -> CtOutput<M> {
+        self.mac.finalize()
+    }
+
+    /// Get reference to the inner MAC.
+    pub fn get_ref(&self) -> &M {
+        &self.mac
+    }
+
+    /// Get the inner MAC and consume the writer.
+    pub fn into_inner(self) -> M {
+        self.mac
+    }
+}
+
+impl<M: Mac> io::Write for MacWriter<M> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.mac.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        let mut n = 0;
+        for buf in bufs {
+            self.mac.update(buf);
+            n += buf.len();
+        }
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<M> fmt::Debug for MacWriter<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MacWriter { .. }")
+    }
+}
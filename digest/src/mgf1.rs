@@ -0,0 +1,30 @@
+use crate::Digest;
+
+/// Fill `out` using the MGF1 mask generation function defined in
+/// [RFC 8017] (PKCS #1) over hash function `D`.
+///
+/// The mask is equal to `D(seed || C)` for 32-bit big-endian counter `C`
+/// starting from zero, concatenated and truncated to `out.len()` bytes.
+/// MGF1 is used by RSA-OAEP and RSA-PSS.
+///
+/// # Panics
+/// If `out` is longer than `2^32` output blocks of `D`.
+///
+/// [RFC 8017]: https://tools.ietf.org/html/rfc8017#appendix-B.2.1
+pub fn mgf1<D: Digest + Clone>(seed: &[u8], out: &mut [u8]) {
+    let n = <D as Digest>::output_size();
+    assert!(
+        out.len() as u128 <= (n as u128) << 32,
+        "MGF1 mask is too long"
+    );
+
+    let mut hasher = D::new();
+    Digest::update(&mut hasher, seed);
+    for (counter, chunk) in out.chunks_mut(n).enumerate() {
+        let block = hasher
+            .clone()
+            .chain_update((counter as u32).to_be_bytes())
+            .finalize();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+}
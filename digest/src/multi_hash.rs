@@ -0,0 +1,71 @@
+use crate::{FixedOutput, Reset, Update};
+use crypto_common::Output;
+
+/// Wrapper around a tuple of hashers which are driven together.
+///
+/// Every [`Update::update`] call is forwarded to all members of the tuple,
+/// allowing to compute several digests of the same data stream in one pass,
+/// e.g. for legacy compatibility.
+///
+/// ```
+/// use digest::{Digest, MultiHash, Update};
+/// use sha2::{Sha256, Sha512};
+///
+/// let mut hasher = MultiHash::new((Sha256::new(), Sha512::new()));
+/// hasher.update(b"data");
+/// let (sha256, sha512) = hasher.finalize();
+/// assert_eq!(sha256, Sha256::digest(b"data"));
+/// assert_eq!(sha512, Sha512::digest(b"data"));
+/// ```
+///
+/// The wrapper is implemented for tuples with 2 to 6 elements.
+#[derive(Clone, Debug, Default)]
+pub struct MultiHash<T> {
+    hashers: T,
+}
+
+impl<T> MultiHash<T> {
+    /// Create new wrapper from a tuple of hashers.
+    #[inline]
+    pub fn new(hashers: T) -> Self {
+        Self { hashers }
+    }
+
+    /// Get the inner tuple of hashers.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.hashers
+    }
+}
+
+macro_rules! impl_multi_hash {
+    ($($t:ident $idx:tt),+) => {
+        impl<$($t: Update),+> Update for MultiHash<($($t,)+)> {
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                $(self.hashers.$idx.update(data);)+
+            }
+        }
+
+        impl<$($t: Reset),+> Reset for MultiHash<($($t,)+)> {
+            #[inline]
+            fn reset(&mut self) {
+                $(self.hashers.$idx.reset();)+
+            }
+        }
+
+        impl<$($t: FixedOutput),+> MultiHash<($($t,)+)> {
+            /// Retrieve results of all hashers and consume the wrapper.
+            #[inline]
+            pub fn finalize(self) -> ($(Output<$t>,)+) {
+                ($(self.hashers.$idx.finalize_fixed(),)+)
+            }
+        }
+    };
+}
+
+impl_multi_hash!(A 0, B 1);
+impl_multi_hash!(A 0, B 1, C 2);
+impl_multi_hash!(A 0, B 1, C 2, D 3);
+impl_multi_hash!(A 0, B 1, C 2, D 3, E 4);
+impl_multi_hash!(A 0, B 1, C 2, D 3, E 4, F 5);
@@ -0,0 +1,91 @@
+use crate::Mac;
+use core::fmt;
+use crypto_common::Output;
+use std::{collections::HashMap, vec::Vec};
+
+/// Result of a `(nonce, tag)` pair observation by [`NonceMonitor`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "mac"))))]
+pub enum NonceStatus {
+    /// Nonce was not observed before.
+    Fresh,
+    /// Nonce was observed before with the same tag, e.g. a retransmission
+    /// or a replay of the same message.
+    Repeated,
+    /// Nonce was observed before with a different tag, which indicates
+    /// nonce reuse or tampering.
+    Misuse,
+}
+
+/// Monitor which detects nonce reuse by tracking tags produced by MAC `M`
+/// for every observed nonce.
+///
+/// The monitor is intended for telemetry and debugging of systems using
+/// nonce-based authenticated encryption. It stores every observed nonce
+/// together with its tag, so memory usage grows with the number of distinct
+/// nonces. Tags are compared in variable time, since they are assumed to be
+/// public (e.g. transmitted alongside ciphertexts).
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "mac"))))]
+pub struct NonceMonitor<M: Mac> {
+    seen: HashMap<Vec<u8>, Output<M>>,
+    misuses: u64,
+}
+
+impl<M: Mac> NonceMonitor<M> {
+    /// Create new empty monitor.
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+            misuses: 0,
+        }
+    }
+
+    /// Record that `tag` was produced for `nonce` and report whether
+    /// the nonce was observed before.
+    ///
+    /// On [`NonceStatus::Misuse`] the tag recorded first is kept.
+    pub fn observe(&mut self, nonce: &[u8], tag: &Output<M>) -> NonceStatus {
+        match self.seen.get(nonce) {
+            None => {
+                self.seen.insert(nonce.to_vec(), tag.clone());
+                NonceStatus::Fresh
+            }
+            Some(prev) if prev == tag => NonceStatus::Repeated,
+            Some(_) => {
+                self.misuses += 1;
+                NonceStatus::Misuse
+            }
+        }
+    }
+
+    /// Return number of observations which resulted in
+    /// [`NonceStatus::Misuse`].
+    pub fn misuses(&self) -> u64 {
+        self.misuses
+    }
+
+    /// Return number of distinct observed nonces.
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Return `true` if no nonces were observed.
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+}
+
+impl<M: Mac> Default for NonceMonitor<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Mac> fmt::Debug for NonceMonitor<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NonceMonitor")
+            .field("nonces", &self.seen.len())
+            .field("misuses", &self.misuses)
+            .finish()
+    }
+}
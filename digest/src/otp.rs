@@ -0,0 +1,54 @@
+//! HMAC-based one-time passwords.
+//!
+//! This module implements HOTP ([RFC 4226]) and its time-based variant
+//! TOTP ([RFC 6238]) generically over a MAC, which is usually [`Hmac`]
+//! over SHA-1, SHA-256 or SHA-512.
+//!
+//! [`Hmac`]: crate::Hmac
+//! [RFC 4226]: https://tools.ietf.org/html/rfc4226
+//! [RFC 6238]: https://tools.ietf.org/html/rfc6238
+use crate::Mac;
+use crypto_common::KeyInit;
+
+/// Compute HOTP value of length `digits` for `counter` using `key`.
+///
+/// The value is obtained from MAC of the big-endian `counter` using dynamic
+/// truncation defined in [RFC 4226 section 5.3]. The result should be
+/// displayed padded with leading zeros to `digits` characters.
+///
+/// # Panics
+/// If `digits` is not in the `1..=9` range, if `key` has invalid length
+/// for `M`, or if output size of `M` is smaller than 20 bytes.
+///
+/// [RFC 4226 section 5.3]: https://tools.ietf.org/html/rfc4226#section-5.3
+pub fn hotp<M: Mac + KeyInit>(key: &[u8], counter: u64, digits: u32) -> u32 {
+    assert!(
+        (1..=9).contains(&digits),
+        "number of digits must be in the 1..=9 range"
+    );
+    let mut mac = <M as Mac>::new_from_slice(key).expect("invalid key length");
+    mac.update(&counter.to_be_bytes());
+    let tag = mac.finalize().into_bytes();
+    assert!(tag.len() >= 20, "MAC output size must be at least 20 bytes");
+
+    let offset = usize::from(tag[tag.len() - 1] & 0x0f);
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(&tag[offset..offset + 4]);
+    let code = u32::from_be_bytes(buf) & 0x7fff_ffff;
+    code % 10u32.pow(digits)
+}
+
+/// Compute TOTP value of length `digits` for `unix_time` using `key`.
+///
+/// Time is counted in intervals of `step` seconds starting from the Unix
+/// epoch, i.e. the result is equal to HOTP value for the
+/// `unix_time / step` counter. [RFC 6238] recommends step of 30 seconds.
+///
+/// # Panics
+/// If `step` is equal to zero or for the same reasons as [`hotp`].
+///
+/// [RFC 6238]: https://tools.ietf.org/html/rfc6238#section-4
+pub fn totp<M: Mac + KeyInit>(key: &[u8], unix_time: u64, step: u64, digits: u32) -> u32 {
+    assert!(step != 0, "time step must not be zero");
+    hotp::<M>(key, unix_time / step, digits)
+}
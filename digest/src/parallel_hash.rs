@@ -0,0 +1,152 @@
+//! ParallelHash.
+//!
+//! ParallelHash is specified in [NIST SP 800-185] on top of cSHAKE. Input
+//! is split into blocks of a fixed size, every block is hashed independently
+//! and the resulting leaf hashes are absorbed by an outer cSHAKE instance,
+//! so the leaf hashes may be computed in parallel. This module implements
+//! it generically over types implementing the cSHAKE and SHAKE sponges, so
+//! ParallelHash128 and ParallelHash256 are obtained by using cSHAKE128 with
+//! SHAKE128 and cSHAKE256 with SHAKE256 respectively.
+//!
+//! [NIST SP 800-185]: https://csrc.nist.gov/publications/detail/sp/800-185/final
+use crate::sp800_185::{cshake_init, left_encode, right_encode};
+use crate::{ExtendableOutput, Update};
+use core::fmt;
+use crypto_common::BlockSizeUser;
+use generic_array::typenum::Unsigned;
+
+/// Function name used by ParallelHash for cSHAKE domain separation.
+const FUNCTION_NAME: &[u8] = b"ParallelHash";
+
+/// Width of the Keccak-f\[1600\] permutation state in bytes.
+const STATE_LEN: usize = 200;
+
+/// ParallelHash generic over the cSHAKE sponge `X` and the SHAKE sponge `L`
+/// used for leaf hashes.
+///
+/// `X` must satisfy the same requirements as the sponge used by
+/// [`TupleHash`][crate::tuple_hash::TupleHash]. `L` must be SHAKE (i.e.
+/// cSHAKE with empty function name and customization string) with the same
+/// rate. Leaf hashes are `2 * c` bits long, where `c` is the security
+/// strength, i.e. 32 bytes for ParallelHash128 and 64 bytes for
+/// ParallelHash256.
+///
+/// The requested output length is bound to the result, so outputs of
+/// different lengths are unrelated. [`ParallelHash::finalize_xof`]
+/// implements ParallelHashXOF, which does not bind the output length.
+#[derive(Clone)]
+pub struct ParallelHash<X, L> {
+    xof: X,
+    leaf: L,
+    block_size: usize,
+    pos: usize,
+    blocks: u64,
+}
+
+impl<X, L> ParallelHash<X, L>
+where
+    X: ExtendableOutput + BlockSizeUser + Default,
+    L: ExtendableOutput + Default,
+{
+    /// Create new ParallelHash instance with block size `block_size` in
+    /// bytes and customization string `s`.
+    ///
+    /// # Panics
+    /// If `block_size` is equal to zero or if block size of `X` is not
+    /// a valid rate of the cSHAKE128 or cSHAKE256 sponge.
+    pub fn new(block_size: usize, s: &[u8]) -> Self {
+        assert!(block_size != 0, "block size must not be zero");
+        let rate = X::BlockSize::USIZE;
+        assert!(rate == 168 || rate == 136, "unsupported sponge rate");
+        let mut xof: X = cshake_init(FUNCTION_NAME, s);
+        let mut buf = [0u8; 9];
+        xof.update(left_encode(block_size as u64, &mut buf));
+        Self {
+            xof,
+            leaf: L::default(),
+            block_size,
+            pos: 0,
+            blocks: 0,
+        }
+    }
+
+    /// Finalize the current leaf and absorb its hash.
+    fn flush_leaf(&mut self) {
+        let leaf = core::mem::take(&mut self.leaf);
+        let mut buf = [0u8; 64];
+        let hash = &mut buf[..STATE_LEN - X::BlockSize::USIZE];
+        leaf.finalize_xof_into(hash);
+        self.xof.update(hash);
+        self.pos = 0;
+        self.blocks += 1;
+    }
+
+    /// Absorb the last (possibly partial) leaf and the number of blocks.
+    fn finalize_blocks(&mut self) {
+        if self.pos != 0 {
+            self.flush_leaf();
+        }
+        let mut buf = [0u8; 9];
+        self.xof.update(right_encode(self.blocks, &mut buf));
+    }
+
+    /// Write ParallelHash result with length equal to `out.len()` into `out`.
+    pub fn finalize_into(mut self, out: &mut [u8]) {
+        self.finalize_blocks();
+        let mut buf = [0u8; 9];
+        self.xof
+            .update(right_encode(8 * out.len() as u64, &mut buf));
+        self.xof.finalize_xof_into(out);
+    }
+
+    /// Retrieve ParallelHashXOF reader.
+    pub fn finalize_xof(mut self) -> X::Reader {
+        self.finalize_blocks();
+        let mut buf = [0u8; 9];
+        self.xof.update(right_encode(0, &mut buf));
+        self.xof.finalize_xof()
+    }
+}
+
+impl<X, L> Update for ParallelHash<X, L>
+where
+    X: ExtendableOutput + BlockSizeUser + Default,
+    L: ExtendableOutput + Default,
+{
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let n = core::cmp::min(self.block_size - self.pos, data.len());
+            let (head, tail) = data.split_at(n);
+            self.leaf.update(head);
+            self.pos += n;
+            if self.pos == self.block_size {
+                self.flush_leaf();
+            }
+            data = tail;
+        }
+    }
+}
+
+impl<X, L> fmt::Debug for ParallelHash<X, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ParallelHash { .. }")
+    }
+}
+
+/// Compute ParallelHash of `data` with block size `block_size` and
+/// customization string `s` and write result with length equal to
+/// `out.len()` into `out`.
+///
+/// See [`ParallelHash`] for requirements on `X` and `L`.
+///
+/// # Panics
+/// Under the same conditions as [`ParallelHash::new`].
+pub fn parallel_hash<X, L>(data: &[u8], block_size: usize, s: &[u8], out: &mut [u8])
+where
+    X: ExtendableOutput + BlockSizeUser + Default,
+    L: ExtendableOutput + Default,
+{
+    let mut hasher = ParallelHash::<X, L>::new(block_size, s);
+    hasher.update(data);
+    hasher.finalize_into(out);
+}
@@ -0,0 +1,39 @@
+use crate::Mac;
+use crypto_common::{Key, Output};
+
+const CHUNK_PREFIX: u8 = 0x00;
+const FINAL_PREFIX: u8 = 0x01;
+
+/// Compute MAC over a sequence of independent `chunks` using MAC `M`.
+///
+/// Every chunk is authenticated separately as `M(0x00 || i || chunk_i)`,
+/// where `i` is the chunk index, and the resulting tags are XOR-combined.
+/// The final tag is computed as `M(0x01 || n || combined)`, where `n` is
+/// the number of chunks, to prevent truncation and extension of the chunk
+/// sequence. Indices and number of chunks are encoded as 64-bit big-endian
+/// integers.
+///
+/// Since per-chunk tags are independent, the caller may compute them in
+/// parallel and the result does not depend on the order of evaluation.
+/// Reordering the chunks changes the resulting tag.
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub fn parallel_mac<M: Mac + Clone>(key: &Key<M>, chunks: &[&[u8]]) -> Output<M> {
+    let mac = <M as Mac>::new(key);
+    let mut combined = Output::<M>::default();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let mut m = mac.clone();
+        m.update(&[CHUNK_PREFIX]);
+        m.update(&(i as u64).to_be_bytes());
+        m.update(chunk);
+        let tag = m.finalize().into_bytes();
+        for (c, t) in combined.iter_mut().zip(tag.iter()) {
+            *c ^= t;
+        }
+    }
+
+    let mut m = mac;
+    m.update(&[FINAL_PREFIX]);
+    m.update(&(chunks.len() as u64).to_be_bytes());
+    m.update(&combined);
+    m.finalize().into_bytes()
+}
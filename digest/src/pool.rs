@@ -0,0 +1,101 @@
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use crypto_common::Reset;
+use std::sync::{Mutex, MutexGuard};
+use std::vec::Vec;
+
+/// Thread-safe pool of reusable hasher instances.
+///
+/// [`HasherPool::get`] returns a hasher in its initial state wrapped into
+/// a [`PooledHasher`] guard. On drop the guard resets the hasher and returns
+/// it to the pool, so following calls to `get` can reuse it instead of
+/// constructing a new instance.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct HasherPool<D> {
+    idle: Mutex<Vec<D>>,
+}
+
+impl<D: Default + Reset> HasherPool<D> {
+    /// Create new empty pool.
+    pub fn new() -> Self {
+        Self {
+            idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Get a hasher in its initial state from the pool.
+    ///
+    /// A new hasher is created using [`Default`] if the pool is empty.
+    pub fn get(&self) -> PooledHasher<'_, D> {
+        let hasher = self.lock().pop().unwrap_or_default();
+        PooledHasher {
+            pool: self,
+            hasher: Some(hasher),
+        }
+    }
+
+    /// Number of idle hashers stored in the pool.
+    pub fn idle_count(&self) -> usize {
+        self.lock().len()
+    }
+
+    // Hashers stored in the pool are always in a valid (reset) state,
+    // so it's safe to ignore poisoning.
+    fn lock(&self) -> MutexGuard<'_, Vec<D>> {
+        self.idle.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<D: Default + Reset> Default for HasherPool<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D> fmt::Debug for HasherPool<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HasherPool { .. }")
+    }
+}
+
+/// Hasher borrowed from a [`HasherPool`].
+///
+/// Derefs to the hasher. On drop the hasher is reset and returned
+/// to the pool.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct PooledHasher<'a, D: Default + Reset> {
+    pool: &'a HasherPool<D>,
+    // `None` only during drop
+    hasher: Option<D>,
+}
+
+impl<D: Default + Reset> Deref for PooledHasher<'_, D> {
+    type Target = D;
+
+    #[inline]
+    fn deref(&self) -> &D {
+        self.hasher.as_ref().expect("hasher is present until drop")
+    }
+}
+
+impl<D: Default + Reset> DerefMut for PooledHasher<'_, D> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut D {
+        self.hasher.as_mut().expect("hasher is present until drop")
+    }
+}
+
+impl<D: Default + Reset> Drop for PooledHasher<'_, D> {
+    fn drop(&mut self) {
+        if let Some(mut hasher) = self.hasher.take() {
+            hasher.reset();
+            self.pool.lock().push(hasher);
+        }
+    }
+}
+
+impl<D: Default + Reset> fmt::Debug for PooledHasher<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PooledHasher { .. }")
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Mac;
+use crypto_common::InvalidLength;
+use generic_array::typenum::Unsigned;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Maximum number of MAC output blocks which can be produced by [`Prf::prf`].
+const MAX_BLOCKS: u64 = core::u32::MAX as u64;
+
+/// Pseudorandom function producing output of arbitrary length.
+///
+/// This trait is implemented for all [`Mac`] types which implement [`Clone`]
+/// using the `P_hash` data expansion function defined in [RFC 5246]
+/// (TLS 1.2 PRF). For example, TLS 1.2 PRF for cipher suites using SHA-256
+/// is `Hmac<Sha256>` applied to the concatenation of label and seed.
+///
+/// Expansion does not branch on secret data.
+///
+/// [RFC 5246]: https://tools.ietf.org/html/rfc5246#section-5
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub trait Prf {
+    /// Fill `out` with PRF output computed over `input` using `key`.
+    ///
+    /// Returns [`InvalidLength`] if `key` is not supported by the underlying
+    /// MAC or if `out` is longer than `u32::MAX` MAC output blocks.
+    fn prf(key: &[u8], input: &[u8], out: &mut [u8]) -> Result<(), InvalidLength>;
+}
+
+impl<M: Mac + Clone> Prf for M {
+    fn prf(key: &[u8], input: &[u8], out: &mut [u8]) -> Result<(), InvalidLength> {
+        let bs = M::OutputSize::USIZE;
+        if out.len() as u64 > MAX_BLOCKS * bs as u64 {
+            return Err(InvalidLength);
+        }
+        let mac = M::new_from_slice(key)?;
+
+        // A(1) = MAC(key, A(0)), where A(0) = input
+        let mut a = {
+            let mut m = mac.clone();
+            m.update(input);
+            m.finalize().into_bytes()
+        };
+        let mut chunks = out.chunks_mut(bs).peekable();
+        while let Some(chunk) = chunks.next() {
+            let mut m = mac.clone();
+            m.update(&a);
+            m.update(input);
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let mut block = m.finalize().into_bytes();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            #[cfg(feature = "zeroize")]
+            block.as_mut_slice().zeroize();
+
+            if chunks.peek().is_some() {
+                let mut m = mac.clone();
+                m.update(&a);
+                a = m.finalize().into_bytes();
+            }
+        }
+        #[cfg(feature = "zeroize")]
+        a.as_mut_slice().zeroize();
+        Ok(())
+    }
+}
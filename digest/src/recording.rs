@@ -0,0 +1,82 @@
+use crate::{FixedOutput, FixedOutputReset, Reset, Update};
+use alloc::vec::Vec;
+use crypto_common::{Output, OutputSizeUser};
+
+/// Wrapper around [`Update`] implementors which records all processed data.
+///
+/// All data is forwarded to the inner type unchanged and additionally
+/// appended to an internal buffer, which allows to inspect the exact
+/// preimage of a computed hash when debugging hash mismatches.
+///
+/// This type is a debugging tool and should not be used in production:
+/// memory usage grows with amount of hashed data and sensitive inputs are
+/// retained in the buffer.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingUpdate<U> {
+    inner: U,
+    recorded: Vec<u8>,
+}
+
+impl<U> RecordingUpdate<U> {
+    /// Create new wrapper around `inner`.
+    #[inline]
+    pub fn new(inner: U) -> Self {
+        Self {
+            inner,
+            recorded: Vec::new(),
+        }
+    }
+
+    /// Get data processed since construction or the last reset.
+    #[inline]
+    pub fn recorded(&self) -> &[u8] {
+        &self.recorded
+    }
+
+    /// Get reference to the inner type.
+    #[inline]
+    pub fn get_ref(&self) -> &U {
+        &self.inner
+    }
+
+    /// Get the inner type and the recorded data, consuming the wrapper.
+    #[inline]
+    pub fn into_parts(self) -> (U, Vec<u8>) {
+        (self.inner, self.recorded)
+    }
+}
+
+impl<U: Update> Update for RecordingUpdate<U> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.recorded.extend_from_slice(data);
+        self.inner.update(data);
+    }
+}
+
+impl<U: Reset> Reset for RecordingUpdate<U> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.recorded.clear();
+    }
+}
+
+impl<U: OutputSizeUser> OutputSizeUser for RecordingUpdate<U> {
+    type OutputSize = U::OutputSize;
+}
+
+impl<U: FixedOutput> FixedOutput for RecordingUpdate<U> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.inner.finalize_into(out);
+    }
+}
+
+impl<U: FixedOutputReset> FixedOutputReset for RecordingUpdate<U> {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.inner.finalize_into_reset(out);
+        self.recorded.clear();
+    }
+}
@@ -0,0 +1,103 @@
+//! Non-cryptographic rolling hashes.
+//!
+//! Rolling hashes are computed over a sliding window of data and can be
+//! updated in constant time when the window is shifted by one byte, which
+//! makes them useful as a cheap signal for boundary detection and weak
+//! checksums (e.g. in rsync-like synchronization or content-defined
+//! chunking).
+//!
+//! **WARNING:** hashes defined in this module are NOT cryptographic! They
+//! are trivial to forge and must not be used in place of [`Digest`]
+//! implementations. Candidates found using a rolling hash should be
+//! confirmed using a cryptographic hash function.
+//!
+//! [`Digest`]: crate::Digest
+
+/// Hash computed over a sliding window of bytes.
+pub trait RollingHash {
+    /// Hash value type.
+    type Value: Copy + Eq;
+
+    /// Append `byte` to the window, increasing its length by one.
+    fn push(&mut self, byte: u8);
+
+    /// Shift the window by one byte, i.e. remove `out_byte` from its start
+    /// and append `in_byte` to its end.
+    ///
+    /// `out_byte` must be equal to the first byte of the current window,
+    /// otherwise the result is unspecified.
+    fn roll(&mut self, out_byte: u8, in_byte: u8);
+
+    /// Return hash value of the current window.
+    fn value(&self) -> Self::Value;
+
+    /// Append all bytes of `data` to the window.
+    #[inline]
+    fn extend(&mut self, data: &[u8]) {
+        for &b in data {
+            self.push(b);
+        }
+    }
+}
+
+/// Modulus used by Adler-32.
+const ADLER_MOD: u32 = 65_521;
+
+/// Adler-32 checksum ([RFC 1950]) with support for rolling.
+///
+/// Value of a window is equal to the Adler-32 checksum of its contents.
+///
+/// [RFC 1950]: https://tools.ietf.org/html/rfc1950#section-8
+#[derive(Clone, Debug)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+    /// Window length modulo 65521.
+    len: u32,
+}
+
+impl Adler32 {
+    /// Create new instance with an empty window.
+    pub fn new() -> Self {
+        Self { a: 1, b: 0, len: 0 }
+    }
+
+    /// Create new instance with window equal to `window`.
+    pub fn from_window(window: &[u8]) -> Self {
+        let mut res = Self::new();
+        res.extend(window);
+        res
+    }
+}
+
+impl Default for Adler32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RollingHash for Adler32 {
+    type Value = u32;
+
+    #[inline]
+    fn push(&mut self, byte: u8) {
+        self.a = (self.a + byte as u32) % ADLER_MOD;
+        self.b = (self.b + self.a) % ADLER_MOD;
+        self.len = (self.len + 1) % ADLER_MOD;
+    }
+
+    #[inline]
+    fn roll(&mut self, out_byte: u8, in_byte: u8) {
+        let out = out_byte as u32;
+        // a' = a - out + in
+        self.a = (self.a + ADLER_MOD - out + in_byte as u32) % ADLER_MOD;
+        // b' = b - len * out + a' - 1
+        let dec = (self.len * out + 1) % ADLER_MOD;
+        self.b = (self.b + ADLER_MOD - dec + self.a) % ADLER_MOD;
+    }
+
+    #[inline]
+    fn value(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
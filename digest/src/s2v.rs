@@ -0,0 +1,59 @@
+use crate::{cmac::dbl, Mac};
+use crypto_common::{Key, KeyInit, Output};
+use generic_array::typenum::Unsigned;
+
+/// Compute the S2V ("string to vector") function defined in [RFC 5297]
+/// over `strings` using MAC `M`.
+///
+/// RFC 5297 uses AES-CMAC as `M`, e.g. to derive synthetic IV in AES-SIV.
+/// Doubling in GF(2^n) is performed using the lexicographically first
+/// minimal polynomial for the given block size.
+///
+/// # Panics
+/// If output size of `M` is not equal to 8, 16, 32, or 64 bytes.
+///
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297#section-2.4
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub fn s2v<M: Mac + KeyInit + Clone>(key: &Key<M>, strings: &[&[u8]]) -> Output<M> {
+    let n = M::OutputSize::USIZE;
+    let mac = <M as Mac>::new(key);
+    let compute = |data: &[u8]| {
+        let mut m = mac.clone();
+        Mac::update(&mut m, data);
+        m.finalize().into_bytes()
+    };
+
+    let (last, init) = match strings.split_last() {
+        Some(v) => v,
+        None => {
+            let mut one = Output::<M>::default();
+            one[n - 1] = 1;
+            return compute(&one);
+        }
+    };
+
+    let mut d = compute(&Output::<M>::default());
+    for s in init {
+        dbl(&mut d);
+        xor(&mut d, &compute(s));
+    }
+
+    let mut m = mac.clone();
+    if last.len() >= n {
+        let (head, tail) = last.split_at(last.len() - n);
+        xor(&mut d, tail);
+        Mac::update(&mut m, head);
+    } else {
+        dbl(&mut d);
+        xor(&mut d[..last.len()], last);
+        d[last.len()] ^= 0x80;
+    }
+    Mac::update(&mut m, &d);
+    m.finalize().into_bytes()
+}
+
+fn xor(a: &mut [u8], b: &[u8]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a ^= b;
+    }
+}
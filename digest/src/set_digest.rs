@@ -0,0 +1,42 @@
+use crate::Digest;
+use crypto_common::Output;
+
+/// Compute order-independent digest of `items`.
+///
+/// Every item is hashed individually and the digests are combined using
+/// addition modulo `2^n`, where `n` is the output size of `D` in bits and
+/// digests are interpreted as big-endian integers. Since addition is
+/// commutative, the result does not depend on the iteration order.
+/// Digest of an empty collection is equal to zero.
+///
+/// The result has multiset semantics: duplicate items are counted, so
+/// e.g. `[a, a]` and `[a]` produce different results. For set semantics
+/// duplicates should be removed before hashing. XOR is not used for
+/// combining, because it cancels out pairs of duplicates.
+///
+/// # Security
+/// Additive combining is weaker than hashing of a serialized collection.
+/// If an attacker is able to choose many items, collisions can be found
+/// significantly faster than by a birthday attack on `D` using
+/// generalized birthday algorithms, so outputs smaller than 256 bits should
+/// not be used in adversarial settings. If a canonical ordering of items
+/// is available (e.g. sorting by value), hashing the ordered items
+/// with length prefixes should be preferred.
+pub fn set_digest<D, I>(items: I) -> Output<D>
+where
+    D: Digest,
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut acc = Output::<D>::default();
+    for item in items {
+        let hash = D::digest(item.as_ref());
+        let mut carry = 0u16;
+        for (a, h) in acc.iter_mut().zip(hash.iter()).rev() {
+            let sum = *a as u16 + *h as u16 + carry;
+            *a = sum as u8;
+            carry = sum >> 8;
+        }
+    }
+    acc
+}
@@ -0,0 +1,57 @@
+use crate::encoding::encode_hex;
+use alloc::string::String;
+use crypto_common::{Output, OutputSizeUser};
+
+/// Derive sharded content-addressed path from hash `out`.
+///
+/// The hash is encoded as lower-case hex, with a `/` separator inserted
+/// after each prefix level, where `prefix_bytes` contains numbers of hash
+/// bytes used by every level. The remaining bytes form the last component.
+/// For example, levels `[1]` produce git-like paths `ab/cdef..`, while
+/// levels `[1, 1]` produce `ab/cd/ef..`. Removing the separators restores
+/// hex encoding of the whole hash.
+///
+/// # Panics
+/// If any level is equal to zero or if the levels in total span the whole
+/// hash or more, i.e. the last component would be empty.
+///
+/// # Example
+/// ```
+/// use digest::shard_path;
+/// use sha2::{Digest, Sha256};
+///
+/// let hash = Sha256::digest(b"hello world");
+/// let path = shard_path::<Sha256>(&hash, &[1, 1]);
+/// assert_eq!(
+///     path,
+///     "b9/4d/27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+/// );
+/// ```
+pub fn shard_path<D: OutputSizeUser>(out: &Output<D>, prefix_bytes: &[usize]) -> String {
+    assert!(
+        prefix_bytes.iter().all(|&n| n != 0),
+        "prefix levels must not be empty"
+    );
+    let total = prefix_bytes
+        .iter()
+        .fold(0usize, |acc, &n| acc.saturating_add(n));
+    assert!(
+        total < out.len(),
+        "prefix levels must be shorter than hash output"
+    );
+
+    let mut res = String::with_capacity(2 * out.len() + prefix_bytes.len());
+    let mut levels = prefix_bytes.iter();
+    let mut next = levels.next().copied();
+    let mut n = 0;
+    let mut buf = [0u8; 2];
+    for (i, &b) in out.iter().enumerate() {
+        if next == Some(i - n) {
+            res.push('/');
+            n = i;
+            next = levels.next().copied();
+        }
+        res.push_str(encode_hex(&[b], &mut buf));
+    }
+    res
+}
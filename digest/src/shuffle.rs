@@ -0,0 +1,107 @@
+use crate::XofReader;
+
+#[cfg(feature = "mac")]
+use crate::Mac;
+#[cfg(feature = "mac")]
+use crypto_common::{Key, KeyInit, Output};
+
+/// Shuffle `slice` in place using output of the XOF `reader` as
+/// a source of randomness.
+///
+/// The shuffle is performed using the Fisher–Yates algorithm, i.e. for
+/// `i` going from `slice.len() - 1` down to 1 the element at index `i` is
+/// swapped with the element at index `j` drawn uniformly from `0..=i`
+/// using [`next_below`]. Since resulting permutation depends only on the XOF
+/// output, anyone who knows the XOF input can reproduce and verify it.
+pub fn shuffle<X: XofReader, T>(reader: &mut X, slice: &mut [T]) {
+    for i in (1..slice.len()).rev() {
+        let j = next_below(reader, i as u64 + 1);
+        slice.swap(i, j as usize);
+    }
+}
+
+/// Shuffle `slice` in place deterministically using MAC `M` keyed with
+/// `key` as a source of randomness.
+///
+/// A seed is computed as `M(key, context)` and expanded into a stream
+/// of blocks `M(key, seed || i)` for `i = 0, 1, ..`, where `i` is encoded as
+/// a 64-bit big-endian integer. The stream is used by [`shuffle`], so anyone
+/// who knows `key` and `context` can reproduce and verify the permutation,
+/// but it's unpredictable for parties which do not know `key`.
+#[cfg(feature = "mac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+pub fn keyed_shuffle<M: Mac + KeyInit + Clone, T>(key: &Key<M>, context: &[u8], slice: &mut [T]) {
+    let mac = <M as KeyInit>::new(key);
+    let mut m = mac.clone();
+    m.update(context);
+    let seed = m.finalize().into_bytes();
+    let block = Output::<M>::default();
+    let pos = block.len();
+    let mut reader = MacReader {
+        mac,
+        seed,
+        counter: 0,
+        block,
+        pos,
+    };
+    shuffle(&mut reader, slice);
+}
+
+/// Reader over the MAC-based stream used by [`keyed_shuffle`].
+#[cfg(feature = "mac")]
+struct MacReader<M: Mac + Clone> {
+    mac: M,
+    seed: Output<M>,
+    counter: u64,
+    block: Output<M>,
+    pos: usize,
+}
+
+#[cfg(feature = "mac")]
+impl<M: Mac + Clone> XofReader for MacReader<M> {
+    fn read(&mut self, buffer: &mut [u8]) {
+        for b in buffer {
+            if self.pos == self.block.len() {
+                let mut m = self.mac.clone();
+                m.update(&self.seed);
+                m.update(&self.counter.to_be_bytes());
+                self.block = m.finalize().into_bytes();
+                self.counter += 1;
+                self.pos = 0;
+            }
+            *b = self.block[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+/// Draw an integer uniformly distributed in `0..n` using output of the XOF
+/// `reader` as a source of randomness.
+///
+/// Each candidate is read from `reader` as a little-endian integer using
+/// the minimal number of bytes sufficient for representing `n - 1`, masked
+/// to the bit length of `n - 1`, and rejected if it's not smaller than `n`.
+/// Thus on average less than two candidates are read and the result does
+/// not have modulo bias. If `n` is equal to 1, nothing is read from `reader`.
+///
+/// # Panics
+/// If `n` is equal to zero.
+pub fn next_below<X: XofReader>(reader: &mut X, n: u64) -> u64 {
+    assert!(n != 0, "upper bound must not be zero");
+    let max = n - 1;
+    if max == 0 {
+        return 0;
+    }
+    let bits = 64 - max.leading_zeros();
+    let rem = bits % 8;
+    let bytes = (bits / 8 + (rem != 0) as u32) as usize;
+    let mask = !0u64 >> (64 - bits);
+    loop {
+        let mut buf = [0u8; 8];
+        reader.read(&mut buf[..bytes]);
+        let r = u64::from_le_bytes(buf) & mask;
+        if r < n {
+            return r;
+        }
+    }
+}
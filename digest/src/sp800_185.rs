@@ -0,0 +1,65 @@
+//! Encoding functions defined in NIST SP 800-185 section 2.3.
+use crate::Update;
+use crypto_common::BlockSizeUser;
+use generic_array::typenum::Unsigned;
+
+/// Number of bytes in the minimal big-endian encoding of `x` (at least 1).
+fn encoded_len(x: u64) -> usize {
+    core::cmp::max(1, 8 - (x.leading_zeros() / 8) as usize)
+}
+
+/// Compute `left_encode(x)` using `buf` as storage.
+pub(crate) fn left_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = encoded_len(x);
+    buf[1..].copy_from_slice(&x.to_be_bytes());
+    buf[8 - n] = n as u8;
+    &buf[8 - n..]
+}
+
+/// Compute `right_encode(x)` using `buf` as storage.
+pub(crate) fn right_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = encoded_len(x);
+    buf[..8].copy_from_slice(&x.to_be_bytes());
+    buf[8] = n as u8;
+    &buf[8 - n..]
+}
+
+/// Absorb `encode_string(s)` and return number of absorbed bytes.
+pub(crate) fn encode_string<U: Update>(state: &mut U, s: &[u8]) -> usize {
+    let mut buf = [0u8; 9];
+    let len = left_encode(8 * s.len() as u64, &mut buf);
+    state.update(len);
+    state.update(s);
+    len.len() + s.len()
+}
+
+/// Absorb `bytepad(encode_string(s_1) || .. || encode_string(s_n), rate)`.
+pub(crate) fn bytepad_strings<U: Update>(state: &mut U, rate: usize, strings: &[&[u8]]) {
+    let mut buf = [0u8; 9];
+    let w = left_encode(rate as u64, &mut buf);
+    state.update(w);
+    let mut n = w.len();
+    for s in strings {
+        n += encode_string(state, s);
+    }
+    let mut pad = (rate - n % rate) % rate;
+    let zeros = [0u8; 64];
+    while pad != 0 {
+        let len = core::cmp::min(pad, zeros.len());
+        state.update(&zeros[..len]);
+        pad -= len;
+    }
+}
+
+/// Initialize cSHAKE with function name `n` and customization string `s`.
+///
+/// `X` must be a Keccak sponge which uses the cSHAKE domain separation, with
+/// rate equal to its block size.
+pub(crate) fn cshake_init<X>(n: &[u8], s: &[u8]) -> X
+where
+    X: Update + BlockSizeUser + Default,
+{
+    let mut state = X::default();
+    bytepad_strings(&mut state, X::BlockSize::USIZE, &[n, s]);
+    state
+}
@@ -0,0 +1,64 @@
+use crate::Update;
+use std::io::{self, Write};
+
+/// Writer which forwards written data both to an inner [`Write`]
+/// implementor and to a hasher.
+///
+/// Only bytes accepted by the inner writer are passed to the hasher, so
+/// the resulting hash always corresponds to data which was actually written.
+/// This allows to save a stream (e.g. a downloaded file) and compute its
+/// hash in one pass.
+#[derive(Clone, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct TeeHash<W, H> {
+    writer: W,
+    hasher: H,
+}
+
+impl<W: Write, H: Update> TeeHash<W, H> {
+    /// Create new tee over `writer` and `hasher`.
+    #[inline]
+    pub fn new(writer: W, hasher: H) -> Self {
+        Self { writer, hasher }
+    }
+
+    /// Get reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get reference to the hasher.
+    #[inline]
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Get the hasher and consume the tee.
+    ///
+    /// Note that the inner writer gets dropped without being flushed.
+    #[inline]
+    pub fn into_hasher(self) -> H {
+        self.hasher
+    }
+
+    /// Get the inner writer and the hasher and consume the tee.
+    #[inline]
+    pub fn into_parts(self) -> (W, H) {
+        (self.writer, self.hasher)
+    }
+}
+
+impl<W: Write, H: Update> Write for TeeHash<W, H> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.writer.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
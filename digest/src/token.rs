@@ -0,0 +1,90 @@
+//! Expiring tokens authenticated using MAC, e.g. for signed URLs.
+//!
+//! A token is equal to `expiry || MAC(key, data || expiry)` encoded using
+//! the URL-safe base64 alphabet without padding ([RFC 4648 section 5]),
+//! where `expiry` is the expiration time in seconds since the Unix epoch
+//! encoded as a 64-bit little-endian integer. `data` is not included in
+//! the token and must be provided again on verification.
+//!
+//! [RFC 4648 section 5]: https://tools.ietf.org/html/rfc4648#section-5
+
+use crate::{encoding::Alphabet, Mac};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use crypto_common::{Key, OutputSizeUser};
+use generic_array::typenum::Unsigned;
+
+const B64: Alphabet = Alphabet::Base64Url;
+
+/// Token verification error.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TokenError {
+    /// Token is authentic, but has expired.
+    Expired,
+    /// Token is malformed or not authentic.
+    ///
+    /// The verification does not reveal which part of the token is invalid.
+    Invalid,
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TokenError::Expired => "token has expired",
+            TokenError::Invalid => "invalid token",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TokenError {}
+
+fn compute_tag<M: Mac>(key: &Key<M>, data: &[u8], expiry: &[u8]) -> M {
+    let mut mac = <M as Mac>::new(key);
+    mac.update(data);
+    mac.update(expiry);
+    mac
+}
+
+/// Create token for `data` which expires at `expiry_unix` using MAC `M`.
+pub fn make_token<M: Mac>(key: &Key<M>, data: &[u8], expiry_unix: u64) -> String {
+    let expiry = expiry_unix.to_le_bytes();
+    let tag = compute_tag::<M>(key, data, &expiry).finalize().into_bytes();
+
+    let mut raw = Vec::with_capacity(expiry.len() + tag.len());
+    raw.extend_from_slice(&expiry);
+    raw.extend_from_slice(&tag);
+    let mut res = String::with_capacity(B64.encoded_len(raw.len()));
+    B64.encode(&raw, &mut res);
+    res
+}
+
+/// Verify `token` for `data` at time `now_unix`.
+///
+/// The token is decoded and its tag is checked in constant time. Expiration
+/// is checked only for authentic tokens, so [`TokenError::Expired`] can be
+/// safely revealed to the token holder. Token is considered expired if
+/// `now_unix` is equal to or bigger than its expiration time.
+pub fn verify_token<M: Mac>(
+    key: &Key<M>,
+    data: &[u8],
+    token: &str,
+    now_unix: u64,
+) -> Result<(), TokenError> {
+    let mut raw = vec![0u8; 8 + <M as OutputSizeUser>::OutputSize::USIZE];
+    if B64.decode(token.as_bytes(), &mut raw, false) != 1 {
+        return Err(TokenError::Invalid);
+    }
+    let (expiry, tag) = raw.split_at(8);
+    compute_tag::<M>(key, data, expiry)
+        .verify_slice(tag)
+        .map_err(|_| TokenError::Invalid)?;
+
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(expiry);
+    if now_unix >= u64::from_le_bytes(buf) {
+        Err(TokenError::Expired)
+    } else {
+        Ok(())
+    }
+}
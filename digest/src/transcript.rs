@@ -0,0 +1,77 @@
+use crate::{ExtendableOutput, XofReader};
+use core::fmt;
+
+/// Fiat–Shamir transcript which absorbs labeled messages and produces
+/// labeled challenges.
+///
+/// Challenges must depend on the whole transcript history, including labels,
+/// message boundaries, and order of operations.
+pub trait Transcript {
+    /// Absorb message `data` labeled with `label`.
+    fn absorb(&mut self, label: &[u8], data: &[u8]);
+
+    /// Fill `out` with a challenge labeled with `label`.
+    ///
+    /// The challenge gets bound into the transcript, so following
+    /// challenges differ even if no messages were absorbed in between.
+    fn challenge(&mut self, label: &[u8], out: &mut [u8]);
+}
+
+const OP_ABSORB: u8 = 0x01;
+const OP_CHALLENGE: u8 = 0x02;
+
+/// [`Transcript`] implemented over an extendable-output function.
+///
+/// Every operation is framed as a one-byte operation code followed by the
+/// label and the message (for challenges, the challenge length encoded as
+/// a 64-bit little-endian integer), both prefixed with their length encoded
+/// as a 64-bit little-endian integer. A challenge is produced by finalizing
+/// a copy of the XOF state after framing the challenge request.
+#[derive(Clone, Default)]
+pub struct XofTranscript<X> {
+    xof: X,
+}
+
+impl<X: ExtendableOutput + Clone> XofTranscript<X> {
+    /// Create new transcript for the protocol identified by `domain`.
+    pub fn new(domain: &[u8]) -> Self
+    where
+        X: Default,
+    {
+        Self::from_xof(X::default(), domain)
+    }
+
+    /// Create new transcript over `xof` for the protocol identified
+    /// by `domain`.
+    pub fn from_xof(xof: X, domain: &[u8]) -> Self {
+        let mut t = Self { xof };
+        t.absorb(b"domain", domain);
+        t
+    }
+
+    fn frame(&mut self, op: u8, label: &[u8], data: &[u8]) {
+        self.xof.update(&[op]);
+        self.xof.update(&(label.len() as u64).to_le_bytes());
+        self.xof.update(label);
+        self.xof.update(&(data.len() as u64).to_le_bytes());
+        self.xof.update(data);
+    }
+}
+
+impl<X: ExtendableOutput + Clone> Transcript for XofTranscript<X> {
+    fn absorb(&mut self, label: &[u8], data: &[u8]) {
+        self.frame(OP_ABSORB, label, data);
+    }
+
+    fn challenge(&mut self, label: &[u8], out: &mut [u8]) {
+        let len = (out.len() as u64).to_le_bytes();
+        self.frame(OP_CHALLENGE, label, &len);
+        self.xof.clone().finalize_xof().read(out);
+    }
+}
+
+impl<X> fmt::Debug for XofTranscript<X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("XofTranscript { .. }")
+    }
+}
@@ -0,0 +1,94 @@
+//! TupleHash.
+//!
+//! TupleHash is specified in [NIST SP 800-185] on top of cSHAKE. It hashes
+//! a sequence of byte strings with every element encoded together with its
+//! length, so e.g. tuples `("ab", "c")` and `("a", "bc")` produce unrelated
+//! results. This module implements it generically over a type implementing
+//! the cSHAKE sponge, so TupleHash128 and TupleHash256 are obtained by using
+//! cSHAKE128 and cSHAKE256 respectively.
+//!
+//! [NIST SP 800-185]: https://csrc.nist.gov/publications/detail/sp/800-185/final
+use crate::sp800_185::{cshake_init, encode_string, right_encode};
+use crate::ExtendableOutput;
+use core::fmt;
+use crypto_common::BlockSizeUser;
+
+/// Function name used by TupleHash for cSHAKE domain separation.
+const FUNCTION_NAME: &[u8] = b"TupleHash";
+
+/// TupleHash generic over the cSHAKE sponge `X`.
+///
+/// `X` must be a Keccak sponge which uses the cSHAKE domain separation
+/// (i.e. `0x04` suffix applied during finalization, see
+/// [`FinalizationTweak`][crate::core_api::FinalizationTweak]) and starts
+/// with an empty state, i.e. cSHAKE with function name and customization
+/// string not absorbed yet. Its block size must be equal to its rate
+/// (168 bytes for TupleHash128 and 136 bytes for TupleHash256).
+///
+/// The requested output length is bound to the result, so outputs of
+/// different lengths are unrelated. [`TupleHash::finalize_xof`] implements
+/// TupleHashXOF, which does not bind the output length.
+#[derive(Clone)]
+pub struct TupleHash<X> {
+    xof: X,
+}
+
+impl<X> TupleHash<X>
+where
+    X: ExtendableOutput + BlockSizeUser + Default,
+{
+    /// Create new TupleHash instance using customization string `s`.
+    pub fn new(s: &[u8]) -> Self {
+        Self {
+            xof: cshake_init(FUNCTION_NAME, s),
+        }
+    }
+
+    /// Append `element` to the hashed tuple.
+    pub fn push(&mut self, element: &[u8]) {
+        encode_string(&mut self.xof, element);
+    }
+
+    /// Append `element` to the hashed tuple in a chained manner.
+    #[must_use]
+    pub fn chain(mut self, element: &[u8]) -> Self {
+        self.push(element);
+        self
+    }
+
+    /// Write TupleHash result with length equal to `out.len()` into `out`.
+    pub fn finalize_into(mut self, out: &mut [u8]) {
+        let mut buf = [0u8; 9];
+        self.xof
+            .update(right_encode(8 * out.len() as u64, &mut buf));
+        self.xof.finalize_xof_into(out);
+    }
+
+    /// Retrieve TupleHashXOF reader.
+    pub fn finalize_xof(mut self) -> X::Reader {
+        let mut buf = [0u8; 9];
+        self.xof.update(right_encode(0, &mut buf));
+        self.xof.finalize_xof()
+    }
+}
+
+impl<X> fmt::Debug for TupleHash<X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TupleHash { .. }")
+    }
+}
+
+/// Compute TupleHash of `elements` with customization string `s` and write
+/// result with length equal to `out.len()` into `out`.
+///
+/// See [`TupleHash`] for requirements on `X`.
+pub fn tuple_hash<X>(elements: &[&[u8]], s: &[u8], out: &mut [u8])
+where
+    X: ExtendableOutput + BlockSizeUser + Default,
+{
+    let mut hasher = TupleHash::<X>::new(s);
+    for element in elements {
+        hasher.push(element);
+    }
+    hasher.finalize_into(out);
+}
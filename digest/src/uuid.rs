@@ -0,0 +1,24 @@
+use crate::ExtendableOutput;
+
+/// Derive deterministic UUID from `namespace` and `name` using XOF `X`.
+///
+/// The XOF is updated with `namespace` prefixed by its length (see
+/// [`Update::update_with_len`]) followed by `name`, and the first 16 bytes
+/// of its output are used as a UUID with version field set to 8 (custom)
+/// and variant field set to the [RFC 9562] variant. This is an analog of
+/// the name-based UUIDv5, so the same inputs always produce the same UUID.
+///
+/// The result is returned in the big-endian (network) byte order.
+///
+/// [`Update::update_with_len`]: crate::Update::update_with_len
+/// [RFC 9562]: https://www.rfc-editor.org/rfc/rfc9562#section-5.8
+pub fn derive_uuid<X: ExtendableOutput + Default>(namespace: &[u8], name: &[u8]) -> [u8; 16] {
+    let mut xof = X::default();
+    xof.update_with_len(namespace);
+    xof.update(name);
+    let mut uuid = [0u8; 16];
+    xof.finalize_xof_into(&mut uuid);
+    uuid[6] = (uuid[6] & 0x0f) | 0x80;
+    uuid[8] = (uuid[8] & 0x3f) | 0x80;
+    uuid
+}
@@ -0,0 +1,113 @@
+use crate::{ExtendableOutput, Reset, Update, VariableOutput, XofReader};
+use alloc::boxed::Box;
+use core::fmt;
+
+/// Adapter which presents a [`VariableOutput`] hasher as an
+/// [`ExtendableOutput`] one.
+///
+/// The inner hasher is created with output size equal to
+/// [`VariableOutput::MAX_OUTPUT_SIZE`]. On finalization the whole output is
+/// computed and reads are served from it.
+///
+/// Unlike true XOFs the output is bounded: at most `MAX_OUTPUT_SIZE`
+/// bytes can be read from [`VarAsXofReader`]. Also note that for most
+/// variable output hashes results of different lengths are not related to
+/// each other, i.e. the first `n` bytes read from the adapter are not equal
+/// to the hash of size `n`.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct VarAsXof<V> {
+    inner: V,
+}
+
+impl<V: VariableOutput> VarAsXof<V> {
+    /// Create new adapter.
+    ///
+    /// # Panics
+    /// If the inner hasher does not support its maximum output size.
+    pub fn new() -> Self {
+        let inner = V::new(V::MAX_OUTPUT_SIZE).expect("hasher supports its maximum output size");
+        Self { inner }
+    }
+
+    /// Get the inner hasher and consume the adapter.
+    pub fn into_inner(self) -> V {
+        self.inner
+    }
+}
+
+impl<V: VariableOutput> Default for VarAsXof<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: VariableOutput> Update for VarAsXof<V> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+}
+
+impl<V: VariableOutput> ExtendableOutput for VarAsXof<V> {
+    type Reader = VarAsXofReader;
+
+    fn finalize_xof(self) -> VarAsXofReader {
+        VarAsXofReader {
+            buf: self.inner.finalize_boxed(),
+            pos: 0,
+        }
+    }
+}
+
+impl<V: VariableOutput + Reset> Reset for VarAsXof<V> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<V> fmt::Debug for VarAsXof<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("VarAsXof { .. }")
+    }
+}
+
+/// Reader returned by [`VarAsXof`].
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct VarAsXofReader {
+    buf: Box<[u8]>,
+    pos: usize,
+}
+
+impl VarAsXofReader {
+    /// Get number of bytes which can be read.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+}
+
+impl XofReader for VarAsXofReader {
+    /// Read output into the `buffer`.
+    ///
+    /// # Panics
+    /// If length of `buffer` is bigger than the number of remaining bytes.
+    fn read(&mut self, buffer: &mut [u8]) {
+        assert!(
+            buffer.len() <= self.remaining(),
+            "read beyond maximum output size of variable output hasher",
+        );
+        let end = self.pos + buffer.len();
+        buffer.copy_from_slice(&self.buf[self.pos..end]);
+        self.pos = end;
+    }
+}
+
+impl fmt::Debug for VarAsXofReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VarAsXofReader")
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{ExtendableOutput, XofReader};
+use core::fmt;
+use rand_core::{impls, Error, RngCore, SeedableRng};
+
+/// Deterministic random number generator backed by output of an
+/// extendable-output function (XOF).
+///
+/// The generator reads bytes from a finalized [`XofReader`]. Integers are
+/// read in little-endian byte order, i.e. [`RngCore::next_u64`] is equal to
+/// `u64::from_le_bytes` applied to the next 8 bytes of XOF output.
+///
+/// Note that the generator provides only security guarantees of
+/// the underlying XOF.
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub struct XofRng<X: ExtendableOutput> {
+    reader: X::Reader,
+}
+
+impl<X: ExtendableOutput> XofRng<X> {
+    /// Create new generator from a finalized XOF reader.
+    #[inline]
+    pub fn from_reader(reader: X::Reader) -> Self {
+        Self { reader }
+    }
+
+    /// Create new generator by absorbing `seed` into `xof` and finalizing it.
+    #[inline]
+    pub fn from_xof(mut xof: X, seed: &[u8]) -> Self {
+        xof.update(seed);
+        Self::from_reader(xof.finalize_xof())
+    }
+
+    /// Get the inner XOF reader.
+    #[inline]
+    pub fn into_reader(self) -> X::Reader {
+        self.reader
+    }
+}
+
+impl<X: ExtendableOutput> RngCore for XofRng<X> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.reader.read(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<X: ExtendableOutput + Default> SeedableRng for XofRng<X> {
+    type Seed = [u8; 32];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_xof(X::default(), &seed)
+    }
+}
+
+impl<X: ExtendableOutput> Clone for XofRng<X>
+where
+    X::Reader: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_reader(self.reader.clone())
+    }
+}
+
+impl<X: ExtendableOutput> fmt::Debug for XofRng<X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("XofRng { .. }")
+    }
+}
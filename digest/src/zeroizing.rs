@@ -0,0 +1,78 @@
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use crypto_common::{Output, OutputSizeUser};
+use zeroize::Zeroize;
+
+/// Wrapper around [`Output`] which zeroizes the finalized bytes on drop.
+///
+/// Useful for holding MAC tags or derived key material. Note that this
+/// type does not zeroize the state of the hasher which produced the output.
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub struct ZeroizingOutput<T: OutputSizeUser> {
+    bytes: Output<T>,
+}
+
+impl<T: OutputSizeUser> ZeroizingOutput<T> {
+    /// Create a new wrapper around the given output.
+    #[inline(always)]
+    pub fn new(bytes: Output<T>) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<T: OutputSizeUser> Clone for ZeroizingOutput<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new(self.bytes.clone())
+    }
+}
+
+impl<T: OutputSizeUser> From<Output<T>> for ZeroizingOutput<T> {
+    #[inline(always)]
+    fn from(bytes: Output<T>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<T: OutputSizeUser> Deref for ZeroizingOutput<T> {
+    type Target = Output<T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Output<T> {
+        &self.bytes
+    }
+}
+
+impl<T: OutputSizeUser> DerefMut for ZeroizingOutput<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Output<T> {
+        &mut self.bytes
+    }
+}
+
+impl<T: OutputSizeUser> AsRef<[u8]> for ZeroizingOutput<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<T: OutputSizeUser> Zeroize for ZeroizingOutput<T> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.bytes.as_mut_slice().zeroize();
+    }
+}
+
+impl<T: OutputSizeUser> Drop for ZeroizingOutput<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: OutputSizeUser> fmt::Debug for ZeroizingOutput<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZeroizingOutput { .. }")
+    }
+}
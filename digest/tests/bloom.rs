@@ -0,0 +1,66 @@
+//! Tests for Bloom filters.
+#![cfg(feature = "alloc")]
+
+use digest::bloom::BloomFilter;
+use sha2::Sha256;
+
+fn item(i: u32) -> [u8; 4] {
+    i.to_le_bytes()
+}
+
+#[test]
+fn no_false_negatives() {
+    let mut filter = BloomFilter::<Sha256>::new(10_000, 5);
+    assert_eq!(filter.m(), 10_000);
+    assert_eq!(filter.k(), 5);
+    assert!(!(0..1000).any(|i| filter.contains(&item(i))));
+
+    for i in 0..1000 {
+        filter.insert(&item(i));
+    }
+    assert!((0..1000).all(|i| filter.contains(&item(i))));
+
+    filter.clear();
+    assert!(!(0..1000).any(|i| filter.contains(&item(i))));
+}
+
+#[test]
+fn false_positive_rate() {
+    const M: usize = 8192;
+    const K: u32 = 7;
+    const N: u32 = 1000;
+    const QUERIES: u32 = 20_000;
+
+    let mut filter = BloomFilter::<Sha256>::new(M, K);
+    for i in 0..N {
+        filter.insert(&item(i));
+    }
+    let fp = (N..N + QUERIES)
+        .filter(|&i| filter.contains(&item(i)))
+        .count();
+
+    let k = f64::from(K);
+    let expected = (1.0 - (-k * f64::from(N) / M as f64).exp()).powf(k) * f64::from(QUERIES);
+    let fp = fp as f64;
+    assert!(
+        expected / 2.0 < fp && fp < expected * 2.0,
+        "{} false positives, expected approximately {}",
+        fp,
+        expected,
+    );
+}
+
+#[test]
+fn single_bit() {
+    let mut filter = BloomFilter::<Sha256>::new(1, 3);
+    assert!(!filter.contains(b"foo"));
+    filter.insert(b"foo");
+    // with a single bit every item is reported as present
+    assert!(filter.contains(b"bar"));
+}
+
+#[test]
+#[should_panic]
+fn zero_bits() {
+    BloomFilter::<Sha256>::new(0, 3);
+}
@@ -0,0 +1,104 @@
+//! Tests for the `HashCanonical` trait.
+use digest::{Digest, HashCanonical, Update};
+use hex_literal::hex;
+use sha2::Sha256;
+
+struct Record<'a> {
+    id: u32,
+    name: &'a str,
+    tags: &'a [&'a str],
+    parent: Option<u64>,
+    public: bool,
+}
+
+impl<'a> HashCanonical for Record<'a> {
+    fn update_canonical<U: Update>(&self, state: &mut U) {
+        self.id.update_canonical(state);
+        self.name.update_canonical(state);
+        self.tags.update_canonical(state);
+        self.parent.update_canonical(state);
+        self.public.update_canonical(state);
+    }
+}
+
+const RECORD: Record<'static> = Record {
+    id: 7,
+    name: "readme",
+    tags: &["doc", "text"],
+    parent: Some(1),
+    public: true,
+};
+
+#[test]
+fn encoding() {
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&7u32.to_le_bytes());
+    expected.extend_from_slice(&6u64.to_le_bytes());
+    expected.extend_from_slice(b"readme");
+    expected.extend_from_slice(&2u64.to_le_bytes());
+    expected.extend_from_slice(&3u64.to_le_bytes());
+    expected.extend_from_slice(b"doc");
+    expected.extend_from_slice(&4u64.to_le_bytes());
+    expected.extend_from_slice(b"text");
+    expected.extend_from_slice(&[1]);
+    expected.extend_from_slice(&1u64.to_le_bytes());
+    expected.extend_from_slice(&[1]);
+    assert_eq!(RECORD.hash_canonical::<Sha256>(), Sha256::digest(&expected));
+}
+
+#[test]
+fn stable_digest() {
+    assert_eq!(
+        RECORD.hash_canonical::<Sha256>()[..],
+        hex!("b3901345660d7eac7031d605d11540195fd7e21ef62c10b3475cbf08c1881037")[..],
+    );
+}
+
+#[test]
+fn unambiguous_boundaries() {
+    let a = ("ab", "c").hash_canonical::<Sha256>();
+    let b = ("a", "bc").hash_canonical::<Sha256>();
+    assert_ne!(a, b);
+
+    let none: Option<u8> = None;
+    assert_ne!(
+        none.hash_canonical::<Sha256>(),
+        Some(0u8).hash_canonical::<Sha256>()
+    );
+    assert_ne!(
+        1usize.hash_canonical::<Sha256>(),
+        1u32.hash_canonical::<Sha256>()
+    );
+    assert_eq!(
+        1usize.hash_canonical::<Sha256>(),
+        1u64.hash_canonical::<Sha256>()
+    );
+    assert_eq!(
+        (-1isize).hash_canonical::<Sha256>(),
+        core::u64::MAX.hash_canonical::<Sha256>()
+    );
+
+    let nested: &[&[u8]] = &[&[1, 2], &[3]];
+    let flat: &[&[u8]] = &[&[1], &[2, 3]];
+    assert_ne!(
+        nested.hash_canonical::<Sha256>(),
+        flat.hash_canonical::<Sha256>()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn alloc_types() {
+    let s = String::from("readme");
+    assert_eq!(
+        s.hash_canonical::<Sha256>(),
+        "readme".hash_canonical::<Sha256>()
+    );
+    let v = vec![1u16, 2, 3];
+    assert_eq!(
+        v.hash_canonical::<Sha256>(),
+        v[..].hash_canonical::<Sha256>()
+    );
+    let b = Box::new(5u8);
+    assert_eq!(b.hash_canonical::<Sha256>(), 5u8.hash_canonical::<Sha256>());
+}
@@ -0,0 +1,113 @@
+//! Tests for the `CapacityTag` trait.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U8,
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, CapacityTag, CoreWrapper, FixedOutputCore,
+        OutputSizeUser, UpdateCore,
+    },
+    Digest, HashMarker, Output,
+};
+
+/// Toy sponge with 8 byte rate and 8 byte capacity.
+#[derive(Clone, Default)]
+struct ToySponge {
+    state: [u8; 16],
+}
+
+impl ToySponge {
+    fn permute(&mut self) {
+        for r in 0..16 {
+            let prev = self.state[(r + 15) % 16];
+            self.state[r] = self.state[r].rotate_left(3) ^ prev.wrapping_add(r as u8);
+        }
+    }
+
+    fn absorb(&mut self, block: &Block<Self>) {
+        for (s, b) in self.state.iter_mut().zip(block.iter()) {
+            *s ^= *b;
+        }
+        self.permute();
+    }
+
+    fn absorb_last(&mut self, buffer: &mut Buffer<Self>) {
+        let block = buffer.pad_with_zeros();
+        block[7] |= 0x80;
+        let block = *block;
+        self.absorb(&block);
+    }
+}
+
+impl HashMarker for ToySponge {}
+
+impl BlockSizeUser for ToySponge {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for ToySponge {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl OutputSizeUser for ToySponge {
+    type OutputSize = U8;
+}
+
+impl UpdateCore for ToySponge {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            self.absorb(block);
+        }
+    }
+}
+
+impl FixedOutputCore for ToySponge {
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        self.absorb_last(buffer);
+        out.copy_from_slice(&self.state[..8]);
+    }
+}
+
+impl CapacityTag for ToySponge {
+    fn capacity_tag(&self, buffer: &mut Buffer<Self>, out: &mut [u8]) {
+        assert!(out.len() <= 8, "unsupported tag length");
+        let mut state = self.clone();
+        state.absorb_last(buffer);
+        out.copy_from_slice(&state.state[8..8 + out.len()]);
+    }
+}
+
+type ToyHash = CoreWrapper<ToySponge>;
+
+fn tag(msg: &[u8]) -> [u8; 8] {
+    let mut tag = [0u8; 8];
+    ToyHash::new().chain_update(msg).capacity_tag(&mut tag);
+    tag
+}
+
+#[test]
+fn tag_is_deterministic() {
+    for msg in &[&b""[..], b"abc", b"abcdefgh", b"abcdefghijklmnopq"] {
+        assert_eq!(tag(msg), tag(msg));
+        assert_ne!(&tag(msg)[..], &ToyHash::digest(msg)[..]);
+    }
+    assert_ne!(tag(b"abc"), tag(b"abd"));
+
+    let mut short = [0u8; 3];
+    ToyHash::new().chain_update(b"abc").capacity_tag(&mut short);
+    assert_eq!(short[..], tag(b"abc")[..3]);
+}
+
+#[test]
+fn tag_does_not_affect_state() {
+    let mut hasher = ToyHash::new();
+    hasher.update(b"abcdefghijk");
+    let mut t = [0u8; 8];
+    hasher.capacity_tag(&mut t);
+    assert_eq!(t, tag(b"abcdefghijk"));
+
+    hasher.update(b"lmn");
+    hasher.capacity_tag(&mut t);
+    assert_eq!(t, tag(b"abcdefghijklmn"));
+    assert_eq!(hasher.finalize(), ToyHash::digest(b"abcdefghijklmn"));
+}
@@ -0,0 +1,108 @@
+//! Tests for content-defined chunking.
+#![cfg(feature = "alloc")]
+
+use digest::{cdc::Chunker, Digest};
+use sha2::Sha256;
+
+const MIN: usize = 256;
+const AVG: usize = 1024;
+const MAX: usize = 4096;
+
+fn test_data(len: usize) -> Vec<u8> {
+    let mut x = 0x1234_5678u32;
+    (0..len)
+        .map(|_| {
+            // xorshift32
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            x as u8
+        })
+        .collect()
+}
+
+fn chunk(data: &[u8], split: usize) -> Vec<(u64, digest::Output<Sha256>)> {
+    let mut chunker = Chunker::<Sha256>::new(MIN, AVG, MAX);
+    for part in data.chunks(split) {
+        chunker.update(part);
+    }
+    chunker.finalize()
+}
+
+#[test]
+fn chunk_digests_match_independent_hashing() {
+    let data = test_data(100_000);
+    let chunks = chunk(&data, data.len());
+    assert!(chunks.len() > 10);
+
+    let mut start = 0;
+    for (i, (end, hash)) in chunks.iter().enumerate() {
+        let end = *end as usize;
+        let len = end - start;
+        assert!(len <= MAX);
+        if i != chunks.len() - 1 {
+            assert!(len >= MIN);
+        }
+        assert_eq!(*hash, Sha256::digest(&data[start..end]));
+        start = end;
+    }
+    assert_eq!(start, data.len());
+}
+
+#[test]
+fn boundaries_are_deterministic() {
+    let data = test_data(50_000);
+    let expected = chunk(&data, data.len());
+    assert_eq!(chunk(&data, data.len()), expected);
+    for &split in &[1, 7, 255, 256, 1000, 4096, 4097] {
+        assert_eq!(chunk(&data, split), expected);
+    }
+}
+
+#[test]
+fn boundaries_are_content_defined() {
+    // prepending data changes only chunks near the beginning
+    let data = test_data(50_000);
+    let mut shifted = vec![0xAA; 100];
+    shifted.extend_from_slice(&data);
+
+    let a: Vec<_> = chunk(&data, 1000).into_iter().map(|(_, h)| h).collect();
+    let b: Vec<_> = chunk(&shifted, 1000).into_iter().map(|(_, h)| h).collect();
+    let common = a.iter().filter(|h| b.contains(h)).count();
+    assert!(common >= a.len() - 2, "{} of {}", common, a.len());
+}
+
+#[test]
+fn take_chunks() {
+    let data = test_data(20_000);
+    let expected = chunk(&data, data.len());
+
+    let mut chunker = Chunker::<Sha256>::new(MIN, AVG, MAX);
+    let mut chunks = Vec::new();
+    for part in data.chunks(3000) {
+        chunker.update(part);
+        chunks.extend(chunker.take_chunks());
+        assert!(chunker.take_chunks().is_empty());
+    }
+    chunks.extend(chunker.finalize());
+    assert_eq!(chunks, expected);
+}
+
+#[test]
+fn max_size_and_empty_stream() {
+    let data = vec![0u8; 10 * MAX + 1];
+    let chunks = chunk(&data, data.len());
+    assert_eq!(chunks.len(), 11);
+    assert!(chunks[..10]
+        .iter()
+        .all(|(_, h)| *h == Sha256::digest(&data[..MAX])));
+    assert_eq!(chunks[10], (data.len() as u64, Sha256::digest([0])));
+
+    assert!(Chunker::<Sha256>::new(MIN, AVG, MAX).finalize().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn invalid_avg_size() {
+    let _ = Chunker::<Sha256>::new(MIN, 1000, MAX);
+}
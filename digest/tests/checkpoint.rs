@@ -0,0 +1,52 @@
+//! Tests for the `CheckpointingHasher` adapter.
+#![cfg(feature = "alloc")]
+
+use digest::{CheckpointingHasher, Digest, FixedOutput, Update};
+use sha2::Sha256;
+
+fn test_data() -> Vec<u8> {
+    (0..10_000u32).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn records_checkpoints() {
+    let data = test_data();
+    let mut hasher = CheckpointingHasher::new(Sha256::new(), 1000);
+    for chunk in data.chunks(333) {
+        hasher.update(chunk);
+    }
+    assert_eq!(hasher.position(), 10_000);
+    let offsets: Vec<u64> = hasher.checkpoints().iter().map(|c| c.0).collect();
+    assert_eq!(offsets, (0..=10).map(|i| i * 1000).collect::<Vec<_>>());
+    for (offset, state) in hasher.checkpoints() {
+        let expected = Sha256::new().chain_update(&data[..*offset as usize]);
+        assert_eq!(state.clone().finalize(), expected.finalize());
+    }
+    assert_eq!(hasher.finalize_fixed(), Sha256::digest(&data));
+}
+
+#[test]
+fn resume_yields_same_digest() {
+    let data = test_data();
+    let mut hasher = CheckpointingHasher::new(Sha256::new(), 1024);
+    // simulate interrupted download followed by corrupted tail
+    hasher.update(&data[..5000]);
+    hasher.update(&[0xff; 1000]);
+
+    let resumed = hasher.resume_from(5000);
+    assert_eq!(resumed, 4096);
+    assert_eq!(hasher.position(), 4096);
+    assert_eq!(hasher.checkpoints().last().unwrap().0, 4096);
+    hasher.update(&data[resumed as usize..]);
+    assert_eq!(hasher.finalize_fixed(), Sha256::digest(&data));
+}
+
+#[test]
+fn resume_from_start() {
+    let mut hasher = CheckpointingHasher::new(Sha256::new(), 64);
+    hasher.update(b"garbage");
+    assert_eq!(hasher.resume_from(10), 0);
+    assert_eq!(hasher.checkpoints().len(), 1);
+    hasher.update(b"abc");
+    assert_eq!(hasher.into_inner().finalize(), Sha256::digest(b"abc"));
+}
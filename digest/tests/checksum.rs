@@ -0,0 +1,93 @@
+//! Tests for checksum file verification.
+#![cfg(feature = "std")]
+
+use digest::verify_checksum_file;
+use sha2::{Digest, Sha256};
+use std::{fs, io, path::PathBuf};
+
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("digest-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("a.txt"), b"hello").unwrap();
+        fs::write(path.join("b.txt"), b"world").unwrap();
+        fs::write(path.join("back\\slash"), b"escaped").unwrap();
+        TempDir(path)
+    }
+
+    fn verify(&self, checksums: &str) -> io::Result<Vec<(String, bool)>> {
+        let path = self.0.join("SHA256SUMS");
+        fs::write(&path, checksums).unwrap();
+        verify_checksum_file::<Sha256, _, _>(path, &self.0)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+fn entry(name: &str, ok: bool) -> (String, bool) {
+    (name.to_string(), ok)
+}
+
+#[test]
+fn gnu_format() {
+    let dir = TempDir::new("gnu");
+    let checksums = format!(
+        "{}  a.txt\n{} *b.txt\n\n{}  missing.txt\n{}  b.txt\n\\{}  back\\\\slash\n",
+        hex(b"hello"),
+        hex(b"world").to_uppercase(),
+        hex(b"hello"),
+        hex(b"hello"),
+        hex(b"escaped"),
+    );
+    let res = dir.verify(&checksums).unwrap();
+    assert_eq!(
+        res,
+        [
+            entry("a.txt", true),
+            entry("b.txt", true),
+            entry("missing.txt", false),
+            entry("b.txt", false),
+            entry("back\\slash", true),
+        ]
+    );
+}
+
+#[test]
+fn bsd_format() {
+    let dir = TempDir::new("bsd");
+    let checksums = format!(
+        "SHA256 (a.txt) = {}\r\nSHA256 (b.txt) = {}\r\n",
+        hex(b"hello"),
+        hex(b"hello"),
+    );
+    let res = dir.verify(&checksums).unwrap();
+    assert_eq!(res, [entry("a.txt", true), entry("b.txt", false)]);
+}
+
+#[test]
+fn invalid_lines() {
+    let dir = TempDir::new("invalid");
+    let short = &hex(b"hello")[..62];
+    let invalid = [
+        format!("{} a.txt", hex(b"hello")),
+        format!("{}  a.txt", short),
+        format!("{}  ", hex(b"hello")),
+        format!("SHA256 a.txt = {}", hex(b"hello")),
+        format!("\\{}  a\\x", hex(b"hello")),
+        "not a checksum".to_string(),
+    ];
+    for line in invalid.iter() {
+        let err = dir.verify(line).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{}", line);
+    }
+}
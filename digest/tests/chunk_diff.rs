@@ -0,0 +1,55 @@
+//! Tests for chunk digests and diffing.
+#![cfg(feature = "alloc")]
+
+use digest::{chunk_digests, diff_chunks, Digest};
+use sha2::Sha256;
+
+fn test_data() -> Vec<u8> {
+    (0..1000u32).map(|i| (i * 7 % 251) as u8).collect()
+}
+
+#[test]
+fn digests() {
+    let data = test_data();
+    let res = chunk_digests::<Sha256>(&data, 300);
+    assert_eq!(res.len(), 4);
+    for (i, d) in res.iter().enumerate() {
+        let end = core::cmp::min(data.len(), 300 * (i + 1));
+        assert_eq!(d, &Sha256::digest(&data[300 * i..end]));
+    }
+    assert!(chunk_digests::<Sha256>(&[], 300).is_empty());
+}
+
+#[test]
+fn identical_data() {
+    let data = test_data();
+    let a = chunk_digests::<Sha256>(&data, 64);
+    let b = chunk_digests::<Sha256>(&data.clone(), 64);
+    assert!(diff_chunks(&a, &b).is_empty());
+}
+
+#[test]
+fn changed_regions() {
+    let data = test_data();
+    let mut changed = data.clone();
+    changed[0] ^= 1;
+    changed[130] ^= 1;
+    changed[191] ^= 1;
+    changed[192] ^= 1;
+    let a = chunk_digests::<Sha256>(&data, 64);
+    let b = chunk_digests::<Sha256>(&changed, 64);
+    assert_eq!(diff_chunks(&a, &b), [0, 2, 3]);
+
+    // appended data changes the last chunk and adds new ones
+    changed = data.clone();
+    changed.extend_from_slice(&[0u8; 100]);
+    let b = chunk_digests::<Sha256>(&changed, 64);
+    assert_eq!(diff_chunks(&a, &b), [15, 16, 17]);
+    assert_eq!(diff_chunks(&b, &a), [15, 16, 17]);
+}
+
+#[test]
+#[should_panic]
+fn zero_chunk() {
+    chunk_digests::<Sha256>(b"abc", 0);
+}
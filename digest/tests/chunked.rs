@@ -0,0 +1,66 @@
+//! Tests for the `ChunkedUpdate` adapter.
+use core::cell::Cell;
+use digest::{ChunkedUpdate, Digest, FixedOutput, FixedOutputReset, Update};
+use sha2::Sha256;
+
+/// Stub recording sizes of the received updates.
+#[derive(Default)]
+struct Recorder(Vec<usize>);
+
+impl Update for Recorder {
+    fn update(&mut self, data: &[u8]) {
+        self.0.push(data.len());
+    }
+}
+
+fn test_data() -> Vec<u8> {
+    (0..1000u16).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn output_is_independent_of_chunk_size() {
+    let data = test_data();
+    let expected = Sha256::digest(&data);
+    for &chunk_size in &[1, 2, 63, 64, 65, 999, 1000, 1001, core::usize::MAX] {
+        let mut hasher = ChunkedUpdate::new(Sha256::new(), chunk_size);
+        hasher.update(&data[..100]);
+        hasher.update(&data[100..]);
+        assert_eq!(hasher.finalize_fixed(), expected);
+    }
+}
+
+#[test]
+fn splits_updates() {
+    let mut rec = ChunkedUpdate::new(Recorder::default(), 64);
+    assert_eq!(rec.chunk_size(), 64);
+    rec.update(&[0u8; 200]);
+    rec.update(&[0u8; 64]);
+    rec.update(&[]);
+    rec.update(&[0u8; 10]);
+    assert_eq!(rec.into_inner().0, [64, 64, 64, 8, 64, 10]);
+}
+
+#[test]
+fn callback_between_chunks() {
+    let calls = Cell::new(0);
+    let mut hasher = ChunkedUpdate::with_callback(Sha256::new(), 100, || {
+        calls.set(calls.get() + 1);
+    });
+    let data = test_data();
+    hasher.update(&data);
+    assert_eq!(calls.get(), 9);
+    hasher.update(&data[..100]);
+    assert_eq!(calls.get(), 9);
+    hasher.update(&data[..101]);
+    assert_eq!(calls.get(), 10);
+
+    hasher.finalize_fixed_reset();
+    hasher.update(&data);
+    assert_eq!(hasher.finalize_fixed(), Sha256::digest(&data));
+}
+
+#[test]
+#[should_panic]
+fn zero_chunk_size() {
+    let _ = ChunkedUpdate::new(Sha256::new(), 0);
+}
@@ -0,0 +1,38 @@
+//! Tests for CMAC subkey derivation.
+use aes::{Aes128, BlockCipher, NewBlockCipher};
+use digest::{cmac_subkeys, generic_array::GenericArray};
+use hex_literal::hex;
+
+#[test]
+fn rfc4493_subkeys() {
+    // RFC 4493 section 4, subkey generation
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let mut l = GenericArray::default();
+    Aes128::new(GenericArray::from_slice(&key)).encrypt_block(&mut l);
+    assert_eq!(l[..], hex!("7df76b0c1ab899b33e42f047b91b546f"));
+
+    let (k1, k2) = cmac_subkeys(&l);
+    assert_eq!(k1[..], hex!("fbeed618357133667c85e08f7236a8de"));
+    assert_eq!(k2[..], hex!("f7ddac306ae266ccf90bc11ee46d513b"));
+}
+
+#[test]
+fn reduction() {
+    // most significant bit set: shifted value is reduced using R_b
+    let l = GenericArray::from(hex!("80000000000000000000000000000000"));
+    let (k1, k2) = cmac_subkeys(&l);
+    assert_eq!(k1[..], hex!("00000000000000000000000000000087"));
+    assert_eq!(k2[..], hex!("0000000000000000000000000000010e"));
+
+    let l = GenericArray::from(hex!("c000000000000000"));
+    let (k1, k2) = cmac_subkeys(&l);
+    assert_eq!(k1[..], hex!("800000000000001b"));
+    assert_eq!(k2[..], hex!("000000000000002d"));
+}
+
+#[test]
+#[should_panic]
+fn unsupported_block_size() {
+    let l = GenericArray::from([0u8; 12]);
+    cmac_subkeys(&l);
+}
@@ -0,0 +1,58 @@
+//! Tests for the commitment scheme.
+#![cfg(all(feature = "alloc", feature = "mac"))]
+
+mod common;
+
+use common::ToyXof;
+use digest::{
+    commitment::{commit, verify, DOMAIN_PREFIX},
+    ExtendableOutput, Update, XofReader,
+};
+
+const R: [u8; 32] = [0x42; 32];
+
+#[test]
+fn commit_verify_roundtrip() {
+    for &len in &[1, 16, 32, 64, 65, 200] {
+        let c = commit::<ToyXof>(&R, b"bid: 100", len);
+        assert_eq!(c.len(), len);
+        assert!(verify::<ToyXof>(&R, b"bid: 100", &c));
+    }
+}
+
+#[test]
+fn commitment_layout() {
+    let mut xof = ToyXof::default();
+    xof.update(DOMAIN_PREFIX);
+    xof.update(&40u64.to_le_bytes());
+    xof.update(&32u64.to_le_bytes());
+    xof.update(&R);
+    xof.update(b"msg");
+    let mut expected = [0u8; 40];
+    xof.finalize_xof().read(&mut expected);
+    assert_eq!(commit::<ToyXof>(&R, b"msg", 40)[..], expected[..]);
+}
+
+#[test]
+fn tampering_is_rejected() {
+    let c = commit::<ToyXof>(&R, b"bid: 100", 80);
+    assert!(!verify::<ToyXof>(&R, b"bid: 101", &c));
+    assert!(!verify::<ToyXof>(&[0x43; 32], b"bid: 100", &c));
+    assert!(!verify::<ToyXof>(&R, b"bid: 100", &c[..79]));
+    assert!(!verify::<ToyXof>(&R, b"bid: 100", &[]));
+    for i in 0..c.len() {
+        let mut tampered = c.clone();
+        tampered[i] ^= 1;
+        assert!(!verify::<ToyXof>(&R, b"bid: 100", &tampered));
+    }
+
+    // boundary between randomness and message is bound to the commitment
+    let c = commit::<ToyXof>(b"ab", b"c", 32);
+    assert!(!verify::<ToyXof>(b"a", b"bc", &c));
+}
+
+#[test]
+#[should_panic]
+fn zero_length_commitment() {
+    let _ = commit::<ToyXof>(&R, b"msg", 0);
+}
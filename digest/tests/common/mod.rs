@@ -0,0 +1,53 @@
+//! Toy primitives shared by tests of constructions generic over hash
+//! traits. NOT cryptographically secure.
+#![allow(dead_code)]
+
+use digest::{ExtendableOutput, Update, XofReader};
+
+/// Toy XOF which outputs a keystream derived from all absorbed data.
+#[derive(Clone, Default)]
+pub struct ToyXof {
+    state: u64,
+}
+
+impl Update for ToyXof {
+    fn update(&mut self, data: &[u8]) {
+        for &b in data {
+            self.state = mix(self.state ^ u64::from(b));
+        }
+    }
+}
+
+impl ExtendableOutput for ToyXof {
+    type Reader = ToyXofReader;
+
+    fn finalize_xof(self) -> ToyXofReader {
+        ToyXofReader {
+            state: self.state,
+            counter: 0,
+        }
+    }
+}
+
+/// Reader of [`ToyXof`].
+#[derive(Clone)]
+pub struct ToyXofReader {
+    state: u64,
+    counter: u64,
+}
+
+impl XofReader for ToyXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        for b in buffer {
+            *b = mix(self.state ^ self.counter) as u8;
+            self.counter += 1;
+        }
+    }
+}
+
+/// The splitmix64 finalizer.
+pub fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,48 @@
+//! Tests for the `CompressCount` trait.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U64,
+    core_api::{Block, BlockSizeUser, BufferKindUser, CompressCount, CoreWrapper, UpdateCore},
+    Update,
+};
+
+/// Mock core which only counts processed blocks.
+#[derive(Clone, Default)]
+struct MockCore {
+    count: u64,
+}
+
+impl BlockSizeUser for MockCore {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for MockCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl UpdateCore for MockCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.count += blocks.len() as u64;
+    }
+}
+
+impl CompressCount for MockCore {
+    fn compress_count(&self) -> u64 {
+        self.count
+    }
+}
+
+#[test]
+fn counts_full_blocks() {
+    let data = [0x42u8; 1000];
+    for &len in &[0, 1, 63, 64, 65, 128, 1000] {
+        for &piece in &[1, 7, 64, 100, 1000] {
+            let mut hasher = CoreWrapper::<MockCore>::default();
+            for chunk in data[..len].chunks(piece) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.compress_count(), len as u64 / 64);
+        }
+    }
+}
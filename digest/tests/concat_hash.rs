@@ -0,0 +1,40 @@
+//! Tests for the `ConcatHash` combiner.
+use digest::{
+    generic_array::typenum::Unsigned, ConcatHash, Digest, FixedOutputReset, OutputSizeUser, Update,
+};
+use sha2::{Sha256, Sha512};
+
+type Combined = ConcatHash<Sha256, Sha512>;
+
+fn expected(data: &[u8]) -> Vec<u8> {
+    let mut res = Sha256::digest(data).to_vec();
+    res.extend_from_slice(&Sha512::digest(data));
+    res
+}
+
+#[test]
+fn output_is_concatenation() {
+    assert_eq!(<Combined as OutputSizeUser>::OutputSize::USIZE, 96);
+    for data in [&b""[..], b"abc", &[0x5a; 1000]].iter() {
+        assert_eq!(&Combined::digest(data)[..], &expected(data)[..]);
+    }
+}
+
+#[test]
+fn incremental_and_reset() {
+    let mut hasher = Combined::new(Sha256::new(), Sha512::new());
+    Update::update(&mut hasher, b"some ");
+    Update::update(&mut hasher, b"data");
+    assert_eq!(
+        &hasher.finalize_fixed_reset()[..],
+        &expected(b"some data")[..]
+    );
+
+    Update::update(&mut hasher, b"abc");
+    let (a, b) = hasher.clone().into_inner();
+    assert_eq!(a.finalize(), Sha256::digest(b"abc"));
+    assert_eq!(b.finalize(), Sha512::digest(b"abc"));
+
+    Digest::reset(&mut hasher);
+    assert_eq!(&hasher.finalize()[..], &expected(b"")[..]);
+}
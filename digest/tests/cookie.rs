@@ -0,0 +1,81 @@
+//! Tests for the cookie signing helpers.
+#![cfg(all(feature = "alloc", feature = "mac"))]
+
+use digest::{
+    cookie::{sign_cookie, verify_cookie},
+    Hmac, Key,
+};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SIGNED: &str = "dXNlcj1hbGljZQ.jslHZlccz29yKw8_INq5eX7R9w3rshHEaySNWTeKe8A";
+
+fn key() -> Key<HmacSha256> {
+    Key::<HmacSha256>::clone_from_slice(&[0x0b; 64])
+}
+
+#[test]
+fn sign_vector() {
+    assert_eq!(sign_cookie::<HmacSha256>(&key(), b"user=alice"), SIGNED);
+}
+
+#[test]
+fn round_trip() {
+    let key = key();
+    for len in 0..70 {
+        let value: Vec<u8> = (0..len as u8).map(|i| i.wrapping_mul(37)).collect();
+        let signed = sign_cookie::<HmacSha256>(&key, &value);
+        assert!(signed
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_' || c == b'.'));
+        assert_eq!(verify_cookie::<HmacSha256>(&key, &signed), Some(value));
+    }
+}
+
+#[test]
+fn reject_tampered() {
+    let key = key();
+    for i in 0..SIGNED.len() {
+        if SIGNED.as_bytes()[i] == b'.' {
+            continue;
+        }
+        for &c in b"AQgw-_." {
+            let mut tampered = SIGNED.as_bytes().to_vec();
+            if tampered[i] == c {
+                continue;
+            }
+            tampered[i] = c;
+            let tampered = String::from_utf8(tampered).unwrap();
+            assert_eq!(verify_cookie::<HmacSha256>(&key, &tampered), None);
+        }
+    }
+
+    let other = Key::<HmacSha256>::clone_from_slice(&[0x0c; 64]);
+    assert_eq!(verify_cookie::<HmacSha256>(&other, SIGNED), None);
+}
+
+#[test]
+fn reject_wrong_tag_length() {
+    let key = key();
+    let (value, tag) = SIGNED.split_at(SIGNED.find('.').unwrap() + 1);
+    for len in 0..tag.len() {
+        let cookie = format!("{}{}", value, &tag[..len]);
+        assert_eq!(verify_cookie::<HmacSha256>(&key, &cookie), None);
+    }
+    let cookie = format!("{}A", SIGNED);
+    assert_eq!(verify_cookie::<HmacSha256>(&key, &cookie), None);
+}
+
+#[test]
+fn reject_malformed() {
+    let key = key();
+    assert_eq!(verify_cookie::<HmacSha256>(&key, ""), None);
+    assert_eq!(verify_cookie::<HmacSha256>(&key, "dXNlcj1hbGljZQ"), None);
+    // padding characters are not accepted
+    let padded = SIGNED.replace(".", "==.");
+    assert_eq!(verify_cookie::<HmacSha256>(&key, &padded), None);
+    // non-canonical encoding of the value ("Q" -> "R" changes trailing bits)
+    let non_canonical = SIGNED.replace("ZQ.", "ZR.");
+    assert_eq!(verify_cookie::<HmacSha256>(&key, &non_canonical), None);
+}
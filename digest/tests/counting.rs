@@ -0,0 +1,37 @@
+//! Tests for the `CountingUpdate` adapter.
+use digest::{CountingUpdate, Digest, FixedOutput, FixedOutputReset, Reset, Update};
+use sha2::Sha256;
+
+#[test]
+fn counts_bytes_across_updates() {
+    let mut hasher = CountingUpdate::new(Sha256::new());
+    assert_eq!(hasher.bytes_hashed(), 0);
+    let chunks: &[&[u8]] = &[b"", b"a", b"bc", &[0u8; 100], &[1u8; 1000]];
+    let mut total = 0;
+    for chunk in chunks {
+        hasher.update(chunk);
+        total += chunk.len() as u64;
+        assert_eq!(hasher.bytes_hashed(), total);
+    }
+    assert_eq!(total, 1103);
+
+    let expected = Sha256::new()
+        .chain_update(b"abc")
+        .chain_update(&[0u8; 100][..])
+        .chain_update(&[1u8; 1000][..])
+        .finalize();
+    assert_eq!(hasher.finalize_fixed(), expected);
+}
+
+#[test]
+fn reset_clears_counter() {
+    let mut hasher = CountingUpdate::<Sha256>::default();
+    hasher.update(b"some data");
+    assert_eq!(hasher.bytes_hashed(), 9);
+    hasher.reset();
+    assert_eq!(hasher.bytes_hashed(), 0);
+
+    hasher.update(b"abc");
+    assert_eq!(hasher.finalize_fixed_reset(), Sha256::digest(b"abc"));
+    assert_eq!(hasher.bytes_hashed(), 0);
+}
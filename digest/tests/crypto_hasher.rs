@@ -0,0 +1,73 @@
+//! Tests for the `CryptoHasher` and `CryptoBuildHasher` types.
+#![cfg(feature = "std")]
+
+use digest::{CryptoBuildHasher, CryptoHasher, OutputReduction};
+use sha2::Sha256;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
+
+fn hash_bytes(reduction: OutputReduction, data: &[u8]) -> u64 {
+    let mut hasher = CryptoHasher::<Sha256>::new(reduction);
+    hasher.write(data);
+    hasher.finish()
+}
+
+#[test]
+fn stable_values() {
+    // SHA-256("abc") = ba7816bf8f01cfea 414140de5dae2223 b00361a396177a9c b410ff61f20015ad
+    assert_eq!(
+        hash_bytes(OutputReduction::Truncate, b"abc"),
+        0xeacf_018f_bf16_78ba
+    );
+    assert_eq!(
+        hash_bytes(OutputReduction::Fold, b"abc"),
+        0xf882_b8b6_a3c8_2aff
+    );
+
+    let mut hasher = CryptoHasher::<Sha256>::default();
+    hasher.write(b"a");
+    hasher.write(b"bc");
+    // `finish` does not consume the state
+    assert_eq!(hasher.finish(), 0xeacf_018f_bf16_78ba);
+    assert_eq!(hasher.finish(), 0xeacf_018f_bf16_78ba);
+}
+
+#[test]
+fn integers_are_little_endian() {
+    let mut a = CryptoHasher::<Sha256>::default();
+    a.write_u32(0x0403_0201);
+    a.write_usize(5);
+    a.write_i16(-2);
+    let mut b = CryptoHasher::<Sha256>::default();
+    b.write(&[1, 2, 3, 4, 5, 0, 0, 0, 0, 0, 0, 0, 0xfe, 0xff]);
+    assert_eq!(a.finish(), b.finish());
+}
+
+#[test]
+fn build_hasher() {
+    let s = CryptoBuildHasher::<Sha256>::new(OutputReduction::Fold);
+    let hash = |v: &str| {
+        let mut h = s.build_hasher();
+        h.write(v.as_bytes());
+        h.finish()
+    };
+    assert_eq!(hash("foo"), hash("foo"));
+    assert_ne!(hash("foo"), hash("bar"));
+
+    let mut map = HashMap::with_hasher(CryptoBuildHasher::<Sha256>::default());
+    map.insert(b"chunk 1".to_vec(), 1);
+    map.insert(b"chunk 2".to_vec(), 2);
+    assert_eq!(map.get(&b"chunk 1"[..]), Some(&1));
+    assert_eq!(map.get(&b"chunk 2"[..]), Some(&2));
+    assert_eq!(map.get(&b"chunk 3"[..]), None);
+}
+
+#[test]
+fn no_collisions_smoke_test() {
+    for &reduction in &[OutputReduction::Truncate, OutputReduction::Fold] {
+        let hashes: HashSet<u64> = (0..10_000u32)
+            .map(|i| hash_bytes(reduction, &i.to_le_bytes()))
+            .collect();
+        assert_eq!(hashes.len(), 10_000);
+    }
+}
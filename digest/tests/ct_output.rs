@@ -0,0 +1,71 @@
+//! Tests for the `CtOutput` type.
+#![cfg(feature = "mac")]
+
+use digest::{consts::U4, CtOutput, DigestEq, Output, OutputSizeUser};
+use subtle::Choice;
+
+struct Tag4;
+
+impl OutputSizeUser for Tag4 {
+    type OutputSize = U4;
+}
+
+fn tag(bytes: [u8; 4]) -> CtOutput<Tag4> {
+    CtOutput::new(Output::<Tag4>::from(bytes))
+}
+
+#[test]
+fn conditional_select() {
+    let a = tag([0x00, 0x0f, 0xf0, 0xff]);
+    let b = tag([0xff, 0xaa, 0x55, 0x00]);
+
+    let res = CtOutput::conditional_select(&a, &b, Choice::from(0));
+    assert!(res == a);
+    assert_eq!(res.into_bytes()[..], [0x00, 0x0f, 0xf0, 0xff]);
+
+    let res = CtOutput::conditional_select(&a, &b, Choice::from(1));
+    assert!(res == b);
+    assert_eq!(res.into_bytes()[..], [0xff, 0xaa, 0x55, 0x00]);
+
+    let res = CtOutput::conditional_select(&a, &a, Choice::from(1));
+    assert!(res == a);
+}
+
+struct Other4;
+
+impl OutputSizeUser for Other4 {
+    type OutputSize = U4;
+}
+
+#[test]
+fn digest_eq() {
+    let a = Output::<Tag4>::from([1, 2, 3, 4]);
+    let b = Output::<Other4>::from([1, 2, 3, 4]);
+    let c = Output::<Other4>::from([1, 2, 3, 5]);
+    let d = Output::<Other4>::from([0, 2, 3, 4]);
+    assert!(<Tag4 as DigestEq<Other4>>::digest_eq(&a, &b));
+    assert!(!<Tag4 as DigestEq<Other4>>::digest_eq(&a, &c));
+    assert!(!<Tag4 as DigestEq<Other4>>::digest_eq(&a, &d));
+    assert!(<Other4 as DigestEq<Tag4>>::digest_eq(&b, &a));
+}
+
+#[test]
+fn digest_eq_real_hashes() {
+    use sha2::{Digest, Sha256};
+
+    struct Stored;
+
+    impl OutputSizeUser for Stored {
+        type OutputSize = <Sha256 as OutputSizeUser>::OutputSize;
+    }
+
+    let stored = Output::<Stored>::clone_from_slice(&Sha256::digest(b"data"));
+    assert!(<Stored as DigestEq<Sha256>>::digest_eq(
+        &stored,
+        &Sha256::digest(b"data")
+    ));
+    assert!(!<Stored as DigestEq<Sha256>>::digest_eq(
+        &stored,
+        &Sha256::digest(b"date")
+    ));
+}
@@ -0,0 +1,47 @@
+//! Tests for per-tenant deduplication keys.
+#![cfg(feature = "mac")]
+
+use digest::{dedup_key, Digest, Hmac, Key, Mac};
+use hex_literal::hex;
+use sha2::{Sha256, Sha512};
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn tenant(b: u8) -> Key<HmacSha256> {
+    let mut key = Key::<HmacSha256>::default();
+    key.iter_mut().for_each(|k| *k = b);
+    key
+}
+
+#[test]
+fn known_answer() {
+    let key = dedup_key::<Sha256, HmacSha256>(&tenant(0x0b), b"hello world");
+    assert_eq!(
+        key[..],
+        hex!("0000ccd507864066986b29891f8285d1b786acb14f829203cf6e40c0d1fbf8c7")[..]
+    );
+}
+
+#[test]
+fn composition() {
+    let content = b"some stored content";
+    let mut mac = <HmacSha256 as Mac>::new(&tenant(1));
+    mac.update(&Sha512::digest(content));
+    let expected = mac.finalize().into_bytes();
+    assert_eq!(
+        dedup_key::<Sha512, HmacSha256>(&tenant(1), content),
+        expected
+    );
+}
+
+#[test]
+fn tenant_separation() {
+    let content = b"some stored content";
+    let a = dedup_key::<Sha256, HmacSha256>(&tenant(1), content);
+    assert_eq!(a, dedup_key::<Sha256, HmacSha256>(&tenant(1), content));
+    assert_ne!(a, dedup_key::<Sha256, HmacSha256>(&tenant(2), content));
+    assert_ne!(
+        a,
+        dedup_key::<Sha256, HmacSha256>(&tenant(1), b"other content")
+    );
+}
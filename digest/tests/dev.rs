@@ -0,0 +1,189 @@
+//! Tests for the `dev` module helpers.
+#![cfg(feature = "dev")]
+
+use digest::{
+    consts::{U1, U16, U8},
+    dev::{
+        fixed_reset_test, fixed_reset_test_detailed, run_blob2_tests, run_blob3_tests, Hex,
+        Mismatch, MockFixedHash,
+    },
+    Digest, Output,
+};
+
+#[test]
+fn mock_is_deterministic() {
+    let data = &b"some test data which is longer than output"[..];
+    let a = MockFixedHash::<U16>::digest(data);
+    let b = MockFixedHash::<U16>::digest(data);
+    assert_eq!(a, b);
+    assert_ne!(a, MockFixedHash::<U16>::digest(&data[1..]));
+    assert_ne!(
+        a,
+        MockFixedHash::<U16>::digest(&b"Some test data which is longer than output"[..])
+    );
+    // inputs which differ only in trailing zeros are distinguished
+    assert_ne!(
+        MockFixedHash::<U8>::digest(b""),
+        MockFixedHash::<U8>::digest(b"\x00")
+    );
+    assert_eq!(MockFixedHash::<U1>::digest(b"abc").len(), 1);
+}
+
+#[test]
+fn mock_reset() {
+    let mut hasher = MockFixedHash::<U16>::new();
+    hasher.update(b"garbage");
+    hasher.reset();
+    hasher.update(b"data");
+    assert_eq!(
+        hasher.finalize_reset(),
+        MockFixedHash::<U16>::digest(b"data")
+    );
+    assert_eq!(hasher.finalize(), MockFixedHash::<U16>::digest(b""));
+}
+
+#[test]
+fn mock_passes_fixed_reset_test() {
+    let input = &b"The quick brown fox jumps over the lazy dog"[..];
+    let output: Output<MockFixedHash<U8>> = MockFixedHash::<U8>::digest(input);
+    assert_eq!(fixed_reset_test::<MockFixedHash<U8>>(input, &output), None);
+}
+
+digest::new_test!(
+    mock_fixed_vectors,
+    "mock_fixed",
+    MockFixedHash<U8>,
+    fixed_reset_test,
+);
+
+digest::new_test!(
+    mock_fixed_vectors_detailed,
+    "mock_fixed",
+    MockFixedHash<U8>,
+    fixed_reset_test_detailed,
+);
+
+/// Two `(input, output)` vectors, the second one with a wrong output.
+const BAD_VECTORS: &[u8] = b"\x00\
+    \x00\x10\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06abc\x10\xff\xff\xff\xff\xff\xff\xff\xff";
+
+#[test]
+#[should_panic(
+    expected = "vector #1 (whole message): expected ffffffffffffffff got 6262630000000000"
+)]
+fn mismatch_message() {
+    run_blob2_tests(BAD_VECTORS, fixed_reset_test_detailed::<MockFixedHash<U8>>);
+}
+
+#[test]
+#[should_panic(expected = "vector #1 (whole message): expected ffffffffffffffff\ninput:\t616263\n")]
+fn mismatch_label_message() {
+    run_blob2_tests(BAD_VECTORS, |input, output| {
+        fixed_reset_test::<MockFixedHash<U8>>(input, output).map(Mismatch::from)
+    });
+}
+
+#[test]
+#[should_panic(expected = "vector #0 (bad tag): expected 02 got 0102\nkey:\t00\ninput:\t01\n")]
+fn mac_mismatch_message() {
+    run_blob3_tests(b"\x00\x02\x00\x02\x01\x02\x02", |key, input, _| {
+        let got = [key[0] ^ 1, input[0] ^ 3];
+        Some(Mismatch::new("bad tag", &got))
+    });
+}
+
+#[test]
+fn mismatch_report() {
+    let input = b"abc";
+    let m = fixed_reset_test_detailed::<MockFixedHash<U8>>(input, &[0; 8]).unwrap();
+    assert_eq!(m.label(), "whole message");
+    assert_eq!(m.got(), Some(&MockFixedHash::<U8>::digest(input)[..]));
+    assert_eq!(m.to_string(), "whole message");
+    assert_eq!(m, Mismatch::new("whole message", m.got().unwrap()));
+    assert_ne!(m, Mismatch::from("whole message"));
+    assert_eq!(
+        fixed_reset_test::<MockFixedHash<U8>>(input, &[0; 8]),
+        Some("whole message")
+    );
+}
+
+#[test]
+fn hex_format() {
+    assert_eq!(Hex(&[]).to_string(), "");
+    assert_eq!(Hex(&[0x00, 0x0f, 0xa0, 0xff]).to_string(), "000fa0ff");
+    assert_eq!(format!("{:?}", Hex(b"\x01\xAB")), "01ab");
+}
+
+#[cfg(feature = "mac")]
+digest::new_mac_test!(
+    hmac_sha256_vectors,
+    "hmac_sha256",
+    digest::Hmac<sha2::Sha256>
+);
+
+#[cfg(feature = "mac")]
+digest::new_resettable_mac_test!(
+    hmac_sha256_resettable_vectors,
+    "hmac_sha256",
+    digest::Hmac<sha2::Sha256>,
+);
+
+#[test]
+fn mock_implements_all_traits() {
+    digest::dev::assert_impl_all_traits::<MockFixedHash<U1>>();
+    digest::dev::assert_impl_all_traits::<MockFixedHash<U16>>();
+}
+
+/// Timing-based tests are ignored by default, since they are unreliable in
+/// debug builds and on loaded machines. Run them with
+/// `cargo test --release --all-features -- --ignored`.
+#[cfg(all(feature = "mac", feature = "std"))]
+mod constant_time {
+    use digest::{
+        dev::{assert_constant_time, assert_verify_constant_time},
+        Hmac,
+    };
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    #[ignore]
+    fn hmac_verify() {
+        assert_verify_constant_time::<Hmac<Sha256>>(b"key", b"message");
+    }
+
+    /// Short-circuiting comparison which is deliberately slow for each
+    /// compared byte.
+    fn slow_eq(a: &[u8], b: &[u8]) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b)
+                .all(|(&x, &y)| Sha256::digest([x]) == Sha256::digest([y]))
+    }
+
+    #[test]
+    #[ignore]
+    #[should_panic(expected = "verification timing depends on tag contents")]
+    fn short_circuiting() {
+        let tag = [0x42; 16];
+        assert_constant_time(&tag, |t| slow_eq(t, &tag));
+    }
+
+    #[test]
+    #[should_panic(expected = "tampered tag was accepted")]
+    fn accepting() {
+        assert_constant_time(&[0x42; 32], |_| true);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bench_throughput() {
+    let sizes = [1, 64, 1000];
+    let res = digest::dev::bench_throughput::<MockFixedHash<U16>>(&sizes);
+    assert_eq!(res.len(), sizes.len());
+    for (&(size, mbps), &expected) in res.iter().zip(sizes.iter()) {
+        assert_eq!(size, expected);
+        assert!(mbps.is_finite() && mbps > 0.0, "{} MB/s", mbps);
+    }
+}
@@ -0,0 +1,241 @@
+//! Tests for the `Digest` trait.
+#![cfg(feature = "std")]
+
+use digest::Digest;
+use sha2::Sha256;
+use std::io::{self, Cursor, Read};
+
+fn test_data() -> Vec<u8> {
+    (0..20_000u32).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn digest_reader() {
+    for &n in &[0, 1, 63, 64, 65, 8 * 1024, 8 * 1024 + 1, 20_000] {
+        let data = &test_data()[..n];
+        let res = Sha256::digest_reader(Cursor::new(data)).unwrap();
+        assert_eq!(res, Sha256::digest(data));
+    }
+}
+
+#[test]
+fn digest_reader_with_capacity() {
+    let data = test_data();
+    let expected = Sha256::digest(&data);
+    for &cap in &[1, 3, 64, 1000, 1 << 20] {
+        let res = Sha256::digest_reader_with_capacity(Cursor::new(&data), cap).unwrap();
+        assert_eq!(res, expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn digest_reader_with_zero_capacity() {
+    let _ = Sha256::digest_reader_with_capacity(Cursor::new(b"abc"), 0);
+}
+
+#[test]
+fn digest_reader_propagates_errors() {
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    let err = Sha256::digest_reader(FailingReader).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn update_from_bufread() {
+    let data = test_data();
+    for &cap in &[1, 7, 64, 8 * 1024] {
+        let mut reader = io::BufReader::with_capacity(cap, Cursor::new(&data));
+        let mut hasher = Sha256::new();
+        hasher.update(b"prefix");
+        let n = hasher.update_from_bufread(&mut reader).unwrap();
+        assert_eq!(n, data.len() as u64);
+        let expected = Sha256::new().chain_update(b"prefix").chain_update(&data);
+        assert_eq!(hasher.finalize(), expected.finalize());
+    }
+
+    let mut hasher = Sha256::new();
+    assert_eq!(hasher.update_from_bufread(&mut &b""[..]).unwrap(), 0);
+    assert_eq!(hasher.finalize(), Sha256::digest(b""));
+}
+
+#[test]
+fn update_from_bufread_retries_interrupted() {
+    struct FlakyReader<'a> {
+        data: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl Read for FlakyReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            self.data.read(buf)
+        }
+    }
+
+    let data = test_data();
+    let reader = FlakyReader {
+        data: &data,
+        interrupt: false,
+    };
+    let mut reader = io::BufReader::with_capacity(100, reader);
+    let mut hasher = Sha256::new();
+    let n = hasher.update_from_bufread(&mut reader).unwrap();
+    assert_eq!(n, data.len() as u64);
+    assert_eq!(hasher.finalize(), Sha256::digest(&data));
+}
+
+#[test]
+fn write_hex_to() {
+    let mut buf = Vec::new();
+    Sha256::write_hex_to(Sha256::digest(b"abc"), &mut buf).unwrap();
+    assert_eq!(
+        buf,
+        &b"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"[..]
+    );
+
+    // appends to existing data
+    Sha256::write_hex_to(Sha256::digest(b""), &mut buf).unwrap();
+    assert_eq!(
+        &buf[64..],
+        &b"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"[..]
+    );
+
+    let sha512 = sha2::Sha512::digest(b"abc");
+    let mut buf = Vec::new();
+    sha2::Sha512::write_hex_to(sha512, &mut buf).unwrap();
+    assert_eq!(buf, format!("{:x}", sha512).as_bytes());
+}
+
+#[test]
+fn digest_file() {
+    let dir = std::env::temp_dir();
+    for &n in &[0, 1, 8 * 1024, 20_000] {
+        let path = dir.join(format!("digest-file-test-{}-{}", std::process::id(), n));
+        let data = &test_data()[..n];
+        std::fs::write(&path, data).unwrap();
+        let res = digest::digest_file::<Sha256, _>(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let expected = Sha256::new()
+            .chain_update((n as u64).to_le_bytes())
+            .chain_update(data)
+            .finalize();
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    let path = dir.join("digest-file-test-missing-file");
+    let err = digest::digest_file::<Sha256, _>(path).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn write_vectored() {
+    use std::io::{IoSlice, Write};
+
+    let data = test_data();
+    let parts: Vec<&[u8]> = vec![
+        &data[..0],
+        &data[..1],
+        &data[1..65],
+        &data[65..1000],
+        &data[1000..],
+    ];
+    let slices: Vec<IoSlice<'_>> = parts.iter().map(|p| IoSlice::new(p)).collect();
+
+    let mut vectored = Sha256::new();
+    assert_eq!(vectored.write_vectored(&slices).unwrap(), data.len());
+    let mut scalar = Sha256::new();
+    for part in &parts {
+        scalar.write_all(part).unwrap();
+    }
+    assert_eq!(vectored.finalize(), scalar.finalize());
+
+    let mut empty = Sha256::new();
+    assert_eq!(empty.write_vectored(&[]).unwrap(), 0);
+    assert_eq!(empty.finalize(), Sha256::digest(b""));
+}
+
+#[test]
+fn digest_with_progress() {
+    let data = test_data();
+    for &(n, chunk, calls) in &[
+        (0, 64, 0),
+        (1, 64, 1),
+        (64, 64, 1),
+        (65, 64, 2),
+        (20_000, 7, 2858),
+    ] {
+        let data = &data[..n];
+        let mut reported = Vec::new();
+        let res = digest::digest_with_progress::<Sha256, _>(data, chunk, |p| reported.push(p));
+        assert_eq!(res, Sha256::digest(data));
+        assert_eq!(reported.len(), calls);
+        let expected: Vec<u64> = (1..=calls).map(|i| (i * chunk).min(n) as u64).collect();
+        assert_eq!(reported, expected);
+    }
+}
+
+#[test]
+fn finalize_reset_reuse() {
+    let records: &[&[u8]] = &[b"", b"abc", &[0x42; 1000], b"abc"];
+    let mut hasher = Sha256::new();
+    for record in records {
+        hasher.update(record);
+        assert_eq!(hasher.finalize_reset(), Sha256::digest(record));
+    }
+
+    let mut out = Default::default();
+    for record in records {
+        hasher.update(record);
+        hasher.finalize_into_reset(&mut out);
+        assert_eq!(out, Sha256::digest(record));
+    }
+}
+
+#[test]
+fn digest_bits() {
+    let full = Sha256::digest(b"abc");
+    for &(bits, len) in &[
+        (0, 0),
+        (1, 1),
+        (7, 1),
+        (8, 1),
+        (9, 2),
+        (163, 21),
+        (255, 32),
+        (256, 32),
+    ] {
+        let res = digest::digest_bits::<Sha256>(b"abc", bits);
+        assert_eq!(res.len(), len);
+        for i in 0..8 * res.len() {
+            let bit = res[i / 8] >> (7 - i % 8) & 1;
+            let expected = if i < bits {
+                full[i / 8] >> (7 - i % 8) & 1
+            } else {
+                0
+            };
+            assert_eq!(bit, expected, "bits = {}, i = {}", bits, i);
+        }
+    }
+    assert_eq!(
+        digest::digest_bits::<Sha256>(b"abc", 163)[20],
+        full[20] & 0xE0
+    );
+}
+
+#[test]
+#[should_panic]
+fn digest_bits_too_long() {
+    digest::digest_bits::<Sha256>(b"abc", 257);
+}
@@ -0,0 +1,120 @@
+//! Tests for the `DirtyGuard` wrapper.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U8,
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, DirtyGuard, FixedOutputCore,
+        OutputSizeUser, UpdateCore,
+    },
+    Digest, FixedOutputReset, HashMarker, Output, Reset,
+};
+
+/// Toy core which XORs blocks into its state.
+#[derive(Clone, Debug, Default)]
+struct ToyCore {
+    state: u64,
+}
+
+impl HashMarker for ToyCore {}
+
+impl BlockSizeUser for ToyCore {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for ToyCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl OutputSizeUser for ToyCore {
+    type OutputSize = U8;
+}
+
+impl UpdateCore for ToyCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(block);
+            self.state = (self.state ^ u64::from_le_bytes(b)).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        }
+    }
+}
+
+impl FixedOutputCore for ToyCore {
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let state = &mut self.state;
+        buffer.len64_padding_le(0, |b| {
+            let mut w = [0u8; 8];
+            w.copy_from_slice(b);
+            *state = (*state ^ u64::from_le_bytes(w)).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        });
+        out.copy_from_slice(&self.state.to_le_bytes());
+    }
+}
+
+impl Reset for ToyCore {
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
+type Toy = CoreWrapper<ToyCore>;
+type GuardedToy = CoreWrapper<DirtyGuard<ToyCore>>;
+
+#[test]
+fn transparent_for_correct_use() {
+    let data = [0x42u8; 100];
+    assert_eq!(GuardedToy::digest(&data[..]), Toy::digest(&data[..]));
+
+    let mut hasher = GuardedToy::default();
+    for _ in 0..3 {
+        hasher.update(&data[..]);
+        assert_eq!(hasher.finalize_fixed_reset(), Toy::digest(&data[..]));
+    }
+}
+
+fn finalize_dirty(core: &mut DirtyGuard<ToyCore>) {
+    let mut buffer = Buffer::<DirtyGuard<ToyCore>>::default();
+    let mut out = Default::default();
+    core.finalize_fixed_core(&mut buffer, &mut out);
+}
+
+#[test]
+fn reset_clears_dirty_state() {
+    let mut core = DirtyGuard::new(ToyCore::default());
+    finalize_dirty(&mut core);
+    core.reset();
+    core.update_blocks(&[Default::default()]);
+    finalize_dirty(&mut core);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "core used after finalization without reset")]
+fn update_after_finalize() {
+    let mut core = DirtyGuard::new(ToyCore::default());
+    finalize_dirty(&mut core);
+    core.update_blocks(&[Default::default()]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "core used after finalization without reset")]
+fn double_finalize() {
+    let mut core = DirtyGuard::new(ToyCore::default());
+    finalize_dirty(&mut core);
+    finalize_dirty(&mut core);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn no_checks_in_release() {
+    let mut core = DirtyGuard::new(ToyCore::default());
+    finalize_dirty(&mut core);
+    core.update_blocks(&[Default::default()]);
+    finalize_dirty(&mut core);
+    assert_eq!(
+        core::mem::size_of::<DirtyGuard<ToyCore>>(),
+        core::mem::size_of::<ToyCore>()
+    );
+}
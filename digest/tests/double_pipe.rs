@@ -0,0 +1,46 @@
+//! Tests for the `DoublePipeHash` combiner.
+use digest::{Digest, DoublePipeHash, FixedOutput, Reset, Update};
+use sha2::Sha256;
+
+type DoublePipe = DoublePipeHash<Sha256>;
+
+fn expected(data: &[u8]) -> Vec<u8> {
+    let mut left = vec![0x00];
+    left.extend_from_slice(data);
+    let mut right = vec![0x01];
+    right.extend_from_slice(data);
+    Sha256::new()
+        .chain_update([0x02])
+        .chain_update(Sha256::digest(&left))
+        .chain_update(Sha256::digest(&right))
+        .finalize()
+        .to_vec()
+}
+
+#[test]
+fn construction() {
+    for data in [&b""[..], b"abc", &[0xa5; 1000]].iter() {
+        let res = DoublePipe::digest(data);
+        assert_eq!(res.len(), 32);
+        assert_eq!(res[..], expected(data)[..]);
+        assert_eq!(res, DoublePipe::digest(data));
+        assert_ne!(res, Sha256::digest(data));
+    }
+    assert_ne!(DoublePipe::digest(b"abc"), DoublePipe::digest(b"abd"));
+}
+
+#[test]
+fn incremental_and_reset() {
+    let mut hasher = DoublePipe::default();
+    Update::update(&mut hasher, b"some ");
+    let copy = hasher.clone();
+    Update::update(&mut hasher, b"data");
+    assert_eq!(hasher.finalize_fixed()[..], expected(b"some data")[..]);
+    assert_eq!(copy.finalize_fixed()[..], expected(b"some ")[..]);
+
+    let mut hasher = DoublePipe::default();
+    Update::update(&mut hasher, b"garbage");
+    Reset::reset(&mut hasher);
+    Update::update(&mut hasher, b"abc");
+    assert_eq!(hasher.finalize_fixed()[..], expected(b"abc")[..]);
+}
@@ -0,0 +1,111 @@
+//! Tests for the `FinalizationTweak` trait.
+#![cfg(feature = "core-api")]
+
+use core::marker::PhantomData;
+use digest::{
+    consts::U8,
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FinalizationTweak,
+        FixedOutputCore, OutputSizeUser, UpdateCore,
+    },
+    Digest, HashMarker, Output,
+};
+
+/// Toy sponge-like core generic over its finalization suffix.
+#[derive(Clone, Default)]
+struct ToyCore<T> {
+    state: [u8; 8],
+    _tweak: PhantomData<T>,
+}
+
+impl<T> ToyCore<T> {
+    fn absorb(&mut self, block: &Block<Self>) {
+        for (i, b) in block.iter().enumerate() {
+            self.state[i] ^= *b;
+        }
+        for r in 0..8 {
+            let prev = self.state[(r + 7) % 8];
+            self.state[r] = self.state[r].rotate_left(3) ^ prev.wrapping_add(r as u8);
+        }
+    }
+}
+
+impl<T> HashMarker for ToyCore<T> {}
+
+impl<T> BlockSizeUser for ToyCore<T> {
+    type BlockSize = U8;
+}
+
+impl<T> BufferKindUser for ToyCore<T> {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl<T> OutputSizeUser for ToyCore<T> {
+    type OutputSize = U8;
+}
+
+impl<T> UpdateCore for ToyCore<T> {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            self.absorb(block);
+        }
+    }
+}
+
+impl<T: FinalizationTweak> FixedOutputCore for ToyCore<T> {
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let block = T::pad_tweaked(buffer);
+        block[7] |= 0x80;
+        self.absorb(block);
+        out.copy_from_slice(&self.state);
+    }
+}
+
+#[derive(Clone, Default)]
+struct HashTweak;
+
+impl FinalizationTweak for HashTweak {
+    const FINALIZATION_SUFFIX: u8 = 0x06;
+}
+
+#[derive(Clone, Default)]
+struct XofTweak;
+
+impl FinalizationTweak for XofTweak {
+    const FINALIZATION_SUFFIX: u8 = 0x1f;
+}
+
+type ToyHash = CoreWrapper<ToyCore<HashTweak>>;
+type ToyXof = CoreWrapper<ToyCore<XofTweak>>;
+
+#[test]
+fn pad_tweaked_places_suffix() {
+    let mut buffer = Buffer::<ToyCore<HashTweak>>::default();
+    buffer.digest_blocks(b"abc", |_| unreachable!());
+    let block = HashTweak::pad_tweaked(&mut buffer);
+    assert_eq!(&block[..], b"abc\x06\x00\x00\x00\x00");
+    assert_eq!(buffer.get_pos(), 0);
+
+    // empty buffer
+    let block = XofTweak::pad_tweaked(&mut buffer);
+    assert_eq!(&block[..], b"\x1f\x00\x00\x00\x00\x00\x00\x00");
+}
+
+#[test]
+fn distinct_suffixes_produce_distinct_outputs() {
+    for msg in &[
+        &b""[..],
+        b"abc",
+        b"abcdefg",
+        b"abcdefgh",
+        b"abcdefghijklmnop",
+    ] {
+        let a = ToyHash::digest(msg);
+        let b = ToyXof::digest(msg);
+        assert_ne!(a, b);
+
+        // same core and suffix is deterministic
+        assert_eq!(a, ToyHash::digest(msg));
+        assert_eq!(b, ToyXof::digest(msg));
+    }
+}
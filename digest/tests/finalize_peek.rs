@@ -0,0 +1,73 @@
+//! Tests for the `FixedOutputCore::finalize_fixed_core_peek` method.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U8,
+    core_api::{Block, BlockSizeUser, Buffer, BufferKindUser, FixedOutputCore, UpdateCore},
+    HashMarker, Output, OutputSizeUser,
+};
+
+/// Mock core which folds blocks into its state.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct MockCore {
+    state: [u8; 8],
+}
+
+impl HashMarker for MockCore {}
+
+impl BlockSizeUser for MockCore {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for MockCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl OutputSizeUser for MockCore {
+    type OutputSize = U8;
+}
+
+impl UpdateCore for MockCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            for (s, b) in self.state.iter_mut().zip(block.iter()) {
+                *s = s.rotate_left(1) ^ b;
+            }
+        }
+    }
+}
+
+impl FixedOutputCore for MockCore {
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let pos = buffer.get_pos() as u8;
+        let block = *buffer.pad_with_zeros();
+        self.update_blocks(&[block]);
+        self.state[0] ^= pos;
+        out.copy_from_slice(&self.state);
+    }
+}
+
+#[test]
+fn peek_matches_finalize() {
+    let data: Vec<u8> = (0..100).collect();
+    for &len in &[0, 1, 7, 8, 9, 63, 100] {
+        let mut core = MockCore::default();
+        let mut buffer = Buffer::<MockCore>::default();
+        buffer.digest_blocks(&data[..len], |blocks| core.update_blocks(blocks));
+
+        let (core_copy, data_copy) = (core.clone(), buffer.get_data().to_vec());
+        let mut peeked = Output::<MockCore>::default();
+        core.finalize_fixed_core_peek(&buffer, &mut peeked);
+        // neither the state nor the buffer is affected
+        assert_eq!(core, core_copy);
+        assert_eq!(buffer.get_data(), &data_copy[..]);
+
+        let mut peeked_again = Output::<MockCore>::default();
+        core.finalize_fixed_core_peek(&buffer, &mut peeked_again);
+        assert_eq!(peeked, peeked_again);
+
+        let mut out = Output::<MockCore>::default();
+        core.finalize_fixed_core(&mut buffer, &mut out);
+        assert_eq!(peeked, out);
+    }
+}
@@ -0,0 +1,40 @@
+//! Tests for the `fingerprint` function.
+#![cfg(feature = "alloc")]
+
+use digest::fingerprint;
+use sha2::Sha256;
+
+const SALT: &[u8] = b"device-id v1";
+
+#[test]
+fn fingerprint_vectors() {
+    assert_eq!(fingerprint::<Sha256>(SALT, b"abc", 10), "EK6SJP4D6PVD9QE6");
+    assert_eq!(fingerprint::<Sha256>(SALT, b"abc", 3), "EK6SJ");
+    assert_eq!(
+        fingerprint::<Sha256>(SALT, b"abc", 32),
+        "EK6SJP4D6PVD9QE6RBT0C05EYMPJS2KMD15X7SATYX49P0HJS72G",
+    );
+    assert_eq!(fingerprint::<Sha256>(SALT, b"abc", 0), "");
+}
+
+#[test]
+fn fingerprint_properties() {
+    for bytes in 0..=32 {
+        let fp = fingerprint::<Sha256>(SALT, b"some device", bytes);
+        assert_eq!(fp.len() * 5 / 8, bytes);
+        assert!(fp.len() * 5 < 8 * bytes + 5);
+        assert!(fp
+            .bytes()
+            .all(|c| c.is_ascii_digit() || (c.is_ascii_uppercase() && !b"ILOU".contains(&c))));
+        assert_eq!(fp, fingerprint::<Sha256>(SALT, b"some device", bytes));
+    }
+    let a = fingerprint::<Sha256>(SALT, b"some device", 16);
+    assert_ne!(a, fingerprint::<Sha256>(b"other salt", b"some device", 16));
+    assert_ne!(a, fingerprint::<Sha256>(SALT, b"other device", 16));
+}
+
+#[test]
+#[should_panic]
+fn fingerprint_too_long() {
+    fingerprint::<Sha256>(SALT, b"abc", 33);
+}
@@ -0,0 +1,53 @@
+//! Tests for the `FixedOutput` trait.
+use digest::{Digest, FixedOutput, InvalidBufferSize};
+use sha2::{Sha256, Sha512};
+
+#[test]
+fn try_finalize_into() {
+    let expected = Sha256::digest(b"abc");
+
+    let mut buf = [0u8; 32];
+    let hasher = Sha256::new().chain_update(b"abc");
+    assert_eq!(hasher.try_finalize_into(&mut buf), Ok(32));
+    assert_eq!(buf[..], expected[..]);
+
+    let mut buf = [0xffu8; 40];
+    let hasher = Sha256::new().chain_update(b"abc");
+    assert_eq!(hasher.try_finalize_into(&mut buf), Ok(32));
+    assert_eq!(buf[..32], expected[..]);
+    assert_eq!(buf[32..], [0xff; 8]);
+
+    let mut buf = [0xffu8; 31];
+    let hasher = Sha256::new().chain_update(b"abc");
+    assert_eq!(hasher.try_finalize_into(&mut buf), Err(InvalidBufferSize));
+    assert_eq!(buf, [0xff; 31]);
+
+    let mut buf = [0u8; 64];
+    assert_eq!(Sha512::new().try_finalize_into(&mut buf), Ok(64));
+    assert_eq!(buf[..], Sha512::digest(b"")[..]);
+    assert_eq!(
+        Sha512::new().try_finalize_into(&mut []),
+        Err(InvalidBufferSize)
+    );
+}
+
+#[cfg(feature = "mac")]
+#[test]
+fn finalize_and_verify() {
+    let expected = Sha256::digest(b"abc");
+    let hasher = Sha256::new().chain_update(b"abc");
+    assert_eq!(hasher.finalize_and_verify(&expected), Ok(()));
+
+    for i in 0..expected.len() {
+        let mut wrong = expected;
+        wrong[i] ^= 0x80;
+        let hasher = Sha256::new().chain_update(b"abc");
+        assert_eq!(hasher.finalize_and_verify(&wrong), Err(expected));
+    }
+
+    let hasher = Sha256::new().chain_update(b"abd");
+    assert_eq!(
+        hasher.finalize_and_verify(&expected),
+        Err(Sha256::digest(b"abd"))
+    );
+}
@@ -0,0 +1,127 @@
+//! Tests for the `FixedOutputCoreExt` trait.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U8,
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
+        FixedOutputCoreExt, OutputSizeUser, UpdateCore,
+    },
+    Digest, HashMarker, Output,
+};
+
+/// Toy sponge with 8 byte rate and 8 byte capacity.
+#[derive(Clone, Default)]
+struct ToySponge {
+    state: [u8; 16],
+}
+
+impl ToySponge {
+    fn permute(&mut self) {
+        for r in 0..16 {
+            let prev = self.state[(r + 15) % 16];
+            self.state[r] = self.state[r].rotate_left(3) ^ prev.wrapping_add(r as u8);
+        }
+    }
+
+    fn absorb(&mut self, block: &Block<Self>) {
+        for (s, b) in self.state.iter_mut().zip(block.iter()) {
+            *s ^= *b;
+        }
+        self.permute();
+    }
+
+    fn absorb_last(&mut self, buffer: &mut Buffer<Self>) {
+        let block = buffer.pad_with_zeros();
+        block[7] |= 0x80;
+        let block = *block;
+        self.absorb(&block);
+    }
+}
+
+impl HashMarker for ToySponge {}
+
+impl BlockSizeUser for ToySponge {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for ToySponge {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl OutputSizeUser for ToySponge {
+    type OutputSize = U8;
+}
+
+impl UpdateCore for ToySponge {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            self.absorb(block);
+        }
+    }
+}
+
+impl FixedOutputCore for ToySponge {
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        self.absorb_last(buffer);
+        out.copy_from_slice(&self.state[..8]);
+    }
+}
+
+impl FixedOutputCoreExt for ToySponge {
+    fn finalize_fixed_core_ext(
+        &mut self,
+        buffer: &mut Buffer<Self>,
+        out: &mut Output<Self>,
+        side: &mut [u8],
+    ) {
+        self.absorb_last(buffer);
+        out.copy_from_slice(&self.state[..8]);
+        for chunk in side.chunks_mut(8) {
+            chunk.copy_from_slice(&self.state[8..8 + chunk.len()]);
+            self.permute();
+        }
+    }
+}
+
+type ToyHash = CoreWrapper<ToySponge>;
+
+#[test]
+fn main_output_matches_fixed_output() {
+    for msg in &[&b""[..], b"abc", b"abcdefgh", b"abcdefghijklmnopq"] {
+        let mut side = [0u8; 8];
+        let out = ToyHash::new()
+            .chain_update(msg)
+            .finalize_fixed_ext(&mut side);
+        assert_eq!(out, ToyHash::digest(msg));
+        assert_ne!(&out[..], &side[..]);
+    }
+}
+
+#[test]
+fn side_output_is_deterministic() {
+    let mut side1 = [0u8; 20];
+    let mut side2 = [0u8; 20];
+    let out1 = ToyHash::new()
+        .chain_update(b"abc")
+        .finalize_fixed_ext(&mut side1);
+    let out2 = ToyHash::new()
+        .chain_update(b"abc")
+        .finalize_fixed_ext(&mut side2);
+    assert_eq!(out1, out2);
+    assert_eq!(side1, side2);
+    assert_ne!(side1, [0u8; 20]);
+
+    // shorter side output is a prefix of the longer one
+    let mut short = [0u8; 5];
+    ToyHash::new()
+        .chain_update(b"abc")
+        .finalize_fixed_ext(&mut short);
+    assert_eq!(short[..], side1[..5]);
+
+    let mut other = [0u8; 20];
+    ToyHash::new()
+        .chain_update(b"abd")
+        .finalize_fixed_ext(&mut other);
+    assert_ne!(side1, other);
+}
@@ -0,0 +1,100 @@
+//! Tests for the `MacFramedWriter` and `MacFramedReader` types.
+#![cfg(all(feature = "std", feature = "mac"))]
+
+use digest::{Hmac, Mac, MacFramedReader, MacFramedWriter};
+use hex_literal::hex;
+use sha2::Sha256;
+use std::io;
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn mac(key: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(key).unwrap()
+}
+
+fn write_frames(frames: &[&[u8]]) -> Vec<u8> {
+    let mut w = MacFramedWriter::new(Vec::new(), mac(b"key"));
+    for frame in frames {
+        w.write_frame(frame).unwrap();
+    }
+    w.flush().unwrap();
+    w.into_inner()
+}
+
+#[test]
+fn wire_format() {
+    let buf = write_frames(&[b"hello"]);
+    assert_eq!(&buf[..4], [0, 0, 0, 5]);
+    assert_eq!(&buf[4..9], b"hello");
+    assert_eq!(
+        buf[9..],
+        hex!("5ab9f4b402b4a77046398f3f05bc072aba264ae313eecfe830525e0fa0a6339e")[..],
+    );
+}
+
+#[test]
+fn round_trip() {
+    let big = vec![0xA5; 100_000];
+    let frames: &[&[u8]] = &[b"first", b"", &big, b"last"];
+    let buf = write_frames(frames);
+
+    let mut r = MacFramedReader::new(&buf[..], mac(b"key"));
+    for frame in frames {
+        assert_eq!(r.read_frame().unwrap().as_deref(), Some(*frame));
+    }
+    assert_eq!(r.read_frame().unwrap(), None);
+    assert!(r.get_ref().is_empty());
+}
+
+fn read_all(buf: &[u8], key: &[u8]) -> io::Result<Vec<Vec<u8>>> {
+    let mut r = MacFramedReader::new(buf, mac(key));
+    let mut res = Vec::new();
+    while let Some(frame) = r.read_frame()? {
+        res.push(frame);
+    }
+    Ok(res)
+}
+
+#[test]
+fn tampered_frames_are_rejected() {
+    let buf = write_frames(&[b"first", b"second"]);
+    assert_eq!(read_all(&buf, b"key").unwrap().len(), 2);
+
+    for i in 0..buf.len() {
+        let mut t = buf.clone();
+        t[i] ^= 0x01;
+        assert!(read_all(&t, b"key").is_err(), "byte {}", i);
+    }
+
+    let err = read_all(&buf, b"wrong key").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn reordered_frames_are_rejected() {
+    let first = write_frames(&[b"frame"]);
+    // the same payload written as the second frame has a different tag
+    let both = write_frames(&[b"frame", b"frame"]);
+    assert_eq!(both[..first.len()], first[..]);
+    assert_ne!(both[first.len()..], first[..]);
+
+    let mut replayed = first.clone();
+    replayed.extend_from_slice(&first);
+    let mut r = MacFramedReader::new(&replayed[..], mac(b"key"));
+    assert_eq!(r.read_frame().unwrap().unwrap(), b"frame");
+    let err = r.read_frame().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn truncated_frames() {
+    let buf = write_frames(&[b"frame"]);
+    for n in 1..buf.len() {
+        let err = read_all(&buf[..n], b"key").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof, "len {}", n);
+    }
+
+    // forged length prefix does not cause a huge allocation
+    let err = read_all(&[0xff, 0xff, 0xff, 0xff, 1, 2, 3], b"key").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
@@ -0,0 +1,166 @@
+//! HMAC-SHA-256 tests using vectors from RFC 4231.
+#![cfg(feature = "mac")]
+
+use digest::{Hmac, Mac};
+use hex_literal::hex;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// (key, data, tag) triples from RFC 4231 (test case 5 is tested separately)
+const VECTORS: &[(&[u8], &[u8], [u8; 32])] = &[
+    (
+        &[0x0b; 20],
+        b"Hi There",
+        hex!("b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"),
+    ),
+    (
+        b"Jefe",
+        b"what do ya want for nothing?",
+        hex!("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"),
+    ),
+    (
+        &[0xaa; 20],
+        &[0xdd; 50],
+        hex!("773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe"),
+    ),
+    (
+        &hex!("0102030405060708090a0b0c0d0e0f10111213141516171819"),
+        &[0xcd; 50],
+        hex!("82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b"),
+    ),
+    (
+        &[0xaa; 131],
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+        hex!("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"),
+    ),
+    (
+        &[0xaa; 131],
+        b"This is a test using a larger than block-size key and a larger \
+          than block-size data. The key needs to be hashed before being \
+          used by the HMAC algorithm.",
+        hex!("9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2"),
+    ),
+];
+
+#[test]
+fn rfc4231_vectors() {
+    for (i, (key, data, tag)) in VECTORS.iter().enumerate() {
+        let mut mac = HmacSha256::new_from_slice(key).unwrap();
+        mac.update(data);
+        assert_eq!(
+            mac.clone().finalize().into_bytes()[..],
+            tag[..],
+            "vector #{}",
+            i
+        );
+        assert!(mac.verify_slice(tag).is_ok(), "vector #{}", i);
+    }
+}
+
+#[test]
+fn rfc4231_truncated() {
+    let mut mac = HmacSha256::new_from_slice(&[0x0c; 20]).unwrap();
+    mac.update(b"Test With Truncation");
+    let tag = hex!("a3b6167473100ee06e0c796c2955552b");
+    assert!(mac.verify_truncated_left(&tag).is_ok());
+}
+
+#[test]
+fn chunked_and_reset() {
+    let (key, data, tag) = VECTORS[5];
+    let mut mac = HmacSha256::new_from_slice(key).unwrap();
+    for chunk in data.chunks(7) {
+        mac.update(chunk);
+    }
+    assert_eq!(mac.finalize_reset().into_bytes()[..], tag[..]);
+
+    // the same instance can be reused after reset
+    mac.update(data);
+    assert_eq!(mac.finalize_reset().into_bytes()[..], tag[..]);
+
+    mac.update(b"garbage");
+    mac.reset();
+    mac.update(data);
+    assert!(mac.verify_slice(&tag).is_ok());
+}
+
+mod verify_encoded {
+    use super::HmacSha256;
+    use digest::{Mac, MacError, TagEncoding};
+
+    const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+    fn mac() -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(b"key").unwrap();
+        mac.update(MSG);
+        mac
+    }
+
+    fn check(tag: &str, encoding: TagEncoding, keep_bytes: usize) -> Result<(), MacError> {
+        mac().verify_encoded(tag, encoding, keep_bytes)
+    }
+
+    #[test]
+    fn hex() {
+        let tag = "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8";
+        assert_eq!(check(tag, TagEncoding::Hex, 32), Ok(()));
+        assert_eq!(check(&tag.to_uppercase(), TagEncoding::Hex, 32), Ok(()));
+        assert_eq!(check(&tag[..20], TagEncoding::Hex, 10), Ok(()));
+        assert_eq!(check(&tag[..20], TagEncoding::Hex, 11), Err(MacError));
+        assert_eq!(check(&tag[..19], TagEncoding::Hex, 10), Err(MacError));
+        assert_eq!(
+            check("f7bc83f430538424b13g", TagEncoding::Hex, 10),
+            Err(MacError)
+        );
+    }
+
+    #[test]
+    fn base32() {
+        assert_eq!(check("666IH5BQKOCCJMJS", TagEncoding::Base32, 10), Ok(()));
+        assert_eq!(check("666ih5bqkoccjmjs", TagEncoding::Base32, 10), Ok(()));
+        let padded = "666IH5BQKOCCJMJSTDTA====";
+        assert_eq!(check(padded, TagEncoding::Base32, 12), Ok(()));
+        assert_eq!(check(&padded[..20], TagEncoding::Base32, 12), Ok(()));
+        assert_eq!(check(&padded[..21], TagEncoding::Base32, 12), Err(MacError));
+        // non-zero trailing bits
+        assert_eq!(
+            check("666IH5BQKOCCJMJSTDTB", TagEncoding::Base32, 12),
+            Err(MacError)
+        );
+        assert_eq!(
+            check("666IH5BQKOCCJMJ1", TagEncoding::Base32, 10),
+            Err(MacError)
+        );
+    }
+
+    #[test]
+    fn base64() {
+        let tag = "97yD9DBThCSxMpjmqm+xQ+9NWaFJRhdZl0edvC0aPNg=";
+        assert_eq!(check(tag, TagEncoding::Base64, 32), Ok(()));
+        assert_eq!(check(&tag[..43], TagEncoding::Base64, 32), Ok(()));
+        assert_eq!(check("97yD9DBThCSxMg==", TagEncoding::Base64, 10), Ok(()));
+        assert_eq!(check("97yD9DBThCSxMg", TagEncoding::Base64, 10), Ok(()));
+        assert_eq!(
+            check("97yD9DBThCSxMg=", TagEncoding::Base64, 10),
+            Err(MacError)
+        );
+        // URL-safe alphabet is not accepted
+        let url = tag.replace('+', "-");
+        assert_eq!(check(&url, TagEncoding::Base64, 32), Err(MacError));
+    }
+
+    #[test]
+    fn tampered() {
+        let tag = "97yD9DBThCSxMg==";
+        for i in 0..14 {
+            let mut bad = tag.as_bytes().to_vec();
+            bad[i] = if bad[i] == b'A' { b'B' } else { b'A' };
+            let bad = String::from_utf8(bad).unwrap();
+            assert_eq!(check(&bad, TagEncoding::Base64, 10), Err(MacError));
+        }
+        assert_eq!(check("", TagEncoding::Base64, 0), Err(MacError));
+        let long = "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd800";
+        assert_eq!(check(long, TagEncoding::Hex, 33), Err(MacError));
+    }
+}
@@ -0,0 +1,98 @@
+//! Tests for the `HmacDrbg` generator.
+#![cfg(feature = "mac")]
+
+use digest::HmacDrbg;
+use hex_literal::hex;
+use sha2::{Digest, Sha256};
+
+/// Generate candidate nonce for ECDSA over P-256 as defined in RFC 6979
+/// section 3.2 (private key and message from appendix A.2.5).
+fn rfc6979_candidate(msg: &[u8]) -> [u8; 32] {
+    let x = hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+    // for the used messages hashes are smaller than the group order,
+    // so `bits2octets(h1)` is equal to `h1`
+    let h1 = Sha256::digest(msg);
+    let mut drbg = HmacDrbg::<Sha256>::new(&x, &h1, &[]);
+    let mut k = [0u8; 32];
+    drbg.fill_bytes(&mut k);
+    k
+}
+
+#[test]
+fn rfc6979_p256_sha256() {
+    assert_eq!(
+        rfc6979_candidate(b"sample"),
+        hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60"),
+    );
+    assert_eq!(
+        rfc6979_candidate(b"test"),
+        hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0"),
+    );
+}
+
+/// Instantiate generator, reseed it and generate output twice as done by
+/// NIST CAVP tests with prediction resistance disabled, returning
+/// the second output.
+fn cavp_reseed(
+    entropy: &[u8],
+    nonce: &[u8],
+    pers: &[u8],
+    entropy_reseed: &[u8],
+    add_reseed: &[u8],
+    add: [&[u8]; 2],
+) -> [u8; 128] {
+    let mut drbg = HmacDrbg::<Sha256>::new(entropy, nonce, pers);
+    drbg.reseed(entropy_reseed, add_reseed);
+    let mut out = [0u8; 128];
+    drbg.generate(&mut out, add[0]);
+    drbg.generate(&mut out, add[1]);
+    out
+}
+
+/// HMAC_DRBG SHA-256 vectors from NIST CAVS 14.3 (`drbgvectors_pr_false`,
+/// `PersonalizationStringLen = 256`, `AdditionalInputLen = 256`),
+/// COUNT = 0 and 1.
+#[test]
+fn cavp_reseed_and_additional_input() {
+    let out = cavp_reseed(
+        &hex!("cdb0d9117cc6dbc9ef9dcb06a97579841d72dc18b2d46a1cb61e314012bdf416"),
+        &hex!("d0c0d01d156016d0eb6b7e9c7c3c8da8"),
+        &hex!("6f0fb9eab3f9ea7ab0a719bfa879bf0aaed683307fda0c6d73ce018b6e34faaa"),
+        &hex!("8ec6f7d5a8e2e88f43986f70b86e050d07c84b931bcf18e601c5a3eee3064c82"),
+        &hex!("1ab4ca9014fa98a55938316de8ba5a68c629b0741bdd058c4d70c91cda5099b3"),
+        [
+            &hex!("16e2d0721b58d839a122852abd3bf2c942a31c84d82fca74211871880d7162ff"),
+            &hex!("53686f042a7b087d5d2eca0d2a96de131f275ed7151189f7ca52deaa78b79fb2"),
+        ],
+    );
+    assert_eq!(
+        out[..],
+        hex!(
+            "dda04a2ca7b8147af1548f5d086591ca4fd951a345ce52b3cd49d47e84aa31a1
+             83e31fbc42a1ff1d95afec7143c8008c97bc2a9c091df0a763848391f68cb4a3
+             66ad89857ac725a53b303ddea767be8dc5f605b1b95f6d24c9f06be65a973a08
+             9320b3cc42569dcfd4b92b62a993785b0301b3fc452445656fce22664827b88f"
+        )[..],
+    );
+
+    let out = cavp_reseed(
+        &hex!("3e42348bf76c0559cce9a44704308c85d9c205b676af0ac6ba377a5da12d3244"),
+        &hex!("9af783973c632a490f03dbb4b4852b1e"),
+        &hex!("2e51c7a8ac70adc37fc7e40d59a8e5bf8dfd8f7b027c77e6ec648bd0c41a78de"),
+        &hex!("45718ac567fd2660b91c8f5f1f8f186c58c6284b6968eadc9810b7beeca148a1"),
+        &hex!("63a107246a2070739aa4bed6746439d8c2ce678a54fc887c5aba29c502da7ba9"),
+        [
+            &hex!("e4576291b1cde51c5044fdc5375624cebf63333c58c7457ca7490da037a9556e"),
+            &hex!("b5a3fbd57784b15fd875e0b0c5e59ec5f089829fac51620aa998fff003534d6f"),
+        ],
+    );
+    assert_eq!(
+        out[..],
+        hex!(
+            "c624d26087ffb8f39836c067ba37217f1977c47172d5dcb7d40193a1cfe20158
+             b774558cbee8eb6f9c62d629e1bcf70a1439e46c5709ba4c94a006ba94994796
+             e10660d6cb1e150a243f7ba5d35c8572fd96f43c08490131797e86d3ed8467b6
+             92f92f668631b1d32862c3dc43bfba686fe72fdd947db2792463e920522eb4bc"
+        )[..],
+    );
+}
@@ -0,0 +1,79 @@
+//! HPKE labeled KDF tests.
+#![cfg(feature = "mac")]
+
+use digest::{
+    hpke::{labeled_expand, labeled_extract},
+    Hmac,
+};
+use hex_literal::hex;
+use sha2::Sha256;
+
+type M = Hmac<Sha256>;
+
+/// Suite ID of DHKEM(X25519, HKDF-SHA256).
+const KEM_SUITE: &[u8] = b"KEM\x00\x20";
+/// Suite ID of DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, AES-128-GCM.
+const HPKE_SUITE: &[u8] = b"HPKE\x00\x20\x00\x01\x00\x01";
+
+/// Key schedule context of the RFC 9180 A.1.1 test vector.
+const CTX: [u8; 65] = hex!("00725611c9d98c07c03f60095cd32d400d8347d45ed67097bbad50fc56da742d07cb6cffde367bb0565ba28bb02c90744a20f5ef37f30523526106f637abb05449");
+
+/// Derivation of the ephemeral key from RFC 9180 Appendix A.1.1.
+#[test]
+fn derive_key_pair() {
+    let ikm = hex!("7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234");
+    let prk = labeled_extract::<M>(KEM_SUITE, b"", b"dkp_prk", &ikm).unwrap();
+    let mut sk = [0u8; 32];
+    labeled_expand::<M>(KEM_SUITE, &prk, b"sk", b"", &mut sk).unwrap();
+    assert_eq!(
+        sk,
+        hex!("52c4a758a802cd8b936eceea314432798d5baf2d7e9235dc084ab1b9cfa2f736")
+    );
+}
+
+/// Key schedule from RFC 9180 Appendix A.1.1.
+#[test]
+fn key_schedule() {
+    let shared_secret = hex!("fe0e18c9f024ce43799ae393c7e8fe8fce9d218875e8227b0187c04e7d2ea1fc");
+    let secret = labeled_extract::<M>(HPKE_SUITE, &shared_secret, b"secret", b"").unwrap();
+    assert_eq!(
+        secret[..],
+        hex!("12fff91991e93b48de37e7daddb52981084bd8aa64289c3788471d9a9712f397")[..]
+    );
+
+    let mut key = [0u8; 16];
+    labeled_expand::<M>(HPKE_SUITE, &secret, b"key", &CTX, &mut key).unwrap();
+    assert_eq!(key, hex!("4531685d41d65f03dc48f6b8302c05b0"));
+
+    let mut base_nonce = [0u8; 12];
+    labeled_expand::<M>(HPKE_SUITE, &secret, b"base_nonce", &CTX, &mut base_nonce).unwrap();
+    assert_eq!(base_nonce, hex!("56d890e5accaaf011cff4b7d"));
+
+    let mut exporter_secret = [0u8; 32];
+    labeled_expand::<M>(HPKE_SUITE, &secret, b"exp", &CTX, &mut exporter_secret).unwrap();
+    assert_eq!(
+        exporter_secret,
+        hex!("45ff1c2e220db587171952c0592d5f5ebe103f1561a2614e38f2ffd47e99e3f8")
+    );
+
+    let mut exported = [0u8; 32];
+    labeled_expand::<M>(HPKE_SUITE, &exporter_secret, b"sec", b"", &mut exported).unwrap();
+    assert_eq!(
+        exported,
+        hex!("3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee")
+    );
+}
+
+#[test]
+fn expand_max_len() {
+    let prk = [0x42u8; 32];
+    let mut out = [0u8; 255 * 32];
+    labeled_expand::<M>(HPKE_SUITE, &prk, b"x", b"", &mut out).unwrap();
+    assert_eq!(
+        out[255 * 32 - 16..],
+        hex!("dc2e219a32180b1aeac3ac93011d8031")[..]
+    );
+
+    let mut out = [0u8; 255 * 32 + 1];
+    assert!(labeled_expand::<M>(HPKE_SUITE, &prk, b"x", b"", &mut out).is_err());
+}
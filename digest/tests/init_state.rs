@@ -0,0 +1,54 @@
+//! Tests for the `InitState` trait.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U64,
+    core_api::{BlockSizeUser, BufferKindUser, CoreWrapper, InitState},
+};
+use hex_literal::hex;
+
+/// SHA-256 initial hash value (FIPS 180-4, section 5.3.3).
+const SHA256_IV: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+const SHA256_IV_BYTES: [u8; 32] = hex!(
+    "6a09e667bb67ae853c6ef372a54ff53a
+    510e527f9b05688c1f83d9ab5be0cd19"
+);
+
+/// Mock core which only declares the SHA-256 IV.
+#[derive(Clone, Default)]
+struct MockCore;
+
+impl BlockSizeUser for MockCore {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for MockCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl InitState for MockCore {
+    fn init_state_bytes() -> &'static [u8] {
+        &SHA256_IV_BYTES
+    }
+}
+
+#[test]
+fn init_state_bytes() {
+    let iv = MockCore::init_state_bytes();
+    assert_eq!(iv, &SHA256_IV_BYTES[..]);
+    for (chunk, &v) in iv.chunks_exact(4).zip(SHA256_IV.iter()) {
+        assert_eq!(chunk, &v.to_be_bytes()[..]);
+    }
+    // the wrapper forwards to the core
+    assert_eq!(CoreWrapper::<MockCore>::init_state_bytes(), iv);
+}
@@ -0,0 +1,184 @@
+//! Minimal cSHAKE and SHAKE sponges used for testing of NIST SP 800-185
+//! constructions. NOT optimized.
+#![allow(dead_code)]
+
+use digest::{
+    consts::{U136, U168},
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, ExtendableOutputCore,
+        FinalizationTweak, UpdateCore, XofReaderCore,
+    },
+    HashMarker,
+};
+
+const RC: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808a,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808b,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008a,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000a,
+    0x0000_0000_8000_808b,
+    0x8000_0000_0000_008b,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800a,
+    0x8000_0000_8000_000a,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+const ROTC: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+const PILN: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+fn keccak_f(st: &mut [u64; 25]) {
+    for rc in RC.iter() {
+        // theta
+        let mut bc = [0u64; 5];
+        for i in 0..5 {
+            bc[i] = st[i] ^ st[i + 5] ^ st[i + 10] ^ st[i + 15] ^ st[i + 20];
+        }
+        for i in 0..5 {
+            let t = bc[(i + 4) % 5] ^ bc[(i + 1) % 5].rotate_left(1);
+            for j in (0..25).step_by(5) {
+                st[j + i] ^= t;
+            }
+        }
+        // rho and pi
+        let mut t = st[1];
+        for (&j, &r) in PILN.iter().zip(ROTC.iter()) {
+            let tmp = st[j];
+            st[j] = t.rotate_left(r);
+            t = tmp;
+        }
+        // chi
+        for j in (0..25).step_by(5) {
+            let mut bc = [0u64; 5];
+            bc.copy_from_slice(&st[j..j + 5]);
+            for i in 0..5 {
+                st[j + i] ^= !bc[(i + 1) % 5] & bc[(i + 2) % 5];
+            }
+        }
+        // iota
+        st[0] ^= rc;
+    }
+}
+
+fn absorb(st: &mut [u64; 25], block: &[u8]) {
+    for (lane, chunk) in st.iter_mut().zip(block.chunks(8)) {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(chunk);
+        *lane ^= u64::from_le_bytes(b);
+    }
+    keccak_f(st);
+}
+
+macro_rules! keccak_core {
+    ($core:ident, $reader:ident, $alias:ident, $rate:ty, $suffix:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Default)]
+        pub struct $core {
+            state: [u64; 25],
+        }
+
+        impl HashMarker for $core {}
+
+        impl BlockSizeUser for $core {
+            type BlockSize = $rate;
+        }
+
+        impl BufferKindUser for $core {
+            type BufferKind = digest::block_buffer::Eager;
+        }
+
+        impl FinalizationTweak for $core {
+            const FINALIZATION_SUFFIX: u8 = $suffix;
+        }
+
+        impl UpdateCore for $core {
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                for block in blocks {
+                    absorb(&mut self.state, block);
+                }
+            }
+        }
+
+        impl ExtendableOutputCore for $core {
+            type ReaderCore = $reader;
+
+            fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+                let block = Self::pad_tweaked(buffer);
+                let n = block.len();
+                block[n - 1] |= 0x80;
+                absorb(&mut self.state, block);
+                $reader {
+                    state: self.state,
+                    first: true,
+                }
+            }
+        }
+
+        #[derive(Clone)]
+        pub struct $reader {
+            state: [u64; 25],
+            first: bool,
+        }
+
+        impl BlockSizeUser for $reader {
+            type BlockSize = $rate;
+        }
+
+        impl XofReaderCore for $reader {
+            fn read_block(&mut self) -> Block<Self> {
+                if !self.first {
+                    keccak_f(&mut self.state);
+                }
+                self.first = false;
+                let mut block = Block::<Self>::default();
+                for (chunk, lane) in block.chunks_mut(8).zip(self.state.iter()) {
+                    chunk.copy_from_slice(&lane.to_le_bytes());
+                }
+                block
+            }
+        }
+
+        pub type $alias = CoreWrapper<$core>;
+    };
+}
+
+macro_rules! cshake_core {
+    ($core:ident, $reader:ident, $alias:ident, $rate:ty) => {
+        keccak_core!(
+            $core,
+            $reader,
+            $alias,
+            $rate,
+            0x04,
+            "cSHAKE sponge with empty function name and customization string not absorbed yet."
+        );
+    };
+}
+
+macro_rules! shake_core {
+    ($core:ident, $reader:ident, $alias:ident, $rate:ty) => {
+        keccak_core!($core, $reader, $alias, $rate, 0x1F, "SHAKE sponge.");
+    };
+}
+
+cshake_core!(CShake128Core, CShake128ReaderCore, CShake128, U168);
+cshake_core!(CShake256Core, CShake256ReaderCore, CShake256, U136);
+shake_core!(Shake128Core, Shake128ReaderCore, Shake128, U168);
+shake_core!(Shake256Core, Shake256ReaderCore, Shake256, U136);
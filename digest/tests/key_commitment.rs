@@ -0,0 +1,44 @@
+//! Tests for the MAC key commitment helper.
+#![cfg(feature = "mac")]
+
+use digest::{key_commitment, Hmac, Key, Mac, KEY_COMMITMENT_DOMAIN};
+use hex_literal::hex;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn key(seed: u8) -> Key<HmacSha256> {
+    let mut key = Key::<HmacSha256>::default();
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = seed.wrapping_add(i as u8);
+    }
+    key
+}
+
+#[test]
+fn commitment_vector() {
+    assert_eq!(
+        key_commitment::<HmacSha256>(&key(0))[..],
+        hex!("2a76f5688b4ea807c931e0171614602169fba226746e55ca9437480670db4e1d")[..],
+    );
+}
+
+#[test]
+fn commitment_is_tag_over_domain() {
+    let key = key(42);
+    let mut mac = <HmacSha256 as Mac>::new(&key);
+    mac.update(KEY_COMMITMENT_DOMAIN);
+    assert_eq!(
+        key_commitment::<HmacSha256>(&key),
+        mac.finalize().into_bytes()
+    );
+}
+
+#[test]
+fn commitments_differ_between_keys() {
+    let a = key_commitment::<HmacSha256>(&key(1));
+    assert_eq!(a, key_commitment::<HmacSha256>(&key(1)));
+    for seed in 2..32 {
+        assert_ne!(a, key_commitment::<HmacSha256>(&key(seed)));
+    }
+}
@@ -0,0 +1,41 @@
+//! Tests for the MAC-keyed shuffle.
+#![cfg(feature = "mac")]
+
+use digest::{keyed_shuffle, Hmac, Key};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn key(b: u8) -> Key<HmacSha256> {
+    let mut key = Key::<HmacSha256>::default();
+    for (i, k) in key.iter_mut().enumerate() {
+        *k = b.wrapping_add(i as u8);
+    }
+    key
+}
+
+#[test]
+fn known_answer() {
+    let mut v: Vec<u8> = (0..10).collect();
+    keyed_shuffle::<HmacSha256, _>(&key(0), b"raffle 2024", &mut v);
+    assert_eq!(v, [0, 3, 7, 1, 9, 4, 2, 5, 8, 6]);
+}
+
+#[test]
+fn reproducible() {
+    let shuffled = |key: &Key<HmacSha256>, context: &[u8]| {
+        let mut v: Vec<u16> = (0..1000).collect();
+        keyed_shuffle::<HmacSha256, _>(key, context, &mut v);
+        v
+    };
+
+    let a = shuffled(&key(0), b"context");
+    assert_eq!(a, shuffled(&key(0), b"context"));
+    assert_ne!(a, shuffled(&key(1), b"context"));
+    assert_ne!(a, shuffled(&key(0), b"other context"));
+
+    let mut sorted = a.clone();
+    sorted.sort_unstable();
+    assert_ne!(a, sorted);
+    assert_eq!(sorted, (0..1000).collect::<Vec<u16>>());
+}
@@ -0,0 +1,134 @@
+//! KMAC tests using sample vectors from NIST.
+#![cfg(all(feature = "mac", feature = "core-api"))]
+
+mod keccak;
+
+use digest::{kmac::Kmac, MacError, Update, XofReader};
+use hex_literal::hex;
+use keccak::{CShake128, CShake256};
+
+type Kmac128 = Kmac<CShake128>;
+type Kmac256 = Kmac<CShake256>;
+
+const KEY: [u8; 32] = hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f");
+const S: &[u8] = b"My Tagged Application";
+
+fn data200() -> Vec<u8> {
+    (0..200).collect()
+}
+
+#[test]
+fn kmac128_samples() {
+    let vectors: &[(&[u8], &[u8], [u8; 32])] = &[
+        (
+            &[0, 1, 2, 3],
+            b"",
+            hex!("e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e"),
+        ),
+        (
+            &[0, 1, 2, 3],
+            S,
+            hex!("3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5"),
+        ),
+        (
+            &data200(),
+            S,
+            hex!("1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230"),
+        ),
+    ];
+    for (data, s, tag) in vectors {
+        let mut mac = Kmac128::new(&KEY, s);
+        mac.update(data);
+        let mut out = [0u8; 32];
+        mac.clone().finalize_into(&mut out);
+        assert_eq!(&out, tag);
+        assert_eq!(mac.verify(tag), Ok(()));
+    }
+}
+
+#[test]
+fn kmac256_samples() {
+    let vectors: &[(&[u8], &[u8], [u8; 64])] = &[
+        (
+            &[0, 1, 2, 3],
+            S,
+            hex!(
+                "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7
+                 f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+            ),
+        ),
+        (
+            &data200(),
+            b"",
+            hex!(
+                "75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691
+                 589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69"
+            ),
+        ),
+        (
+            &data200(),
+            S,
+            hex!(
+                "b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d9
+                 70fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965"
+            ),
+        ),
+    ];
+    for (data, s, tag) in vectors {
+        let mut mac = Kmac256::new(&KEY, s);
+        mac.update(data);
+        let mut out = [0u8; 64];
+        mac.clone().finalize_into(&mut out);
+        assert_eq!(&out[..], &tag[..]);
+        assert_eq!(mac.verify(tag), Ok(()));
+    }
+}
+
+#[test]
+fn kmacxof_samples() {
+    let mut mac = Kmac128::new(&KEY, b"");
+    mac.update(&[0, 1, 2, 3]);
+    let mut out = [0u8; 32];
+    mac.finalize_xof().read(&mut out);
+    assert_eq!(
+        out,
+        hex!("cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35"),
+    );
+
+    let mut mac = Kmac256::new(&KEY, S);
+    mac.update(&data200());
+    let mut out = [0u8; 64];
+    mac.finalize_xof().read(&mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "d5be731c954ed7732846bb59dbe3a8e30f83e77a4bff4459f2f1c2b4ecebb8ce
+             67ba01c62e8ab8578d2d499bd1bb276768781190020a306a97de281dcc30305d"
+        )[..],
+    );
+}
+
+#[test]
+fn output_length_is_bound() {
+    let mac = Kmac128::new(&KEY, S);
+    let mut short = [0u8; 16];
+    let mut long = [0u8; 32];
+    mac.clone().finalize_into(&mut short);
+    mac.finalize_into(&mut long);
+    assert_ne!(short[..], long[..16]);
+}
+
+#[test]
+fn verify_rejects_invalid_tags() {
+    let mut tag = [0u8; 32];
+    Kmac128::new(&KEY, S).finalize_into(&mut tag);
+    assert_eq!(Kmac128::new(&KEY, S).verify(&tag), Ok(()));
+    for i in 0..tag.len() {
+        let mut bad = tag;
+        bad[i] ^= 1;
+        assert_eq!(Kmac128::new(&KEY, S).verify(&bad), Err(MacError));
+    }
+    assert_eq!(Kmac128::new(&KEY, S).verify(&tag[..31]), Err(MacError));
+    assert_eq!(Kmac128::new(&KEY, S).verify(&[]), Err(MacError));
+    assert_eq!(Kmac128::new(&KEY, b"").verify(&tag), Err(MacError));
+}
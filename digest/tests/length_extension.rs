@@ -0,0 +1,43 @@
+//! Tests for the `LengthExtendable` marker and prefix MAC.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U8,
+    core_api::{BlockSizeUser, BufferKindUser, CoreWrapper},
+    Digest, LengthExtendable,
+};
+use sha2::Sha256;
+
+struct MdCore;
+
+impl BlockSizeUser for MdCore {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for MdCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl LengthExtendable for MdCore {}
+
+fn assert_length_extendable<T: LengthExtendable>() {}
+
+#[test]
+fn wrapper_forwards_marker() {
+    assert_length_extendable::<MdCore>();
+    assert_length_extendable::<CoreWrapper<MdCore>>();
+}
+
+// `Sha256` from the pinned `sha2` release does not opt in
+digest::assert_not_length_extendable!(Sha256);
+
+#[test]
+fn prefix_mac() {
+    let secret = [0x42; 32];
+    let tag = digest::prefix_mac!(Sha256, secret, b"message");
+    let expected = Sha256::new()
+        .chain_update(secret)
+        .chain_update(b"message")
+        .finalize();
+    assert_eq!(tag, expected);
+}
@@ -0,0 +1,124 @@
+//! Tests for the `Mac` trait.
+#![cfg(all(feature = "mac", feature = "std"))]
+
+use digest::{
+    consts::U32, crypto_common::KeySizeUser, Digest, FixedOutput, Key, KeyInit, Mac, MacMarker,
+    Output, OutputSizeUser, Update,
+};
+use sha2::Sha256;
+use std::io::{self, Cursor, Read};
+
+/// Stub MAC computing `SHA-256(key || message)`.
+///
+/// NOT a secure construction, used only for testing the trait plumbing.
+#[derive(Clone)]
+struct StubMac(Sha256);
+
+impl KeySizeUser for StubMac {
+    type KeySize = U32;
+}
+
+impl KeyInit for StubMac {
+    fn new(key: &Key<Self>) -> Self {
+        Self(Sha256::new().chain_update(key))
+    }
+}
+
+impl Update for StubMac {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+}
+
+impl OutputSizeUser for StubMac {
+    type OutputSize = U32;
+}
+
+impl FixedOutput for StubMac {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        Digest::finalize_into(self.0, out);
+    }
+}
+
+impl MacMarker for StubMac {}
+
+const KEY: [u8; 32] = [0x42; 32];
+
+fn stub_tag(msg: &[u8]) -> Output<StubMac> {
+    let mut mac = <StubMac as Mac>::new(&KEY.into());
+    Mac::update(&mut mac, msg);
+    mac.finalize().into_bytes()
+}
+
+#[test]
+fn verify_reader() {
+    let msg: Vec<u8> = (0..20_000u32).map(|i| i as u8).collect();
+    let tag = stub_tag(&msg);
+    let mac = <StubMac as Mac>::new(&KEY.into());
+    assert!(mac
+        .clone()
+        .verify_reader(Cursor::new(&msg), &tag)
+        .unwrap()
+        .is_ok());
+
+    let mut tampered = msg.clone();
+    tampered[12_345] ^= 1;
+    let res = mac.clone().verify_reader(Cursor::new(&tampered), &tag);
+    assert!(res.unwrap().is_err());
+
+    // tags of invalid length are rejected
+    let res = mac.verify_reader(Cursor::new(&msg), &tag[..16]);
+    assert!(res.unwrap().is_err());
+}
+
+#[test]
+fn verify_reader_propagates_errors() {
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    let mac = <StubMac as Mac>::new(&KEY.into());
+    let err = mac.verify_reader(FailingReader, &[0; 32]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn mac_error_display_is_constant() {
+    let msg = b"message";
+    let tag = stub_tag(msg);
+    let mac = <StubMac as Mac>::new(&KEY.into());
+    for i in 0..tag.len() {
+        let mut wrong = tag;
+        wrong[i] ^= 0xff;
+        let mut m = mac.clone();
+        Mac::update(&mut m, msg);
+        let err = m.verify_slice(&wrong).unwrap_err();
+        assert_eq!(err.to_string(), "MAC tag mismatch");
+        assert_eq!(format!("{:?}", err), "MacError");
+    }
+    let err = mac.verify_slice(&tag[..5]).unwrap_err();
+    assert_eq!(err.to_string(), "MAC tag mismatch");
+}
+
+#[cfg(feature = "insecure-diagnostics")]
+#[test]
+fn insecure_mismatch_position() {
+    use digest::MacError;
+
+    assert_eq!(MacError::insecure_mismatch_position(b"abc", b"abc"), None);
+    assert_eq!(MacError::insecure_mismatch_position(b"", b""), None);
+    assert_eq!(
+        MacError::insecure_mismatch_position(b"abc", b"abd"),
+        Some(2)
+    );
+    assert_eq!(
+        MacError::insecure_mismatch_position(b"xbc", b"abc"),
+        Some(0)
+    );
+    assert_eq!(MacError::insecure_mismatch_position(b"ab", b"abc"), Some(2));
+    assert_eq!(MacError::insecure_mismatch_position(b"abc", b""), Some(0));
+}
@@ -0,0 +1,41 @@
+//! Tests for the `MacWriter` adapter.
+#![cfg(all(feature = "std", feature = "mac"))]
+
+use digest::{Hmac, Mac, MacWriter};
+use sha2::Sha256;
+use std::io::{self, IoSlice, Write};
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn mac() -> HmacSha256 {
+    <HmacSha256 as Mac>::new_from_slice(b"key").unwrap()
+}
+
+#[test]
+fn copy_matches_update() {
+    let data: Vec<u8> = (0..20_000u32).map(|i| (i % 251) as u8).collect();
+    let mut writer = MacWriter::new(mac());
+    let n = io::copy(&mut &data[..], &mut writer).unwrap();
+    assert_eq!(n, data.len() as u64);
+
+    let mut expected = mac();
+    expected.update(&data);
+    assert!(writer.finalize() == expected.finalize());
+}
+
+#[test]
+fn write_vectored() {
+    let mut writer = MacWriter::new(mac());
+    let bufs = [
+        IoSlice::new(b"hello "),
+        IoSlice::new(b""),
+        IoSlice::new(b"world"),
+    ];
+    assert_eq!(writer.write_vectored(&bufs).unwrap(), 11);
+    writer.flush().unwrap();
+
+    let tag = writer.into_inner().finalize().into_bytes();
+    let mut expected = mac();
+    expected.update(b"hello world");
+    assert!(expected.verify_slice(&tag).is_ok());
+}
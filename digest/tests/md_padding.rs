@@ -0,0 +1,67 @@
+//! Tests for the Merkle–Damgård padding helpers.
+#![cfg(feature = "core-api")]
+
+use digest::core_api::{md_pad_len, write_md_padding};
+use hex_literal::hex;
+
+#[test]
+fn pad_len() {
+    // SHA-256 parameters
+    assert_eq!(md_pad_len(0, 64, 8), 55);
+    assert_eq!(md_pad_len(3, 64, 8), 52);
+    assert_eq!(md_pad_len(55, 64, 8), 0);
+    // the length field does not fit, so an additional block is used
+    assert_eq!(md_pad_len(56, 64, 8), 63);
+    assert_eq!(md_pad_len(63, 64, 8), 56);
+    assert_eq!(md_pad_len(64, 64, 8), 55);
+    assert_eq!(md_pad_len(64 * 1000 + 3, 64, 8), 52);
+    // SHA-512 parameters
+    assert_eq!(md_pad_len(0, 128, 16), 111);
+    assert_eq!(md_pad_len(111, 128, 16), 0);
+    assert_eq!(md_pad_len(112, 128, 16), 127);
+    for msg_len in 0..300 {
+        let n = msg_len + 1 + md_pad_len(msg_len, 64, 8) + 8;
+        assert_eq!(n % 64, 0);
+        assert!(n - msg_len <= 64 + 8);
+    }
+}
+
+#[test]
+fn padding_only_0x80() {
+    assert_eq!(md_pad_len(63, 64, 0), 0);
+    let mut buf = [0xffu8; 4];
+    assert_eq!(write_md_padding(&mut buf, 63, 64, 0), 1);
+    assert_eq!(buf, [0x80, 0xff, 0xff, 0xff]);
+}
+
+#[test]
+fn sha256_abc_padding() {
+    let mut buf = [0xffu8; 64];
+    let n = write_md_padding(&mut buf, 3, 64, 8);
+    assert_eq!(n, 61);
+    let mut expected = [0u8; 61];
+    expected[0] = 0x80;
+    expected[53..].copy_from_slice(&hex!("0000000000000018"));
+    assert_eq!(&buf[..n], &expected[..]);
+    assert_eq!(buf[n..], [0xff; 3]);
+}
+
+#[test]
+fn sha512_length_field() {
+    let mut buf = [0u8; 256];
+    let msg_len = 0x1234_5678;
+    let n = write_md_padding(&mut buf, msg_len, 128, 16);
+    assert_eq!((msg_len + n) % 128, 0);
+    assert_eq!(buf[0], 0x80);
+    assert_eq!(
+        &buf[n - 16..n],
+        &hex!("00000000000000000000000091a2b3c0")[..]
+    );
+}
+
+#[test]
+#[should_panic]
+fn small_buffer() {
+    let mut buf = [0u8; 8];
+    write_md_padding(&mut buf, 0, 64, 8);
+}
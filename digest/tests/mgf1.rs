@@ -0,0 +1,37 @@
+//! Tests for the MGF1 mask generation function.
+use digest::{mgf1, Digest};
+use hex_literal::hex;
+use sha2::Sha256;
+
+#[test]
+fn mgf1_sha256_vectors() {
+    let mut out = [0u8; 3];
+    mgf1::<Sha256>(b"foo", &mut out);
+    assert_eq!(out, hex!("3bdaba"));
+
+    let mut out = [0u8; 50];
+    mgf1::<Sha256>(b"bar", &mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "382576a7841021cc28fc4c0948753fb8312090cea942ea4c4e735d10dc724b15
+             5f9f6069f289d61daca0cb814502ef04eae1"
+        )[..],
+    );
+}
+
+#[test]
+fn mgf1_lengths() {
+    let mut expected = Vec::new();
+    for counter in 0u32..4 {
+        let mut h = Sha256::new();
+        h.update(b"seed");
+        h.update(counter.to_be_bytes());
+        expected.extend_from_slice(&h.finalize());
+    }
+    for &n in &[0, 1, 31, 32, 33, 64, 100, 128] {
+        let mut out = vec![0u8; n];
+        mgf1::<Sha256>(b"seed", &mut out);
+        assert_eq!(out[..], expected[..n]);
+    }
+}
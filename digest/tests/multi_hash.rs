@@ -0,0 +1,30 @@
+//! Tests for the `MultiHash` adapter.
+use digest::{Digest, MultiHash, Reset, Update};
+use sha2::{Sha224, Sha256, Sha384, Sha512};
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn members_match_independent_hashing() {
+    let mut hasher = MultiHash::new((Sha224::new(), Sha256::new(), Sha512::new()));
+    for chunk in DATA.chunks(5) {
+        hasher.update(chunk);
+    }
+    let (a, b, c) = hasher.finalize();
+    assert_eq!(a, Sha224::digest(DATA));
+    assert_eq!(b, Sha256::digest(DATA));
+    assert_eq!(c, Sha512::digest(DATA));
+}
+
+#[test]
+fn reset_resets_all_members() {
+    let mut hasher = MultiHash::<(Sha256, Sha384, Sha512, Sha224)>::default();
+    hasher.update(b"garbage");
+    hasher.reset();
+    hasher.update(DATA);
+    let (a, b, c, d) = hasher.finalize();
+    assert_eq!(a, Sha256::digest(DATA));
+    assert_eq!(b, Sha384::digest(DATA));
+    assert_eq!(c, Sha512::digest(DATA));
+    assert_eq!(d, Sha224::digest(DATA));
+}
@@ -0,0 +1,51 @@
+//! Tests for the nonce reuse monitor.
+#![cfg(all(feature = "std", feature = "mac"))]
+
+use digest::{Hmac, Mac, NonceMonitor, NonceStatus, Output};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Tag of a simulated message sent with `nonce`.
+fn tag(nonce: &[u8], msg: &[u8]) -> Output<HmacSha256> {
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(b"telemetry key").unwrap();
+    mac.update(nonce);
+    mac.update(msg);
+    mac.finalize().into_bytes()
+}
+
+#[test]
+fn fresh_nonces() {
+    let mut monitor = NonceMonitor::<HmacSha256>::new();
+    assert!(monitor.is_empty());
+    for i in 0u32..100 {
+        let nonce = i.to_be_bytes();
+        assert_eq!(
+            monitor.observe(&nonce, &tag(&nonce, b"msg")),
+            NonceStatus::Fresh
+        );
+    }
+    assert_eq!(monitor.len(), 100);
+    assert_eq!(monitor.misuses(), 0);
+}
+
+#[test]
+fn reuse_detection() {
+    let mut monitor = NonceMonitor::<HmacSha256>::default();
+    let nonce = [7u8; 12];
+    let first = tag(&nonce, b"first message");
+    assert_eq!(monitor.observe(&nonce, &first), NonceStatus::Fresh);
+    // retransmission of the same message
+    assert_eq!(monitor.observe(&nonce, &first), NonceStatus::Repeated);
+    // the same nonce used for a different message
+    let second = tag(&nonce, b"second message");
+    assert_eq!(monitor.observe(&nonce, &second), NonceStatus::Misuse);
+    assert_eq!(monitor.observe(&nonce, &second), NonceStatus::Misuse);
+    // the first tag is kept
+    assert_eq!(monitor.observe(&nonce, &first), NonceStatus::Repeated);
+
+    let other = [8u8; 12];
+    assert_eq!(monitor.observe(&other, &second), NonceStatus::Fresh);
+    assert_eq!(monitor.len(), 2);
+    assert_eq!(monitor.misuses(), 2);
+}
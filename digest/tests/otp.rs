@@ -0,0 +1,61 @@
+//! HOTP and TOTP tests.
+#![cfg(feature = "mac")]
+
+use digest::{
+    otp::{hotp, totp},
+    Hmac,
+};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+const SEED20: &[u8] = b"12345678901234567890";
+const SEED32: &[u8] = b"12345678901234567890123456789012";
+const SEED64: &[u8] = b"1234567890123456789012345678901234567890123456789012345678901234";
+
+/// Test vectors from RFC 4226 Appendix D.
+#[test]
+fn hotp_rfc4226() {
+    let expected = [
+        755224, 287082, 359152, 969429, 338314, 254676, 287922, 162583, 399871, 520489,
+    ];
+    for (counter, &code) in expected.iter().enumerate() {
+        assert_eq!(hotp::<Hmac<Sha1>>(SEED20, counter as u64, 6), code);
+    }
+}
+
+/// Test vectors from RFC 6238 Appendix B.
+#[test]
+fn totp_rfc6238() {
+    let vectors: &[(u64, u32, u32, u32)] = &[
+        (59, 94287082, 46119246, 90693936),
+        (1111111109, 7081804, 68084774, 25091201),
+        (1111111111, 14050471, 67062674, 99943326),
+        (1234567890, 89005924, 91819424, 93441116),
+        (2000000000, 69279037, 90698825, 38618901),
+        (20000000000, 65353130, 77737706, 47863826),
+    ];
+    for &(time, sha1, sha256, sha512) in vectors {
+        assert_eq!(totp::<Hmac<Sha1>>(SEED20, time, 30, 8), sha1);
+        assert_eq!(totp::<Hmac<Sha256>>(SEED32, time, 30, 8), sha256);
+        assert_eq!(totp::<Hmac<Sha512>>(SEED64, time, 30, 8), sha512);
+    }
+}
+
+/// HOTP truncated to a single digit, the last digit of the RFC 4226
+/// Appendix D value for counter 0.
+#[test]
+fn hotp_one_digit() {
+    assert_eq!(hotp::<Hmac<Sha1>>(SEED20, 0, 1), 4);
+}
+
+#[test]
+#[should_panic]
+fn zero_digits() {
+    hotp::<Hmac<Sha256>>(SEED32, 0, 0);
+}
+
+#[test]
+#[should_panic]
+fn too_many_digits() {
+    hotp::<Hmac<Sha256>>(SEED32, 0, 10);
+}
@@ -0,0 +1,156 @@
+//! ParallelHash tests using sample vectors from NIST.
+#![cfg(feature = "core-api")]
+
+mod keccak;
+
+use digest::{
+    parallel_hash::{parallel_hash, ParallelHash},
+    Update, XofReader,
+};
+use hex_literal::hex;
+use keccak::{CShake128, CShake256, Shake128, Shake256};
+
+type ParallelHash128 = ParallelHash<CShake128, Shake128>;
+type ParallelHash256 = ParallelHash<CShake256, Shake256>;
+
+/// Input, block size, customization string and expected result.
+type Vector<T> = (&'static [u8], usize, &'static [u8], T);
+
+const S: &[u8] = b"Parallel Data";
+
+const X1: &[u8] = &hex!("000102030405060710111213141516172021222324252627");
+const X3: &[u8] = &hex!(
+    "000102030405060708090A0B101112131415161718191A1B
+    202122232425262728292A2B303132333435363738393A3B
+    404142434445464748494A4B505152535455565758595A5B"
+);
+
+#[test]
+fn parallel_hash128_samples() {
+    let vectors: &[Vector<[u8; 32]>] = &[
+        (
+            X1,
+            8,
+            b"",
+            hex!("ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5"),
+        ),
+        (
+            X1,
+            8,
+            S,
+            hex!("fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206"),
+        ),
+        (
+            X3,
+            12,
+            S,
+            hex!("f7fd5312896c6685c828af7e2adb97e393e7f8d54e3c2ea4b95e5aca3796e8fc"),
+        ),
+    ];
+    for (data, b, s, expected) in vectors {
+        let mut out = [0u8; 32];
+        parallel_hash::<CShake128, Shake128>(data, *b, s, &mut out);
+        assert_eq!(&out, expected);
+
+        // split input at positions which do not match block boundaries
+        for split in 0..data.len() {
+            let mut hasher = ParallelHash128::new(*b, s);
+            hasher.update(&data[..split]);
+            hasher.update(&data[split..]);
+            let mut out = [0u8; 32];
+            hasher.finalize_into(&mut out);
+            assert_eq!(&out, expected);
+        }
+    }
+}
+
+#[test]
+fn parallel_hash256_samples() {
+    let vectors: &[Vector<[u8; 64]>] = &[
+        (
+            X1,
+            8,
+            b"",
+            hex!(
+                "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c45110553
+                1b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429"
+            ),
+        ),
+        (
+            X1,
+            8,
+            S,
+            hex!(
+                "cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb
+                33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110"
+            ),
+        ),
+        (
+            X3,
+            12,
+            S,
+            hex!(
+                "69d0fcb764ea055dd09334bc6021cb7e4b61348dff375da262671cdec3effa8d
+                1b4568a6cce16b1cad946ddde27f6ce2b8dee4cd1b24851ebf00eb90d43813e9"
+            ),
+        ),
+    ];
+    for (data, b, s, expected) in vectors {
+        let mut out = [0u8; 64];
+        parallel_hash::<CShake256, Shake256>(data, *b, s, &mut out);
+        assert_eq!(&out[..], &expected[..]);
+    }
+}
+
+#[test]
+fn parallel_hash_xof_samples() {
+    let vectors: &[Vector<[u8; 32]>] = &[
+        (
+            X1,
+            8,
+            b"",
+            hex!("fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3"),
+        ),
+        (
+            X1,
+            8,
+            S,
+            hex!("ea2a793140820f7a128b8eb70a9439f93257c6e6e79b4a540d291d6dae7098d7"),
+        ),
+        (
+            X3,
+            12,
+            S,
+            hex!("0127ad9772ab904691987fcc4a24888f341fa0db2145e872d4efd255376602f0"),
+        ),
+    ];
+    for (data, b, s, expected) in vectors {
+        let mut hasher = ParallelHash128::new(*b, s);
+        hasher.update(data);
+        let mut out = [0u8; 32];
+        hasher.finalize_xof().read(&mut out);
+        assert_eq!(&out, expected);
+    }
+
+    let mut hasher = ParallelHash256::new(8, b"");
+    hasher.update(X1);
+    let mut out = [0u8; 64];
+    hasher.finalize_xof().read(&mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "c10a052722614684144d28474850b410757e3cba87651ba167a5cbddff7f4666
+            75fbf84bcae7378ac444be681d729499afca667fb879348bfdda427863c82f1c"
+        )[..]
+    );
+}
+
+#[test]
+fn empty_input() {
+    let mut out = [0u8; 32];
+    parallel_hash::<CShake128, Shake128>(b"", 8, b"", &mut out);
+    assert_eq!(
+        out,
+        hex!("96427c30224408859f95e89e4fa84e1c7a1478dbf2008ac982ce61a77f37a272")
+    );
+}
@@ -0,0 +1,60 @@
+//! Tests for the `parallel_mac` function.
+#![cfg(feature = "mac")]
+
+use digest::{parallel_mac, Hmac, Key};
+use hex_literal::hex;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn key() -> Key<HmacSha256> {
+    let mut key = Key::<HmacSha256>::default();
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    key
+}
+
+#[test]
+fn parallel_mac_vector() {
+    let tag = parallel_mac::<HmacSha256>(&key(), &[b"first", b"second", b"third"]);
+    assert_eq!(
+        tag[..],
+        hex!("a68c393b5e1f2cc72338b45d3fe214f2a4f14876c63293a0523eb0a09f4e4d8f")[..],
+    );
+}
+
+#[test]
+fn parallel_mac_binds_chunks() {
+    let key = key();
+    let tag = parallel_mac::<HmacSha256>(&key, &[b"first", b"second", b"third"]);
+    assert_eq!(
+        tag,
+        parallel_mac::<HmacSha256>(&key, &[b"first", b"second", b"third"])
+    );
+
+    let others: &[&[&[u8]]] = &[
+        &[b"second", b"first", b"third"],
+        &[b"first", b"third", b"second"],
+        &[b"first", b"second"],
+        &[b"first", b"second", b"third", b""],
+        &[b"firstsecond", b"third"],
+        &[b"first", b"second", b"thirc"],
+        &[],
+    ];
+    for chunks in others {
+        assert_ne!(tag, parallel_mac::<HmacSha256>(&key, chunks));
+    }
+
+    // duplicated chunks do not cancel out
+    assert_ne!(
+        parallel_mac::<HmacSha256>(&key, &[b"a", b"a"]),
+        parallel_mac::<HmacSha256>(&key, &[]),
+    );
+
+    let other_key = Key::<HmacSha256>::default();
+    assert_ne!(
+        tag,
+        parallel_mac::<HmacSha256>(&other_key, &[b"first", b"second", b"third"])
+    );
+}
@@ -0,0 +1,61 @@
+//! Tests for the `HasherPool` type.
+#![cfg(feature = "std")]
+
+use digest::{Digest, FixedOutputReset, HasherPool};
+use sha2::Sha256;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn recycled_hashers_are_reset() {
+    let pool = HasherPool::<Sha256>::new();
+    assert_eq!(pool.idle_count(), 0);
+    {
+        let mut hasher = pool.get();
+        hasher.update(&b"garbage which must not leak into the next digest"[..]);
+    }
+    assert_eq!(pool.idle_count(), 1);
+
+    let mut hasher = pool.get();
+    assert_eq!(pool.idle_count(), 0);
+    hasher.update(b"abc");
+    assert_eq!(hasher.finalize_fixed_reset(), Sha256::digest(b"abc"));
+    drop(hasher);
+    assert_eq!(pool.idle_count(), 1);
+}
+
+#[test]
+fn multiple_guards() {
+    let pool = HasherPool::<Sha256>::default();
+    let mut a = pool.get();
+    let mut b = pool.get();
+    a.update(b"a");
+    b.update(b"b");
+    assert_eq!(a.finalize_fixed_reset(), Sha256::digest(b"a"));
+    assert_eq!(b.finalize_fixed_reset(), Sha256::digest(b"b"));
+    drop(a);
+    drop(b);
+    assert_eq!(pool.idle_count(), 2);
+}
+
+#[test]
+fn concurrent_use() {
+    let pool = Arc::new(HasherPool::<Sha256>::new());
+    let handles: Vec<_> = (0..4u8)
+        .map(|i| {
+            let pool = pool.clone();
+            thread::spawn(move || {
+                for j in 0..100u8 {
+                    let msg = [i, j];
+                    let mut hasher = pool.get();
+                    hasher.update(msg);
+                    assert_eq!(hasher.finalize_fixed_reset(), Sha256::digest(msg));
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert!(pool.idle_count() >= 1 && pool.idle_count() <= 4);
+}
@@ -0,0 +1,44 @@
+//! Tests for the `Prf` trait.
+#![cfg(feature = "mac")]
+
+use digest::{Hmac, Prf};
+use hex_literal::hex;
+use sha2::Sha256;
+
+/// TLS 1.2 PRF with SHA-256 test vector.
+#[test]
+fn tls12_prf_sha256() {
+    let secret = hex!("9bbe436ba940f017b17652849a71db35");
+    let seed = hex!("a0ba9f936cda311827a6f796ffd5198c");
+    let expected = hex!(
+        "e3f229ba727be17b8d122620557cd453c2aab21d07c3d495329b52d4e61edb5a
+         6b301791e90d35c9c9a46b4e14baf9af0fa022f7077def17abfd3797c0564bab
+         4fbc91666e9def9b97fce34f796789baa48082d122ee42c5a72e5a5110fff701
+         87347b66"
+    );
+    let mut input = b"test label".to_vec();
+    input.extend_from_slice(&seed);
+
+    let mut out = [0u8; 100];
+    Hmac::<Sha256>::prf(&secret, &input, &mut out).unwrap();
+    assert_eq!(out[..], expected[..]);
+
+    // shorter outputs are prefixes of longer ones
+    for &n in &[0, 1, 31, 32, 33, 64, 99] {
+        let mut out = vec![0u8; n];
+        Hmac::<Sha256>::prf(&secret, &input, &mut out).unwrap();
+        assert_eq!(out[..], expected[..n]);
+    }
+}
+
+#[test]
+fn prf_depends_on_key_and_input() {
+    let mut a = [0u8; 40];
+    let mut b = [0u8; 40];
+    let mut c = [0u8; 40];
+    Hmac::<Sha256>::prf(b"key", b"input", &mut a).unwrap();
+    Hmac::<Sha256>::prf(b"key2", b"input", &mut b).unwrap();
+    Hmac::<Sha256>::prf(b"key", b"input2", &mut c).unwrap();
+    assert_ne!(a[..], b[..]);
+    assert_ne!(a[..], c[..]);
+}
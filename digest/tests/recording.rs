@@ -0,0 +1,38 @@
+//! Tests for the `RecordingUpdate` adapter.
+#![cfg(feature = "alloc")]
+
+use digest::{Digest, FixedOutput, FixedOutputReset, RecordingUpdate, Reset, Update};
+use sha2::Sha256;
+
+#[test]
+fn records_fed_bytes() {
+    let mut hasher = RecordingUpdate::new(Sha256::new());
+    assert!(hasher.recorded().is_empty());
+    let chunks: &[&[u8]] = &[b"", b"a", b"bc", &[0u8; 100], &[1u8; 1000]];
+    let mut expected = Vec::new();
+    for chunk in chunks {
+        hasher.update(chunk);
+        expected.extend_from_slice(chunk);
+        assert_eq!(hasher.recorded(), &expected[..]);
+    }
+    assert_eq!(hasher.recorded().len(), 1103);
+
+    let (inner, recorded) = hasher.clone().into_parts();
+    assert_eq!(recorded, expected);
+    assert_eq!(inner.finalize(), Sha256::digest(&expected));
+    assert_eq!(hasher.finalize_fixed(), Sha256::digest(&expected));
+}
+
+#[test]
+fn reset_clears_recording() {
+    let mut hasher = RecordingUpdate::<Sha256>::default();
+    hasher.update(b"some data");
+    assert_eq!(hasher.recorded(), b"some data");
+    hasher.reset();
+    assert!(hasher.recorded().is_empty());
+
+    hasher.update(b"abc");
+    assert_eq!(hasher.finalize_fixed_reset(), Sha256::digest(b"abc"));
+    assert!(hasher.recorded().is_empty());
+    assert_eq!(hasher.get_ref().clone().finalize(), Sha256::digest(b""));
+}
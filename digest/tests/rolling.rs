@@ -0,0 +1,40 @@
+//! Tests for the rolling hashes.
+use digest::rolling::{Adler32, RollingHash};
+
+fn test_data(len: usize) -> Vec<u8> {
+    let mut x = 0x1234_5678u32;
+    (0..len)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            x as u8
+        })
+        .collect()
+}
+
+#[test]
+fn adler32_known_values() {
+    assert_eq!(Adler32::new().value(), 1);
+    assert_eq!(Adler32::from_window(b"Wikipedia").value(), 0x11E6_0398);
+}
+
+#[test]
+fn roll_matches_recomputation() {
+    let mut data = test_data(10_000);
+    // long run of maximal bytes exercises modular reduction
+    data[100..6000].iter_mut().for_each(|b| *b = 0xFF);
+    for &window in &[1, 16, 1000, 65_520, 65_521, 65_600] {
+        if window >= data.len() {
+            data.extend(test_data(window - data.len() + 1000));
+        }
+        let mut hash = Adler32::from_window(&data[..window]);
+        for i in 0..data.len() - window {
+            hash.roll(data[i], data[i + window]);
+            if i % 97 == 0 || i + 1 == data.len() - window {
+                let expected = Adler32::from_window(&data[i + 1..i + 1 + window]);
+                assert_eq!(hash.value(), expected.value(), "window {}, i {}", window, i);
+            }
+        }
+    }
+}
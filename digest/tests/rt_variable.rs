@@ -0,0 +1,109 @@
+//! Tests for the `RtVariableCoreWrapper` type.
+#![cfg(feature = "core-api")]
+
+mod common;
+
+use common::mix;
+use digest::{
+    consts::{U32, U8},
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, OutputSizeUser, RtVariableCoreWrapper,
+        TruncSide, UpdateCore, VariableOutputCore,
+    },
+    HashMarker, InvalidOutputSize, Output, Reset, Update, VariableOutput, VariableOutputReset,
+};
+
+/// Toy core which mixes output size into its initial state similarly to
+/// BLAKE2. NOT cryptographically secure.
+#[derive(Clone)]
+struct ToyVarCore {
+    init: u64,
+    state: u64,
+}
+
+impl HashMarker for ToyVarCore {}
+
+impl BlockSizeUser for ToyVarCore {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for ToyVarCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl OutputSizeUser for ToyVarCore {
+    type OutputSize = U32;
+}
+
+impl UpdateCore for ToyVarCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(block);
+            self.state = mix(self.state ^ u64::from_le_bytes(b));
+        }
+    }
+}
+
+impl VariableOutputCore for ToyVarCore {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size == 0 || output_size > 32 {
+            return Err(InvalidOutputSize);
+        }
+        let init = mix(output_size as u64);
+        Ok(Self { init, state: init })
+    }
+
+    fn finalize_variable_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let pos = buffer.get_pos() as u8;
+        let block = buffer.pad_with_zeros();
+        block[7] = pos;
+        self.update_blocks(core::slice::from_ref(block));
+        for (i, chunk) in out.chunks_mut(8).enumerate() {
+            chunk.copy_from_slice(&mix(self.state ^ i as u64).to_le_bytes());
+        }
+    }
+}
+
+impl Reset for ToyVarCore {
+    fn reset(&mut self) {
+        self.state = self.init;
+    }
+}
+
+type ToyVar = RtVariableCoreWrapper<ToyVarCore>;
+
+fn hash(output_size: usize, data: &[u8]) -> Vec<u8> {
+    let mut hasher = ToyVar::new(output_size).unwrap();
+    hasher.update(data);
+    let mut out = vec![0u8; output_size];
+    hasher.finalize_variable(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn output_size_affects_result() {
+    assert_ne!(hash(20, b"abc")[..16], hash(16, b"abc")[..]);
+    assert_ne!(hash(20, b"abc"), hash(20, b"abd"));
+}
+
+#[test]
+fn reset_preserves_output_size() {
+    let mut hasher = ToyVar::new(20).unwrap();
+    hasher.update(b"some data which spans several blocks");
+    hasher.reset();
+    assert_eq!(hasher.output_size(), 20);
+    hasher.update(b"abc");
+    let mut out = [0u8; 20];
+    hasher.clone().finalize_variable(&mut out).unwrap();
+    assert_eq!(out[..], hash(20, b"abc")[..]);
+
+    hasher.finalize_variable_reset(&mut out).unwrap();
+    assert_eq!(out[..], hash(20, b"abc")[..]);
+    assert_eq!(hasher.output_size(), 20);
+    hasher.update(b"message");
+    hasher.finalize_variable_reset(&mut out).unwrap();
+    assert_eq!(out[..], hash(20, b"message")[..]);
+}
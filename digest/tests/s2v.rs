@@ -0,0 +1,152 @@
+//! Tests for the S2V function.
+#![cfg(feature = "mac")]
+
+use aes::{Aes128, BlockCipher, NewBlockCipher};
+use digest::{
+    consts::U16, crypto_common::KeySizeUser, generic_array::GenericArray, s2v, FixedOutput, Key,
+    KeyInit, MacMarker, Output, OutputSizeUser, Update,
+};
+use hex_literal::hex;
+
+/// Minimal AES-128-CMAC ([RFC 4493]) implementation used for testing.
+///
+/// [RFC 4493]: https://tools.ietf.org/html/rfc4493
+#[derive(Clone)]
+struct Cmac {
+    cipher: Aes128,
+    data: Vec<u8>,
+}
+
+fn dbl(block: &mut [u8; 16]) {
+    let v = u128::from_be_bytes(*block);
+    let v = (v << 1) ^ if v >> 127 == 1 { 0x87 } else { 0 };
+    *block = v.to_be_bytes();
+}
+
+impl MacMarker for Cmac {}
+
+impl KeySizeUser for Cmac {
+    type KeySize = U16;
+}
+
+impl OutputSizeUser for Cmac {
+    type OutputSize = U16;
+}
+
+impl KeyInit for Cmac {
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            cipher: Aes128::new(key),
+            data: Vec::new(),
+        }
+    }
+}
+
+impl Update for Cmac {
+    fn update(&mut self, data: &[u8]) {
+        self.data.extend_from_slice(data);
+    }
+}
+
+impl FixedOutput for Cmac {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let mut subkey = [0u8; 16];
+        self.cipher
+            .encrypt_block(GenericArray::from_mut_slice(&mut subkey));
+        dbl(&mut subkey);
+
+        let mut data = self.data;
+        if data.is_empty() || data.len() & 15 != 0 {
+            data.push(0x80);
+            while data.len() & 15 != 0 {
+                data.push(0);
+            }
+            dbl(&mut subkey);
+        }
+        let last = data.len() - 16;
+        for (b, k) in data[last..].iter_mut().zip(subkey.iter()) {
+            *b ^= k;
+        }
+
+        let mut state = GenericArray::default();
+        for block in data.chunks(16) {
+            for (s, b) in state.iter_mut().zip(block) {
+                *s ^= b;
+            }
+            self.cipher.encrypt_block(&mut state);
+        }
+        *out = state;
+    }
+}
+
+#[test]
+fn cmac_rfc4493() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let cmac = |data: &[u8]| {
+        let mut mac = <Cmac as KeyInit>::new(&key.into());
+        mac.update(data);
+        mac.finalize_fixed()
+    };
+    assert_eq!(cmac(b"")[..], hex!("bb1d6929e95937287fa37d129b756746"));
+    assert_eq!(
+        cmac(&hex!("6bc1bee22e409f96e93d7e117393172a"))[..],
+        hex!("070a16b46b4d4144f79bdd9dd04a287c"),
+    );
+}
+
+/// RFC 5297 Appendix A.1: Deterministic Authenticated Encryption Example
+#[test]
+fn s2v_rfc5297_deterministic() {
+    let key = hex!("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0");
+    let ad = hex!("101112131415161718191a1b1c1d1e1f2021222324252627");
+    let plaintext = hex!("112233445566778899aabbccddee");
+    let v = s2v::<Cmac>(&key.into(), &[&ad, &plaintext]);
+    assert_eq!(v[..], hex!("85632d07c6e8f37f950acd320a2ecc93"));
+}
+
+/// RFC 5297 Appendix A.2: Nonce-Based Authenticated Encryption Example
+#[test]
+fn s2v_rfc5297_nonce_based() {
+    let key = hex!("7f7e7d7c7b7a79787776757473727170");
+    let ad1 = hex!(
+        "00112233445566778899aabbccddeeff
+         deaddadadeaddadaffeeddccbbaa9988
+         7766554433221100"
+    );
+    let ad2 = hex!("102030405060708090a0");
+    let nonce = hex!("09f911029d74e35bd84156c5635688c0");
+    let plaintext = hex!(
+        "7468697320697320736f6d6520706c61
+         696e7465787420746f20656e63727970
+         74207573696e67205349562d414553"
+    );
+    let v = s2v::<Cmac>(&key.into(), &[&ad1, &ad2, &nonce, &plaintext]);
+    assert_eq!(v[..], hex!("7bdb6e3b432667eb06f4d14bff2fbd0f"));
+}
+
+#[test]
+fn s2v_empty() {
+    let key = hex!("7f7e7d7c7b7a79787776757473727170");
+    let v = s2v::<Cmac>(&key.into(), &[]);
+    assert_eq!(v[..], hex!("4987d8dfc37c9ceda72c5244918a3a32"));
+}
+
+/// S2V instantiated with HMAC-SHA-256, i.e. with 256-bit blocks.
+#[test]
+fn s2v_hmac_sha256() {
+    let mut key = Key::<digest::Hmac<sha2::Sha256>>::default();
+    key.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+    let v = s2v::<digest::Hmac<sha2::Sha256>>(&key, &[b"header", b"nonce", b"short"]);
+    assert_eq!(
+        v[..],
+        hex!("c951cf3d8bdc54433082915e078cf70fc74adc9d5f05492d56a62ac0b8dbe244")
+    );
+    let v = s2v::<digest::Hmac<sha2::Sha256>>(
+        &key,
+        &[b"header", b"a message which is longer than 32 bytes"],
+    );
+    assert_eq!(
+        v[..],
+        hex!("2d9090613530de91469a7ea82d701a3d908d051fc34a575431ed6ed41ca3101c")
+    );
+}
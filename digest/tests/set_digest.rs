@@ -0,0 +1,55 @@
+//! Tests for order-independent set digests.
+use digest::{set_digest, Digest, Output};
+use hex_literal::hex;
+use sha2::Sha256;
+
+const TAGS: &[&str] = &["red", "green", "blue", "yellow", ""];
+
+#[test]
+fn known_answer() {
+    let res = set_digest::<Sha256, _>(&["red", "green", "blue"]);
+    assert_eq!(
+        res[..],
+        hex!("8284198c06a83529e237a7f39ecb3bd6d49e80603fe299eda057d809a53e5d2a")[..]
+    );
+}
+
+#[test]
+fn order_independence() {
+    let expected = set_digest::<Sha256, _>(TAGS);
+    let mut items = TAGS.to_vec();
+    for i in 0..items.len() {
+        items.rotate_left(1);
+        assert_eq!(set_digest::<Sha256, _>(&items), expected);
+        items.swap(0, i);
+        assert_eq!(set_digest::<Sha256, _>(&items), expected);
+    }
+    items.reverse();
+    assert_eq!(set_digest::<Sha256, _>(items.iter()), expected);
+}
+
+#[test]
+fn element_changes() {
+    let expected = set_digest::<Sha256, _>(TAGS);
+    assert_ne!(set_digest::<Sha256, _>(&TAGS[1..]), expected);
+    assert_ne!(
+        set_digest::<Sha256, _>(&["red", "green", "blue", "yellow", "black"]),
+        expected
+    );
+    // multiset semantics: duplicates are counted
+    assert_ne!(
+        set_digest::<Sha256, _>(&["red", "red"]),
+        set_digest::<Sha256, _>(&["red"])
+    );
+    assert_ne!(
+        set_digest::<Sha256, _>(&["red", "red"]),
+        Output::<Sha256>::default()
+    );
+}
+
+#[test]
+fn single_and_empty() {
+    assert_eq!(set_digest::<Sha256, _>(&["red"]), Sha256::digest(b"red"));
+    let empty: [&[u8]; 0] = [];
+    assert_eq!(set_digest::<Sha256, _>(&empty), Output::<Sha256>::default());
+}
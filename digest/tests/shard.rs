@@ -0,0 +1,61 @@
+//! Tests for content-addressed path derivation.
+#![cfg(feature = "alloc")]
+
+use digest::shard_path;
+use sha2::{Digest, Sha256, Sha512};
+
+#[test]
+fn shard_levels() {
+    let hash = Sha256::digest(b"hello world");
+    let hex = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+    let vectors: &[(&[usize], &str)] = &[
+        (&[], hex),
+        (
+            &[1],
+            "b9/4d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+        ),
+        (
+            &[1, 1],
+            "b9/4d/27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+        ),
+        (
+            &[2, 1],
+            "b94d/27/b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+        ),
+        (
+            &[31],
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcd/e9",
+        ),
+    ];
+    for (levels, expected) in vectors {
+        let path = shard_path::<Sha256>(&hash, levels);
+        assert_eq!(&path, expected);
+        assert_eq!(path.replace('/', ""), hex);
+    }
+}
+
+#[test]
+fn reassemble() {
+    let hash = Sha512::digest(b"abc");
+    let path = shard_path::<Sha512>(&hash, &[1, 2, 3]);
+    let parts: Vec<&str> = path.split('/').collect();
+    let lens: Vec<usize> = parts.iter().map(|p| p.len()).collect();
+    assert_eq!(lens, [2, 4, 6, 116]);
+
+    let bytes: Vec<u8> = (0..hash.len())
+        .map(|i| u8::from_str_radix(&path.replace('/', "")[2 * i..2 * i + 2], 16).unwrap())
+        .collect();
+    assert_eq!(bytes, &hash[..]);
+}
+
+#[test]
+#[should_panic]
+fn whole_hash_prefix() {
+    shard_path::<Sha256>(&Sha256::digest(b""), &[16, 16]);
+}
+
+#[test]
+#[should_panic]
+fn empty_level() {
+    shard_path::<Sha256>(&Sha256::digest(b""), &[1, 0]);
+}
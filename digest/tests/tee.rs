@@ -0,0 +1,53 @@
+//! Tests for the `TeeHash` type.
+#![cfg(feature = "std")]
+
+use digest::{Digest, TeeHash};
+use sha2::Sha256;
+use std::io::{self, Write};
+
+#[test]
+fn tee_hash() {
+    let data: Vec<u8> = (0..10_000u32)
+        .map(|i| (i.wrapping_mul(31) >> 3) as u8)
+        .collect();
+    let mut tee = TeeHash::new(Vec::new(), Sha256::new());
+    for chunk in data.chunks(777) {
+        tee.write_all(chunk).unwrap();
+    }
+    tee.flush().unwrap();
+    let (written, hasher) = tee.into_parts();
+    assert_eq!(written, data);
+    assert_eq!(hasher.finalize(), Sha256::digest(&data));
+
+    let mut tee = TeeHash::new(io::sink(), Sha256::new());
+    io::copy(&mut &data[..], &mut tee).unwrap();
+    assert_eq!(tee.into_hasher().finalize(), Sha256::digest(&data));
+}
+
+/// Writer which accepts at most 3 bytes per call.
+struct ShortWriter(Vec<u8>);
+
+impl Write for ShortWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = buf.len().min(3);
+        self.0.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn short_writes() {
+    let mut tee = TeeHash::new(ShortWriter(Vec::new()), Sha256::new());
+    assert_eq!(tee.write(b"abcdef").unwrap(), 3);
+    assert_eq!(tee.get_ref().0, b"abc");
+    assert_eq!(tee.hasher().clone().finalize(), Sha256::digest(b"abc"));
+
+    tee.write_all(b"def").unwrap();
+    let (written, hasher) = tee.into_parts();
+    assert_eq!(written.0, b"abcdef");
+    assert_eq!(hasher.finalize(), Sha256::digest(b"abcdef"));
+}
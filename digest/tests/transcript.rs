@@ -0,0 +1,96 @@
+//! Tests for the `XofTranscript` type.
+
+mod common;
+
+use common::ToyXof;
+use digest::{ExtendableOutput, Transcript, Update, XofReader, XofTranscript};
+
+fn challenge(ops: &[(&[u8], &[u8])]) -> [u8; 32] {
+    let mut t = XofTranscript::<ToyXof>::new(b"test protocol");
+    for (label, data) in ops {
+        t.absorb(label, data);
+    }
+    let mut out = [0u8; 32];
+    t.challenge(b"c", &mut out);
+    out
+}
+
+#[test]
+fn deterministic() {
+    let ops: &[(&[u8], &[u8])] = &[(b"a", b"first"), (b"b", b"second")];
+    assert_eq!(challenge(ops), challenge(ops));
+
+    let mut t1 = XofTranscript::<ToyXof>::new(b"test protocol");
+    let mut t2 = t1.clone();
+    let (mut c1, mut c2) = ([0u8; 50], [0u8; 50]);
+    t1.challenge(b"c", &mut c1);
+    t2.challenge(b"c", &mut c2);
+    assert_eq!(c1[..], c2[..]);
+}
+
+#[test]
+fn transcript_layout() {
+    let mut xof = ToyXof::default();
+    for (op, label, data) in &[
+        (1u8, &b"domain"[..], &b"test protocol"[..]),
+        (1, b"a", b"msg"),
+        (2, b"c", &16u64.to_le_bytes()),
+    ] {
+        xof.update(&[*op]);
+        xof.update(&(label.len() as u64).to_le_bytes());
+        xof.update(label);
+        xof.update(&(data.len() as u64).to_le_bytes());
+        xof.update(data);
+    }
+    let mut expected = [0u8; 16];
+    xof.finalize_xof().read(&mut expected);
+
+    let mut t = XofTranscript::<ToyXof>::new(b"test protocol");
+    t.absorb(b"a", b"msg");
+    let mut out = [0u8; 16];
+    t.challenge(b"c", &mut out);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn changes_alter_challenges() {
+    let base = challenge(&[(b"a", b"first"), (b"b", b"second")]);
+    let variants: &[&[(&[u8], &[u8])]] = &[
+        &[(b"b", b"second"), (b"a", b"first")],
+        &[(b"a", b"first"), (b"c", b"second")],
+        &[(b"a", b"first"), (b"b", b"second!")],
+        &[(b"a", b"firsts"), (b"b", b"econd")],
+        &[(b"af", b"irst"), (b"b", b"second")],
+        &[(b"a", b"first"), (b"b", b"second"), (b"", b"")],
+        &[(b"a", b"first")],
+    ];
+    for ops in variants {
+        assert_ne!(challenge(ops), base);
+    }
+
+    let mut other = XofTranscript::<ToyXof>::new(b"other protocol");
+    other.absorb(b"a", b"first");
+    other.absorb(b"b", b"second");
+    let mut out = [0u8; 32];
+    other.challenge(b"c", &mut out);
+    assert_ne!(out, base);
+}
+
+#[test]
+fn challenges_are_bound() {
+    let mut t = XofTranscript::<ToyXof>::new(b"test protocol");
+    let (mut c1, mut c2, mut c3) = ([0u8; 32], [0u8; 32], [0u8; 32]);
+    t.challenge(b"c", &mut c1);
+    t.challenge(b"c", &mut c2);
+    assert_ne!(c1, c2);
+
+    // challenge length is bound into the transcript
+    let mut t = XofTranscript::<ToyXof>::new(b"test protocol");
+    let mut short = [0u8; 16];
+    t.challenge(b"c", &mut short);
+    assert_ne!(short[..], c1[..16]);
+
+    let mut t = XofTranscript::<ToyXof>::new(b"test protocol");
+    t.challenge(b"d", &mut c3);
+    assert_ne!(c1, c3);
+}
@@ -0,0 +1,129 @@
+//! TupleHash tests using sample vectors from NIST.
+#![cfg(feature = "core-api")]
+
+mod keccak;
+
+use digest::{
+    tuple_hash::{tuple_hash, TupleHash},
+    XofReader,
+};
+use hex_literal::hex;
+use keccak::{CShake128, CShake256};
+
+type TupleHash128 = TupleHash<CShake128>;
+type TupleHash256 = TupleHash<CShake256>;
+
+/// Tuple elements, customization string and expected result.
+type Vector<T> = (&'static [&'static [u8]], &'static [u8], T);
+
+const S: &[u8] = b"My Tuple App";
+
+const E1: &[u8] = &hex!("000102");
+const E2: &[u8] = &hex!("101112131415");
+const E3: &[u8] = &hex!("202122232425262728");
+
+#[test]
+fn tuple_hash128_samples() {
+    let vectors: &[Vector<[u8; 32]>] = &[
+        (
+            &[E1, E2],
+            b"",
+            hex!("c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1"),
+        ),
+        (
+            &[E1, E2],
+            S,
+            hex!("75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb"),
+        ),
+        (
+            &[E1, E2, E3],
+            S,
+            hex!("e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84"),
+        ),
+    ];
+    for (elements, s, expected) in vectors {
+        let mut hasher = TupleHash128::new(s);
+        for e in elements.iter() {
+            hasher.push(e);
+        }
+        let mut out = [0u8; 32];
+        hasher.finalize_into(&mut out);
+        assert_eq!(&out, expected);
+
+        let mut out = [0u8; 32];
+        tuple_hash::<CShake128>(elements, s, &mut out);
+        assert_eq!(&out, expected);
+    }
+}
+
+#[test]
+fn tuple_hash256_samples() {
+    let vectors: &[Vector<[u8; 64]>] = &[
+        (
+            &[E1, E2],
+            b"",
+            hex!(
+                "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec6073
+                11ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194"
+            ),
+        ),
+        (
+            &[E1, E2],
+            S,
+            hex!(
+                "147c2191d5ed7efd98dbd96d7ab5a11692576f5fe2a5065f3e33de6bba9f3aa1
+                c4e9a068a289c61c95aab30aee1e410b0b607de3620e24a4e3bf9852a1d4367e"
+            ),
+        ),
+        (
+            &[E1, E2, E3],
+            S,
+            hex!(
+                "45000be63f9b6bfd89f54717670f69a9bc763591a4f05c50d68891a744bcc6e7
+                d6d5b5e82c018da999ed35b0bb49c9678e526abd8e85c13ed254021db9e790ce"
+            ),
+        ),
+    ];
+    for (elements, s, expected) in vectors {
+        let mut out = [0u8; 64];
+        tuple_hash::<CShake256>(elements, s, &mut out);
+        assert_eq!(&out[..], &expected[..]);
+    }
+}
+
+#[test]
+fn tuple_hash_xof_samples() {
+    let mut reader = TupleHash128::new(S).chain(E1).chain(E2).finalize_xof();
+    let mut out = [0u8; 32];
+    reader.read(&mut out);
+    assert_eq!(
+        out,
+        hex!("3fc8ad69453128292859a18b6c67d7ad85f01b32815e22ce839c49ec374e9b9a"),
+    );
+
+    let mut reader = TupleHash256::new(S)
+        .chain(E1)
+        .chain(E2)
+        .chain(E3)
+        .finalize_xof();
+    let mut out = [0u8; 64];
+    reader.read(&mut out);
+    assert_eq!(
+        &out[..],
+        &hex!(
+            "0c59b11464f2336c34663ed51b2b950bec743610856f36c28d1d088d8a244628
+            4dd09830a6a178dc752376199fae935d86cfdee5913d4922dfd369b66a53c897"
+        )[..],
+    );
+}
+
+#[test]
+fn element_boundaries() {
+    let mut a = [0u8; 32];
+    let mut b = [0u8; 32];
+    tuple_hash::<CShake128>(&[b"ab", b"c"], b"", &mut a);
+    tuple_hash::<CShake128>(&[b"a", b"bc"], b"", &mut b);
+    assert_ne!(a, b);
+    tuple_hash::<CShake128>(&[b"abc"], b"", &mut b);
+    assert_ne!(a, b);
+}
@@ -0,0 +1,207 @@
+//! Tests for the provided methods of the `Update` trait.
+use digest::{Digest, Update};
+use sha2::Sha256;
+
+const HEADERS: &[&[u8]] = &[
+    b"Host: example.com\r\n",
+    b"",
+    b"Accept: */*\r\n",
+    &[0u8; 200],
+    b"\r\n",
+];
+
+#[test]
+fn update_all_matches_concatenation() {
+    let mut hasher = Sha256::default();
+    hasher.update_all(HEADERS);
+    assert_eq!(hasher.finalize(), Sha256::digest(HEADERS.concat()));
+}
+
+#[test]
+fn update_all_matches_individual_updates() {
+    let mut expected = Sha256::default();
+    Update::update(&mut expected, b"prefix");
+    for header in HEADERS {
+        Update::update(&mut expected, header);
+    }
+    let mut hasher = Sha256::default();
+    Update::update(&mut hasher, b"prefix");
+    hasher.update_all(&HEADERS[..2]);
+    hasher.update_all(&HEADERS[2..]);
+    assert_eq!(hasher.finalize(), expected.finalize());
+}
+
+#[test]
+fn update_all_empty() {
+    let mut hasher = Sha256::default();
+    hasher.update_all(&[]);
+    assert_eq!(hasher.finalize(), Sha256::digest(b""));
+}
+
+#[test]
+fn try_update_iter_all_ok() {
+    let mut hasher = Sha256::default();
+    let items = HEADERS.iter().map(Ok::<_, ()>);
+    assert_eq!(hasher.try_update_iter(items), Ok(()));
+    assert_eq!(hasher.finalize(), Sha256::digest(HEADERS.concat()));
+
+    let mut hasher = Sha256::default();
+    let owned = vec![Ok::<_, ()>(vec![1u8, 2]), Ok(vec![3])];
+    assert_eq!(hasher.try_update_iter(owned), Ok(()));
+    assert_eq!(hasher.finalize(), Sha256::digest([1, 2, 3]));
+}
+
+#[test]
+fn try_update_iter_error() {
+    let mut consumed = 0;
+    let items = HEADERS.iter().enumerate().map(|(i, h)| {
+        consumed += 1;
+        if i == 2 {
+            Err(i)
+        } else {
+            Ok(h)
+        }
+    });
+    let mut hasher = Sha256::default();
+    assert_eq!(hasher.try_update_iter(items), Err(2));
+    assert_eq!(consumed, 3);
+    // state contains only items preceding the error
+    assert_eq!(hasher.finalize(), Sha256::digest(HEADERS[..2].concat()));
+}
+
+#[test]
+fn update_integers() {
+    let mut hasher = Sha256::default();
+    hasher.update_u8(0xab);
+    hasher.update_u16_be(0x0102);
+    hasher.update_u16_le(0x0102);
+    hasher.update_u32_be(0x0304_0506);
+    hasher.update_u32_le(0x0304_0506);
+    hasher.update_u64_be(0x0708_090a_0b0c_0d0e);
+    hasher.update_u64_le(0x0708_090a_0b0c_0d0e);
+
+    let mut expected = Sha256::default();
+    Update::update(&mut expected, &[0xab]);
+    Update::update(&mut expected, &0x0102u16.to_be_bytes());
+    Update::update(&mut expected, &0x0102u16.to_le_bytes());
+    Update::update(&mut expected, &0x0304_0506u32.to_be_bytes());
+    Update::update(&mut expected, &0x0304_0506u32.to_le_bytes());
+    Update::update(&mut expected, &0x0708_090a_0b0c_0d0eu64.to_be_bytes());
+    Update::update(&mut expected, &0x0708_090a_0b0c_0d0eu64.to_le_bytes());
+    assert_eq!(hasher.finalize(), expected.finalize());
+
+    let bytes: &[u8] = &[
+        0xab, 0x01, 0x02, 0x02, 0x01, 0x03, 0x04, 0x05, 0x06, 0x06, 0x05, 0x04, 0x03, 0x07, 0x08,
+        0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07,
+    ];
+    let mut hasher = Sha256::default();
+    hasher.update_u8(0xab);
+    hasher.update_u16_be(0x0102);
+    hasher.update_u16_le(0x0102);
+    hasher.update_u32_be(0x0304_0506);
+    hasher.update_u32_le(0x0304_0506);
+    hasher.update_u64_be(0x0708_090a_0b0c_0d0e);
+    hasher.update_u64_le(0x0708_090a_0b0c_0d0e);
+    assert_eq!(hasher.finalize(), Sha256::digest(bytes));
+}
+
+#[test]
+fn update_with_len() {
+    let mut hasher = Sha256::default();
+    hasher.update_with_len(b"abc");
+    hasher.update_with_len(b"");
+
+    let mut expected = 3u64.to_le_bytes().to_vec();
+    expected.extend_from_slice(b"abc");
+    expected.extend_from_slice(&0u64.to_le_bytes());
+    assert_eq!(hasher.finalize(), Sha256::digest(&expected));
+}
+
+#[test]
+fn update_bool() {
+    let mut hasher = Sha256::default();
+    hasher.update_bool(true);
+    hasher.update_bool(false);
+    hasher.update_u8(0x02);
+
+    let mut expected = Sha256::default();
+    Update::update(&mut expected, &[1]);
+    Update::update(&mut expected, &[0]);
+    Update::update(&mut expected, &[2]);
+    assert_eq!(hasher.finalize(), expected.finalize());
+}
+
+#[test]
+fn update_hex() {
+    let data: Vec<u8> = (0..1000u32).map(|i| (i * 31) as u8).collect();
+    let hex: String = data.iter().map(|b| format!("{:02x}", b)).collect();
+
+    let mut hasher = Sha256::default();
+    hasher.update_hex(&hex).unwrap();
+    hasher.update_hex(&hex[..20].to_uppercase()).unwrap();
+    hasher.update_hex("").unwrap();
+    let mut expected = data.clone();
+    expected.extend_from_slice(&data[..10]);
+    assert_eq!(hasher.finalize(), Sha256::digest(&expected));
+
+    let mut hasher = Sha256::default();
+    assert!(hasher.update_hex("abc").is_err());
+    // invalid character after several valid chunks
+    let invalid = format!("{}zz", &hex[..400]);
+    assert_eq!(hasher.update_hex(&invalid), Err(digest::DecodeError));
+    assert_eq!(hasher.finalize(), Sha256::digest(b""));
+}
+
+#[test]
+fn update_base64() {
+    // "Many hands make light work." from RFC 4648 examples
+    let mut hasher = Sha256::default();
+    hasher
+        .update_base64("TWFueSBoYW5kcyBtYWtlIGxpZ2h0IHdvcmsu")
+        .unwrap();
+    hasher.update_base64("Zm9vYg==").unwrap();
+    hasher.update_base64("Zm9vYmE").unwrap();
+    hasher.update_base64("").unwrap();
+    assert_eq!(
+        hasher.finalize(),
+        Sha256::digest(&b"Many hands make light work.foobfooba"[..])
+    );
+
+    let data: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+    let encoded = base64(&data);
+    let mut hasher = Sha256::default();
+    hasher.update_base64(&encoded).unwrap();
+    assert_eq!(hasher.finalize(), Sha256::digest(&data));
+
+    let mut hasher = Sha256::default();
+    let invalid = &[
+        "Zm9vYg=",
+        "Zm9vYh==",
+        "Zm9v\nYg==",
+        "Zm9vY",
+        "Zm9-YmE",
+        &format!("{}!", &encoded[..800]),
+    ];
+    for s in invalid {
+        assert!(hasher.update_base64(s).is_err(), "{}", s);
+    }
+    assert_eq!(hasher.finalize(), Sha256::digest(b""));
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut res = String::new();
+    for chunk in data.chunks(3) {
+        let mut buf = [0u8; 3];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let n = (u32::from(buf[0]) << 16) | (u32::from(buf[1]) << 8) | u32::from(buf[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                res.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                res.push('=');
+            }
+        }
+    }
+    res
+}
@@ -0,0 +1,109 @@
+//! Tests for the `UpdateCore` trait.
+#![cfg(feature = "core-api")]
+
+use digest::{
+    consts::U8,
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore, OutputSizeUser,
+        UpdateCore,
+    },
+    Digest, HashMarker, Output,
+};
+
+/// Toy core which processes two blocks at once in its bulk path.
+#[derive(Clone, Default)]
+struct ToyCore {
+    state: u64,
+    bulk_calls: usize,
+    bulk_blocks: usize,
+}
+
+fn compress(state: u64, block: &Block<ToyCore>) -> u64 {
+    let mut b = [0u8; 8];
+    b.copy_from_slice(block);
+    (state ^ u64::from_le_bytes(b))
+        .wrapping_mul(0x9e37_79b9_7f4a_7c15)
+        .rotate_left(29)
+}
+
+impl HashMarker for ToyCore {}
+
+impl BlockSizeUser for ToyCore {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for ToyCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl OutputSizeUser for ToyCore {
+    type OutputSize = U8;
+}
+
+impl UpdateCore for ToyCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            self.state = compress(self.state, block);
+        }
+    }
+
+    fn update_blocks_par(&mut self, blocks: &[Block<Self>]) {
+        self.bulk_calls += 1;
+        self.bulk_blocks += blocks.len();
+        let mut pairs = blocks.chunks_exact(2);
+        for pair in &mut pairs {
+            // "two-lane" path
+            let s = compress(self.state, &pair[0]);
+            self.state = compress(s, &pair[1]);
+        }
+        self.update_blocks(pairs.remainder());
+    }
+}
+
+impl FixedOutputCore for ToyCore {
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let pos = buffer.get_pos() as u8;
+        let block = buffer.pad_with_zeros();
+        block[7] = pos;
+        let block = *block;
+        self.update_blocks(core::slice::from_ref(&block));
+        out.copy_from_slice(&self.state.to_le_bytes());
+    }
+}
+
+#[test]
+fn bulk_and_single_paths_agree() {
+    for n in 0..40 {
+        let blocks: Vec<Block<ToyCore>> = (0..n).map(|i| [i as u8; 8].into()).collect();
+
+        let mut single = ToyCore::default();
+        single.update_blocks(&blocks);
+        let mut bulk = ToyCore::default();
+        bulk.update_blocks_par(&blocks);
+        assert_eq!(single.state, bulk.state);
+    }
+}
+
+#[test]
+fn wrapper_uses_bulk_path() {
+    let data: Vec<u8> = (0..100u8).collect();
+    let mut hasher = CoreWrapper::<ToyCore>::default();
+    hasher.update(&data[..3]);
+    hasher.update(&data[3..]);
+    let (core, _) = hasher.clone().decompose();
+    assert_eq!(core.bulk_calls, 2);
+    assert_eq!(core.bulk_blocks, 12);
+
+    let mut expected = ToyCore::default();
+    for block in data.chunks_exact(8) {
+        expected.update_blocks(&[Block::<ToyCore>::clone_from_slice(block)]);
+    }
+    assert_eq!(core.state, expected.state);
+
+    // chunking of input does not affect the result
+    let mut byte_by_byte = CoreWrapper::<ToyCore>::default();
+    for b in data.chunks(1) {
+        byte_by_byte.update(b);
+    }
+    assert_eq!(byte_by_byte.finalize(), hasher.finalize());
+}
@@ -0,0 +1,188 @@
+//! Tests for the `VariableOutput` trait.
+use digest::{InvalidBufferSize, InvalidOutputSize, ParamsInit, Update, VariableOutput};
+
+/// Stub hasher supporting output sizes in the `1..=16` range.
+struct Stub(usize);
+
+impl Update for Stub {
+    fn update(&mut self, _data: &[u8]) {}
+}
+
+impl VariableOutput for Stub {
+    const MAX_OUTPUT_SIZE: usize = 16;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size == 0 || output_size > Self::MAX_OUTPUT_SIZE {
+            return Err(InvalidOutputSize);
+        }
+        Ok(Self(output_size))
+    }
+
+    fn output_size(&self) -> usize {
+        self.0
+    }
+
+    fn finalize_variable(self, out: &mut [u8]) -> Result<(), InvalidBufferSize> {
+        if out.len() != self.0 {
+            return Err(InvalidBufferSize);
+        }
+        out.iter_mut().for_each(|b| *b = 0);
+        Ok(())
+    }
+}
+
+#[test]
+fn new_clamped_within_range() {
+    assert_eq!(Stub::new_clamped(1).output_size(), 1);
+    assert_eq!(Stub::new_clamped(10).output_size(), 10);
+    assert_eq!(Stub::new_clamped(16).output_size(), 16);
+}
+
+#[test]
+fn new_clamped_beyond_max() {
+    assert!(Stub::new(17).is_err());
+    assert_eq!(Stub::new_clamped(17).output_size(), 16);
+    assert_eq!(Stub::new_clamped(core::usize::MAX).output_size(), 16);
+}
+
+#[test]
+fn new_clamped_zero() {
+    assert_eq!(Stub::new_clamped(0).output_size(), 1);
+}
+
+/// Stub parametrized hasher. NOT cryptographically secure.
+struct ParamStub {
+    state: u64,
+    size: usize,
+}
+
+impl Update for ParamStub {
+    fn update(&mut self, data: &[u8]) {
+        for &b in data {
+            self.state = (self.state ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+impl VariableOutput for ParamStub {
+    const MAX_OUTPUT_SIZE: usize = 8;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        Self::new_with_params(output_size, &[], &[])
+    }
+
+    fn output_size(&self) -> usize {
+        self.size
+    }
+
+    fn finalize_variable(self, out: &mut [u8]) -> Result<(), InvalidBufferSize> {
+        if out.len() != self.size {
+            return Err(InvalidBufferSize);
+        }
+        out.copy_from_slice(&self.state.to_le_bytes()[..self.size]);
+        Ok(())
+    }
+}
+
+impl ParamsInit for ParamStub {
+    fn new_with_params(
+        output_size: usize,
+        salt: &[u8],
+        personal: &[u8],
+    ) -> Result<Self, InvalidOutputSize> {
+        if output_size == 0 || output_size > 8 || salt.len() > 8 || personal.len() > 8 {
+            return Err(InvalidOutputSize);
+        }
+        let mut hasher = Self {
+            state: 0xcbf2_9ce4_8422_2325,
+            size: output_size,
+        };
+        let mut block = [0u8; 17];
+        block[0] = output_size as u8;
+        block[1..1 + salt.len()].copy_from_slice(salt);
+        block[9..9 + personal.len()].copy_from_slice(personal);
+        hasher.update(&block);
+        Ok(hasher)
+    }
+}
+
+fn param_hash(salt: &[u8], personal: &[u8]) -> [u8; 8] {
+    let mut hasher = ParamStub::new_with_params(8, salt, personal).unwrap();
+    hasher.update(b"message");
+    let mut out = [0u8; 8];
+    hasher.finalize_variable(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn params_init() {
+    let default = param_hash(&[], &[]);
+    let mut out = [0u8; 8];
+    let mut hasher = ParamStub::new(8).unwrap();
+    hasher.update(b"message");
+    hasher.finalize_variable(&mut out).unwrap();
+    assert_eq!(out, default);
+
+    let salted = param_hash(b"salt", &[]);
+    let personal = param_hash(&[], b"app-v1");
+    let both = param_hash(b"salt", b"app-v1");
+    let outputs = [default, salted, personal, both, param_hash(b"app-v1", &[])];
+    for (i, a) in outputs.iter().enumerate() {
+        for b in &outputs[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+    assert_eq!(param_hash(b"salt", b"app-v1"), both);
+
+    assert!(ParamStub::new_with_params(8, &[0; 9], &[]).is_err());
+    assert!(ParamStub::new_with_params(9, &[], &[]).is_err());
+}
+
+#[cfg(feature = "alloc")]
+mod var_as_xof {
+    use super::ParamStub;
+    use digest::{ExtendableOutput, Update, VarAsXof, VariableOutput, XofReader};
+
+    fn expected() -> Box<[u8]> {
+        let mut hasher = ParamStub::new(ParamStub::MAX_OUTPUT_SIZE).unwrap();
+        hasher.update(b"message");
+        hasher.finalize_boxed()
+    }
+
+    /// Code which knows only the `ExtendableOutput` interface.
+    fn xof_output<X: ExtendableOutput + Default>(data: &[u8], out: &mut [u8]) {
+        let mut xof = X::default();
+        xof.update(data);
+        xof.finalize_xof_into(out);
+    }
+
+    #[test]
+    fn reads_match_finalize_boxed() {
+        let expected = expected();
+        let mut out = [0u8; 8];
+        xof_output::<VarAsXof<ParamStub>>(b"message", &mut out);
+        assert_eq!(out[..], expected[..]);
+
+        let mut xof = VarAsXof::<ParamStub>::new();
+        xof.update(b"mess");
+        xof.update(b"age");
+        let mut reader = xof.finalize_xof();
+        assert_eq!(reader.remaining(), 8);
+        let (mut a, mut b) = ([0u8; 3], [0u8; 5]);
+        reader.read(&mut a);
+        reader.read(&mut []);
+        reader.read(&mut b);
+        assert_eq!(reader.remaining(), 0);
+        assert_eq!(a[..], expected[..3]);
+        assert_eq!(b[..], expected[3..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "read beyond maximum output size")]
+    fn read_beyond_max() {
+        let mut reader = VarAsXof::<ParamStub>::new().finalize_xof();
+        let mut out = [0u8; 6];
+        reader.read(&mut out);
+        reader.read(&mut out);
+    }
+}
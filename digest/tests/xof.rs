@@ -0,0 +1,447 @@
+//! Tests for the XOF traits and wrappers.
+#![cfg(feature = "core-api")]
+
+mod common;
+
+use common::mix;
+use digest::{
+    consts::U8,
+    core_api::{
+        Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, ExtendableOutputCore,
+        UpdateCore, XofReaderCore,
+    },
+    ExtendableOutput, HashMarker, Update, XofReader,
+};
+
+/// Toy XOF core. NOT cryptographically secure.
+#[derive(Clone, Default)]
+struct ToyXofCore {
+    state: u64,
+}
+
+impl HashMarker for ToyXofCore {}
+
+impl BlockSizeUser for ToyXofCore {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for ToyXofCore {
+    type BufferKind = digest::block_buffer::Eager;
+}
+
+impl UpdateCore for ToyXofCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(block);
+            self.state = mix(self.state ^ u64::from_le_bytes(b));
+        }
+    }
+}
+
+impl ExtendableOutputCore for ToyXofCore {
+    type ReaderCore = ToyXofReaderCore;
+
+    fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+        let pos = buffer.get_pos() as u8;
+        let block = buffer.pad_with_zeros();
+        block[7] = pos;
+        self.update_blocks(core::slice::from_ref(block));
+        ToyXofReaderCore {
+            state: self.state,
+            counter: 0,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ToyXofReaderCore {
+    state: u64,
+    counter: u64,
+}
+
+impl BlockSizeUser for ToyXofReaderCore {
+    type BlockSize = U8;
+}
+
+impl XofReaderCore for ToyXofReaderCore {
+    fn read_block(&mut self) -> Block<Self> {
+        self.counter += 1;
+        mix(self.state ^ self.counter).to_le_bytes().into()
+    }
+
+    fn skip_blocks(&mut self, n: u64) {
+        self.counter += n;
+    }
+}
+
+type ToyXof = CoreWrapper<ToyXofCore>;
+
+fn reader(seed: &[u8]) -> <ToyXof as ExtendableOutput>::Reader {
+    let mut xof = ToyXof::default();
+    xof.update(seed);
+    xof.finalize_xof()
+}
+
+#[test]
+fn fork_produces_identical_streams() {
+    let mut reader = reader(b"seed");
+    let mut skip = [0u8; 13];
+    reader.read(&mut skip);
+
+    let mut fork1 = reader.fork();
+    let mut fork2 = reader.fork();
+    let (mut a, mut b, mut c) = ([0u8; 37], [0u8; 37], [0u8; 37]);
+    fork1.read(&mut a);
+    fork2.read(&mut b);
+    reader.read(&mut c);
+    assert_eq!(a[..], b[..]);
+    assert_eq!(a[..], c[..]);
+}
+
+#[test]
+fn forks_diverge_only_when_advanced() {
+    let mut reader = reader(b"seed");
+    let mut fork = reader.fork();
+
+    let mut skip = [0u8; 5];
+    fork.read(&mut skip);
+
+    let (mut a, mut b) = ([0u8; 16], [0u8; 16]);
+    fork.read(&mut a);
+    reader.read(&mut b);
+    assert_ne!(a, b);
+
+    // output of the advanced fork is a suffix of the original stream
+    let mut full = [0u8; 21];
+    self::reader(b"seed").read(&mut full);
+    assert_eq!(&full[..16], &b[..]);
+    assert_eq!(&full[5..], &a[..]);
+}
+
+/// Reader which relies on the default `XofReader::skip` implementation.
+struct ByteCounterReader(u8);
+
+impl XofReader for ByteCounterReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        for b in buffer {
+            *b = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+}
+
+#[test]
+fn default_skip() {
+    let mut reader = ByteCounterReader(0);
+    reader.skip(0);
+    reader.skip(1000);
+    let mut buf = [0u8; 3];
+    reader.read(&mut buf);
+    assert_eq!(buf, [232, 233, 234]);
+}
+
+#[test]
+fn finalize_xof_at_ranges_stitch() {
+    let seed = b"range stitching";
+    let mut full = [0u8; 200];
+    let mut xof = ToyXof::default();
+    xof.update(seed);
+    xof.finalize_xof_into(&mut full);
+
+    for &(start, end) in &[
+        (0, 0),
+        (0, 5),
+        (3, 8),
+        (5, 16),
+        (8, 24),
+        (7, 200),
+        (100, 101),
+    ] {
+        let mut xof = ToyXof::default();
+        xof.update(seed);
+        let mut buf = [0u8; 200];
+        xof.finalize_xof_at(start as u64, &mut buf[..end - start]);
+        assert_eq!(
+            &buf[..end - start],
+            &full[start..end],
+            "range {}..{}",
+            start,
+            end
+        );
+    }
+
+    // stitching several ranges together equals a single long read
+    let mut stitched = [0u8; 200];
+    let bounds = [0, 1, 9, 16, 17, 63, 64, 150, 200];
+    for w in bounds.windows(2) {
+        let mut xof = ToyXof::default();
+        xof.update(seed);
+        xof.finalize_xof_at(w[0] as u64, &mut stitched[w[0]..w[1]]);
+    }
+    assert_eq!(&stitched[..], &full[..]);
+}
+
+#[test]
+fn wrapper_skip_after_partial_read() {
+    let mut full = [0u8; 64];
+    reader(b"skip").read(&mut full);
+
+    for &(first, skip) in &[(1, 0), (1, 3), (3, 5), (3, 6), (7, 9), (8, 8), (2, 30)] {
+        let mut r = reader(b"skip");
+        let mut buf = [0u8; 8];
+        r.read(&mut buf[..first]);
+        r.skip(skip as u64);
+        r.read(&mut buf);
+        let start = first + skip;
+        assert_eq!(
+            &buf[..],
+            &full[start..start + 8],
+            "read {}, skip {}",
+            first,
+            skip
+        );
+    }
+}
+
+/// Reader which outputs bytes `(37 * i + 11) mod 256`.
+struct PatternReader(usize);
+
+impl XofReader for PatternReader {
+    fn read(&mut self, buf: &mut [u8]) {
+        for b in buf {
+            *b = (self.0 * 37 + 11) as u8;
+            self.0 += 1;
+        }
+    }
+}
+
+#[test]
+fn shuffle_kat() {
+    let mut v: Vec<u32> = (0..10).collect();
+    digest::shuffle(&mut PatternReader(0), &mut v);
+    assert_eq!(v, [7, 6, 8, 9, 3, 1, 4, 2, 5, 0]);
+}
+
+#[test]
+fn shuffle_uses_next_below() {
+    // candidates for `0..3` are masked to 2 bits, so 3 is rejected
+    let mut v = [0u8, 1, 2];
+    let mut r = ByteReader {
+        data: &[0xff, 0x02, 0x01],
+        reads: vec![],
+    };
+    digest::shuffle(&mut r, &mut v);
+    assert_eq!(r.reads, [1, 1, 1]);
+    assert_eq!(v, [0, 1, 2]);
+
+    let mut v = [0u8, 1, 2];
+    let mut r = ByteReader {
+        data: &[0x00, 0x00],
+        reads: vec![],
+    };
+    digest::shuffle(&mut r, &mut v);
+    assert_eq!(v, [1, 2, 0]);
+}
+
+#[test]
+fn shuffle_is_deterministic_permutation() {
+    let seeded = |seed: &[u8]| {
+        let mut xof = CoreWrapper::<ToyXofCore>::default();
+        xof.update(seed);
+        xof.finalize_xof()
+    };
+
+    let mut a: Vec<u16> = (0..1000).collect();
+    let mut b = a.clone();
+    let mut c = a.clone();
+    digest::shuffle(&mut seeded(b"seed"), &mut a);
+    digest::shuffle(&mut seeded(b"seed"), &mut b);
+    digest::shuffle(&mut seeded(b"other seed"), &mut c);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    assert_ne!(a, (0..1000).collect::<Vec<u16>>());
+    a.sort_unstable();
+    assert_eq!(a, (0..1000).collect::<Vec<u16>>());
+
+    let mut empty: [u8; 0] = [];
+    digest::shuffle(&mut seeded(b""), &mut empty);
+    let mut one = [42u8];
+    digest::shuffle(&mut seeded(b""), &mut one);
+    assert_eq!(one, [42]);
+}
+
+/// Reader which outputs provided bytes and records lengths of reads.
+struct ByteReader<'a> {
+    data: &'a [u8],
+    reads: Vec<usize>,
+}
+
+impl<'a> XofReader for ByteReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) {
+        let (head, tail) = self.data.split_at(buf.len());
+        buf.copy_from_slice(head);
+        self.data = tail;
+        self.reads.push(buf.len());
+    }
+}
+
+#[test]
+fn next_below_minimal_bytes() {
+    let mut r = ByteReader {
+        data: &[],
+        reads: vec![],
+    };
+    assert_eq!(digest::next_below(&mut r, 1), 0);
+    assert!(r.reads.is_empty());
+
+    // candidates are masked to 3 bits, 6 and 7 are rejected
+    let mut r = ByteReader {
+        data: &[0xfe, 0xff, 0x0d],
+        reads: vec![],
+    };
+    assert_eq!(digest::next_below(&mut r, 6), 5);
+    assert_eq!(r.reads, [1, 1, 1]);
+
+    let mut r = ByteReader {
+        data: &[0x34, 0x12, 0x00],
+        reads: vec![],
+    };
+    assert_eq!(digest::next_below(&mut r, 0x1_0000), 0x1234);
+    assert_eq!(digest::next_below(&mut r, 2), 0);
+    assert_eq!(r.reads, [2, 1]);
+
+    // 2^64 - 1 is the only rejected candidate
+    let mut data = [0xffu8; 16];
+    data[8] = 0x2a;
+    let mut r = ByteReader {
+        data: &data,
+        reads: vec![],
+    };
+    assert_eq!(digest::next_below(&mut r, !0), 0xffff_ffff_ffff_ff2a);
+    assert_eq!(r.reads, [8, 8]);
+}
+
+#[test]
+fn next_below_is_deterministic_and_unbiased() {
+    let draws = |seed: &[u8]| -> Vec<u64> {
+        let mut r = reader(seed);
+        (0..6000).map(|_| digest::next_below(&mut r, 6)).collect()
+    };
+    let a = draws(b"lottery seed");
+    assert_eq!(a, draws(b"lottery seed"));
+    assert_ne!(a, draws(b"other seed"));
+
+    // chi-squared test with 5 degrees of freedom, p = 0.001
+    let mut counts = [0u32; 6];
+    for &x in &a {
+        counts[x as usize] += 1;
+    }
+    let chi2: f64 = counts
+        .iter()
+        .map(|&c| (f64::from(c) - 1000.0).powi(2) / 1000.0)
+        .sum();
+    assert!(chi2 < 20.5, "counts: {:?}", counts);
+}
+
+#[test]
+#[should_panic]
+fn next_below_zero() {
+    digest::next_below(&mut PatternReader(0), 0);
+}
+
+#[test]
+fn keystream_round_trip() {
+    let msg: Vec<u8> = (0..300u32).map(|i| (i * 7) as u8).collect();
+    let mut data = msg.clone();
+    digest::apply_keystream(&mut reader(b"key and nonce"), &mut data);
+    assert_ne!(data, msg);
+    digest::apply_keystream(&mut reader(b"key and nonce"), &mut data);
+    assert_eq!(data, msg);
+}
+
+#[test]
+fn keystream_matches_read() {
+    let msg: Vec<u8> = (0..300u32).map(|i| (i * 7) as u8).collect();
+    let mut ks = vec![0u8; msg.len()];
+    reader(b"seed").read(&mut ks);
+    let expected: Vec<u8> = msg.iter().zip(ks.iter()).map(|(m, k)| m ^ k).collect();
+
+    // split into parts to check that the reader is advanced correctly
+    let mut data = msg.clone();
+    let mut r = reader(b"seed");
+    let (head, tail) = data.split_at_mut(13);
+    digest::apply_keystream(&mut r, head);
+    digest::apply_keystream(&mut r, tail);
+    assert_eq!(data, expected);
+
+    let mut rest = [0u8; 8];
+    r.read(&mut rest);
+    let mut ks = vec![0u8; msg.len() + 8];
+    reader(b"seed").read(&mut ks);
+    assert_eq!(rest, ks[msg.len()..]);
+}
+
+#[test]
+fn derive_uuid() {
+    let a = digest::derive_uuid::<ToyXof>(b"namespace", b"name");
+    assert_eq!(a, digest::derive_uuid::<ToyXof>(b"namespace", b"name"));
+    assert_eq!(a[6] >> 4, 8);
+    assert_eq!(a[8] >> 6, 0b10);
+
+    // version and variant bits are the only difference from the raw output
+    let mut xof = ToyXof::default();
+    xof.update(&9u64.to_le_bytes());
+    xof.update(b"namespacename");
+    let mut raw = [0u8; 16];
+    xof.finalize_xof().read(&mut raw);
+    for (i, (&a, &r)) in a.iter().zip(raw.iter()).enumerate() {
+        let mask = match i {
+            6 => 0x0f,
+            8 => 0x3f,
+            _ => 0xff,
+        };
+        assert_eq!(a & mask, r & mask);
+    }
+
+    // namespace is separated from name
+    let b = digest::derive_uuid::<ToyXof>(b"namespacen", b"ame");
+    assert_ne!(a, b);
+    assert_ne!(a, digest::derive_uuid::<ToyXof>(b"namespace", b"other"));
+}
+
+#[test]
+fn read_hex_into() {
+    for &n in &[0, 1, 31, 32, 33, 100] {
+        let mut expected = vec![0u8; n];
+        PatternReader(0).read(&mut expected);
+        let expected: String = expected.iter().map(|b| format!("{:02x}", b)).collect();
+
+        let mut s = String::new();
+        PatternReader(0).read_hex_into(&mut s, n).unwrap();
+        assert_eq!(s, expected);
+    }
+
+    // output continues after the hex dump
+    let mut reader = PatternReader(0);
+    let mut s = String::from("ks: ");
+    reader.read_hex_into(&mut s, 3).unwrap();
+    let mut next = [0u8; 1];
+    reader.read(&mut next);
+    assert_eq!(s, "ks: 0b3055");
+    assert_eq!(next, [0x7a]);
+}
+
+#[test]
+fn read_hex_into_error() {
+    struct Failing;
+
+    impl core::fmt::Write for Failing {
+        fn write_str(&mut self, _: &str) -> core::fmt::Result {
+            Err(core::fmt::Error)
+        }
+    }
+
+    assert!(PatternReader(0).read_hex_into(&mut Failing, 10).is_err());
+}
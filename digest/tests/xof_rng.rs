@@ -0,0 +1,58 @@
+//! Tests for the `XofRng` generator.
+#![cfg(feature = "rand_core")]
+
+mod common;
+
+use common::{ToyXof, ToyXofReader};
+use digest::{
+    rand_core::{RngCore, SeedableRng},
+    ExtendableOutput, Update, XofReader, XofRng,
+};
+
+fn reader(seed: &[u8]) -> ToyXofReader {
+    let mut xof = ToyXof::default();
+    xof.update(seed);
+    xof.finalize_xof()
+}
+
+#[test]
+fn matches_manual_reads() {
+    let mut rng = XofRng::<ToyXof>::from_xof(ToyXof::default(), b"seed");
+    let mut reader = reader(b"seed");
+
+    let mut buf = [0u8; 8];
+    reader.read(&mut buf);
+    assert_eq!(rng.next_u64(), u64::from_le_bytes(buf));
+
+    let mut buf = [0u8; 4];
+    reader.read(&mut buf);
+    assert_eq!(rng.next_u32(), u32::from_le_bytes(buf));
+
+    let mut expected = [0u8; 37];
+    reader.read(&mut expected);
+    let mut out = [0u8; 37];
+    rng.fill_bytes(&mut out);
+    assert_eq!(out[..], expected[..]);
+
+    reader.read(&mut expected);
+    rng.try_fill_bytes(&mut out).unwrap();
+    assert_eq!(out[..], expected[..]);
+}
+
+#[test]
+fn same_seed_reproduces_stream() {
+    let seed = [42u8; 32];
+    let mut a = XofRng::<ToyXof>::from_seed(seed);
+    let mut b = XofRng::<ToyXof>::from_seed(seed);
+    let mut c = XofRng::<ToyXof>::from_seed([43u8; 32]);
+    let mut d = XofRng::<ToyXof>::from_reader(reader(&seed));
+    for _ in 0..100 {
+        let x = a.next_u64();
+        assert_eq!(x, b.next_u64());
+        assert_eq!(x, d.next_u64());
+        assert_ne!(x, c.next_u64());
+    }
+
+    let mut e = a.clone();
+    assert_eq!(a.next_u32(), e.next_u32());
+}
@@ -0,0 +1,37 @@
+//! Tests for the `ZeroizingOutput` wrapper.
+#![cfg(feature = "zeroize")]
+
+use core::mem::{size_of, MaybeUninit};
+use digest::{Digest, ZeroizingOutput};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+#[test]
+fn derefs_to_output() {
+    let out = ZeroizingOutput::<Sha256>::from(Sha256::digest(b"abc"));
+    assert_eq!(*out, Sha256::digest(b"abc"));
+    assert_eq!(out.as_ref(), &Sha256::digest(b"abc")[..]);
+    assert_eq!(out.clone()[..], out[..]);
+    assert_eq!(format!("{:?}", out), "ZeroizingOutput { .. }");
+}
+
+#[test]
+fn zeroize_clears_bytes() {
+    let mut out = ZeroizingOutput::<Sha256>::new(Sha256::digest(b"abc"));
+    out.zeroize();
+    assert!(out.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn drop_clears_bytes() {
+    let mut slot = MaybeUninit::new(ZeroizingOutput::<Sha256>::new(Sha256::digest(b"abc")));
+    let ptr = slot.as_mut_ptr();
+    // SAFETY: `slot` is initialized and is not used as a `ZeroizingOutput`
+    // after being dropped, only its raw memory is inspected.
+    let bytes = unsafe {
+        core::ptr::drop_in_place(ptr);
+        core::slice::from_raw_parts(ptr as *const u8, size_of::<ZeroizingOutput<Sha256>>())
+    };
+    assert_eq!(bytes.len(), 32);
+    assert!(bytes.iter().all(|&b| b == 0));
+}
@@ -108,6 +108,7 @@ pub use {
         arithmetic::{
             AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
         },
+        point::{decompress_y, WeierstrassCoefficients},
         public_key::PublicKey,
         scalar::{nonzero::NonZeroScalar, Scalar},
     },
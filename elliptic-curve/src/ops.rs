@@ -6,7 +6,19 @@ use crypto_bigint::{ArrayEncoding, ByteArray, Integer};
 use subtle::CtOption;
 
 #[cfg(feature = "arithmetic")]
-use group::Group;
+use {generic_array::typenum::Unsigned, group::Group};
+
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+use {alloc::vec::Vec, subtle::Choice};
+
+#[cfg(feature = "digest")]
+use {
+    crate::{Curve, FieldBytes},
+    crypto_bigint::CheckedSub,
+    digest::{KeyInit, Mac, Output},
+    subtle::{ConstantTimeEq, ConstantTimeLess},
+    zeroize::Zeroize,
+};
 
 /// Perform an inversion on a field element (i.e. base field element or scalar)
 pub trait Invert {
@@ -15,6 +27,60 @@ pub trait Invert {
 
     /// Invert a field element.
     fn invert(&self) -> CtOption<Self::Output>;
+
+    /// Invert a field element, returning zero if the element is zero.
+    ///
+    /// The result is selected in constant time, which is convenient in
+    /// formulas which handle zero inputs without branching.
+    #[cfg(feature = "arithmetic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+    fn invert_or_zero(&self) -> Self::Output
+    where
+        Self::Output: ff::Field,
+    {
+        self.invert().unwrap_or(<Self::Output as ff::Field>::zero())
+    }
+
+    /// Invert every non-zero element of `elements` in place.
+    ///
+    /// Returns a [`Choice`] for every element, which is truthy if the element
+    /// was inverted and falsy if it's zero. Zero elements are left unchanged.
+    ///
+    /// Non-zero elements are inverted using Montgomery's trick, i.e. with
+    /// a single field inversion and about `3 * n` multiplications. Processing
+    /// is constant-time with respect to values of the elements, including
+    /// positions of zeros.
+    #[cfg(all(feature = "alloc", feature = "arithmetic"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "arithmetic"))))]
+    fn batch_invert_fallible(elements: &mut [Self]) -> Vec<Choice>
+    where
+        Self: ff::Field,
+    {
+        let one = <Self as ff::Field>::one();
+        let mut choices = Vec::with_capacity(elements.len());
+        let mut products = Vec::with_capacity(elements.len());
+
+        // `products[i]` is the product of non-zero elements before `i`,
+        // zeros are replaced with one
+        let mut acc = one;
+        for e in elements.iter() {
+            let is_zero = ff::Field::is_zero(e);
+            choices.push(!is_zero);
+            products.push(acc);
+            acc *= Self::conditional_select(e, &one, is_zero);
+        }
+
+        // `acc` is never zero, so the inversion always succeeds
+        let mut inv = ff::Field::invert(&acc).unwrap();
+        for ((e, product), choice) in elements.iter_mut().zip(products).zip(&choices).rev() {
+            let e_or_one = Self::conditional_select(&one, e, *choice);
+            let e_inv = inv * product;
+            inv *= e_or_one;
+            e.conditional_assign(&e_inv, *choice);
+        }
+
+        choices
+    }
 }
 
 #[cfg(feature = "arithmetic")]
@@ -39,6 +105,17 @@ pub trait LinearCombination: Group {
     fn lincomb(x: &Self, k: &Self::Scalar, y: &Self, l: &Self::Scalar) -> Self {
         (*x * k) + (*y * l)
     }
+
+    /// Calculates `x1 * k1 + ... + xN * kN` for a fixed number of terms.
+    ///
+    /// Implementations may override this method to provide optimized
+    /// versions (e.g. unrolled and allocation-free) for common small `N`.
+    /// The default implementation sums the terms one by one.
+    fn lincomb_array<const N: usize>(pairs: &[(Self, Self::Scalar); N]) -> Self {
+        pairs
+            .iter()
+            .fold(Self::identity(), |acc, (x, k)| acc + (*x * k))
+    }
 }
 
 /// Modular reduction.
@@ -57,6 +134,38 @@ pub trait Reduce<UInt: Integer + ArrayEncoding>: Sized {
     fn from_le_bytes_reduced(bytes: ByteArray<UInt>) -> Self {
         Self::from_uint_reduced(UInt::from_le_byte_array(bytes))
     }
+
+    /// Interpret a byte slice of arbitrary length as a big endian integer
+    /// and perform a modular reduction.
+    ///
+    /// Inputs longer than `UInt` are folded in `UInt`-sized chunks using
+    /// Horner's method, which makes this method suitable for reducing
+    /// variable-length hash (e.g. XOF) outputs. Processing is constant-time
+    /// with respect to the value of the input, but not its length.
+    #[cfg(feature = "arithmetic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+    fn from_slice_reduced(bytes: &[u8]) -> Self
+    where
+        Self: ff::Field,
+    {
+        let n = UInt::ByteSize::USIZE;
+
+        // 2^(8 * n) mod modulus
+        let mut max = ByteArray::<UInt>::default();
+        max.iter_mut().for_each(|b| *b = 0xff);
+        let one = <Self as ff::Field>::one();
+        let shift = <Self as Reduce<UInt>>::from_be_bytes_reduced(max) + one;
+
+        let (head, tail) = bytes.split_at(bytes.len() % n);
+        let mut chunk = ByteArray::<UInt>::default();
+        chunk[n - head.len()..].copy_from_slice(head);
+        let mut acc = <Self as Reduce<UInt>>::from_be_bytes_reduced(chunk);
+        for chunk in tail.chunks_exact(n) {
+            let chunk = ByteArray::<UInt>::clone_from_slice(chunk);
+            acc = acc * shift + <Self as Reduce<UInt>>::from_be_bytes_reduced(chunk);
+        }
+        acc
+    }
 }
 
 /// Modular reduction to a non-zero output.
@@ -68,3 +177,107 @@ pub trait ReduceNonZero<UInt: Integer + ArrayEncoding>: Sized {
     /// Perform a modular reduction, returning a field element.
     fn from_uint_reduced_nonzero(n: UInt) -> Self;
 }
+
+/// Derive a deterministic nonce `k` as specified in [RFC 6979 § 3.2].
+///
+/// `M` is the HMAC instance used by the HMAC_DRBG, e.g. `Hmac<Sha256>`
+/// from the `hmac` crate. `secret` is the serialized secret scalar `x`,
+/// `msg_hash` is the message digest `H(m)` and `extra` is the optional
+/// additional data `k'` described in [RFC 6979 § 3.6] (pass an empty slice
+/// to get the vanilla nonce).
+///
+/// The digest is converted into an integer using the `bits2int` procedure,
+/// i.e. it's truncated to the field size if it's longer. It's assumed that
+/// bit length of the curve order is equal to `8 * FieldSize<C>`, which is
+/// the case for the standard NIST and SEC curves except P-521.
+///
+/// [RFC 6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3.2
+/// [RFC 6979 § 3.6]: https://tools.ietf.org/html/rfc6979#section-3.6
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub fn rfc6979_nonce<C, M, S>(secret: &FieldBytes<C>, msg_hash: &[u8], extra: &[u8]) -> S
+where
+    C: Curve,
+    M: Mac + KeyInit,
+    S: Reduce<C::UInt>,
+{
+    // bits2octets(H(m)): take the leftmost bits and reduce them modulo the
+    // order, a single subtraction is enough since the value is below `2q`
+    let mut h1 = FieldBytes::<C>::default();
+    let n = core::cmp::min(h1.len(), msg_hash.len());
+    let offset = h1.len() - n;
+    h1[offset..].copy_from_slice(&msg_hash[..n]);
+    let z = C::UInt::from_be_byte_array(h1);
+    let z = z.checked_sub(&C::ORDER).unwrap_or(z);
+    let h1 = z.to_be_byte_array();
+
+    let mut drbg = HmacDrbg::<M>::new(secret, &h1, extra);
+    let mut t = FieldBytes::<C>::default();
+    loop {
+        drbg.fill_bytes(&mut t);
+        let k = C::UInt::from_be_byte_array(t.clone());
+        let valid = !k.ct_eq(&C::UInt::default()) & k.ct_lt(&C::ORDER);
+        if bool::from(valid) {
+            return S::from_uint_reduced(k);
+        }
+    }
+}
+
+/// HMAC_DRBG as used by [RFC 6979 § 3.2] steps b-h.
+///
+/// [RFC 6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3.2
+#[cfg(feature = "digest")]
+struct HmacDrbg<M: Mac + KeyInit> {
+    k: Output<M>,
+    v: Output<M>,
+}
+
+#[cfg(feature = "digest")]
+impl<M: Mac + KeyInit> HmacDrbg<M> {
+    fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        let k = Output::<M>::default();
+        let mut v = Output::<M>::default();
+        v.iter_mut().for_each(|b| *b = 0x01);
+        let mut drbg = Self { k, v };
+        drbg.update_round(0x00, &[entropy, nonce, personalization]);
+        drbg.update_round(0x01, &[entropy, nonce, personalization]);
+        drbg
+    }
+
+    fn fill_bytes(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(self.v.len()) {
+            self.next_v();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update_round(0x00, &[]);
+    }
+
+    fn update_round(&mut self, sep: u8, provided: &[&[u8]]) {
+        let mut mac = self.mac();
+        mac.update(&self.v);
+        mac.update(&[sep]);
+        for p in provided {
+            mac.update(p);
+        }
+        self.k = mac.finalize().into_bytes();
+        self.next_v();
+    }
+
+    fn next_v(&mut self) {
+        let mut mac = self.mac();
+        mac.update(&self.v);
+        self.v = mac.finalize().into_bytes();
+    }
+
+    fn mac(&self) -> M {
+        <M as Mac>::new_from_slice(&self.k).expect("HMAC accepts keys of any size")
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<M: Mac + KeyInit> Drop for HmacDrbg<M> {
+    fn drop(&mut self) {
+        self.k.as_mut_slice().zeroize();
+        self.v.as_mut_slice().zeroize();
+    }
+}
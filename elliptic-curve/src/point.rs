@@ -3,6 +3,12 @@
 use crate::{Curve, FieldBytes};
 use subtle::{Choice, CtOption};
 
+#[cfg(feature = "arithmetic")]
+use {
+    ff::{Field, PrimeField},
+    subtle::ConditionallySelectable,
+};
+
 /// Obtain the affine x-coordinate of an elliptic curve point.
 pub trait AffineXCoordinate<C: Curve> {
     /// Get the affine x-coordinate as a serialized field element.
@@ -18,6 +24,41 @@ pub trait DecompressPoint<C: Curve>: Sized {
     fn decompress(x: &FieldBytes<C>, y_is_odd: Choice) -> CtOption<Self>;
 }
 
+/// Coefficients of a short Weierstrass curve equation `y² = x³ + ax + b`.
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub trait WeierstrassCoefficients {
+    /// Base field element type.
+    type FieldElement: PrimeField;
+
+    /// Coefficient `a` of the curve equation.
+    const EQUATION_A: Self::FieldElement;
+
+    /// Coefficient `b` of the curve equation.
+    const EQUATION_B: Self::FieldElement;
+}
+
+/// Recover y-coordinate of a short Weierstrass curve point from its
+/// x-coordinate and a flag indicating whether the y-coordinate is odd.
+///
+/// Computes `y = sqrt(x³ + ax + b)` and negates it if its parity does not
+/// match `y_is_odd`. Returns none if `x` is not an x-coordinate of a curve
+/// point. The computation is performed in constant time.
+///
+/// This helper is intended for implementing [`DecompressPoint`].
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub fn decompress_y<C: WeierstrassCoefficients>(
+    x: &C::FieldElement,
+    y_is_odd: Choice,
+) -> CtOption<C::FieldElement> {
+    let rhs = x.square() * x + C::EQUATION_A * x + C::EQUATION_B;
+    rhs.sqrt().map(|y| {
+        let flip = y.is_odd() ^ y_is_odd;
+        ConditionallySelectable::conditional_select(&y, &-y, flip)
+    })
+}
+
 /// Decompact an elliptic curve point from an x-coordinate.
 ///
 /// Decompaction relies on properties of specially-generated keys but provides
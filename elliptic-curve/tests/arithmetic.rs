@@ -0,0 +1,425 @@
+//! Tests for arithmetic traits and helpers using a toy prime field.
+
+#![cfg(feature = "arithmetic")]
+
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use elliptic_curve::{
+    bigint::{ArrayEncoding, U64},
+    decompress_y,
+    ff::{Field, PrimeField},
+    group::Group,
+    ops::{Invert, LinearCombination, Reduce},
+    rand_core::RngCore,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    WeierstrassCoefficients,
+};
+use std::cell::Cell;
+
+/// Modulus of the toy prime field.
+const P: u64 = 1_000_003;
+
+thread_local! {
+    /// Number of field inversions performed by the current thread.
+    static INVERSIONS: Cell<usize> = Cell::new(0);
+}
+
+fn inversions() -> usize {
+    INVERSIONS.with(|n| n.get())
+}
+
+/// Toy prime field element. NOT constant-time.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Fe(u64);
+
+impl Fe {
+    fn pow(self, mut e: u64) -> Self {
+        let (mut base, mut res) = (self, Fe(1));
+        while e != 0 {
+            if e & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        res
+    }
+}
+
+impl Field for Fe {
+    fn random(mut rng: impl RngCore) -> Self {
+        Fe(rng.next_u64() % P)
+    }
+
+    fn zero() -> Self {
+        Fe(0)
+    }
+
+    fn one() -> Self {
+        Fe(1)
+    }
+
+    fn square(&self) -> Self {
+        *self * self
+    }
+
+    fn double(&self) -> Self {
+        *self + self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        INVERSIONS.with(|n| n.set(n.get() + 1));
+        CtOption::new(self.pow(P - 2), !self.is_zero())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // P = 3 mod 4
+        let root = self.pow((P + 1) / 4);
+        CtOption::new(root, root.square().ct_eq(self))
+    }
+}
+
+impl ConditionallySelectable for Fe {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fe(u64::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for Fe {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+macro_rules! impl_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $f:expr) => {
+        impl $op<Fe> for Fe {
+            type Output = Fe;
+
+            fn $op_fn(self, rhs: Fe) -> Fe {
+                Fe($f(self.0, rhs.0) % P)
+            }
+        }
+
+        impl $op<&Fe> for Fe {
+            type Output = Fe;
+
+            fn $op_fn(self, rhs: &Fe) -> Fe {
+                self.$op_fn(*rhs)
+            }
+        }
+
+        impl $op_assign<Fe> for Fe {
+            fn $op_assign_fn(&mut self, rhs: Fe) {
+                *self = self.$op_fn(rhs);
+            }
+        }
+
+        impl $op_assign<&Fe> for Fe {
+            fn $op_assign_fn(&mut self, rhs: &Fe) {
+                *self = self.$op_fn(*rhs);
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign, |a, b| a + b);
+impl_op!(Sub, sub, SubAssign, sub_assign, |a, b| a + P - b);
+impl_op!(Mul, mul, MulAssign, mul_assign, |a, b| a * b);
+
+impl Neg for Fe {
+    type Output = Fe;
+
+    fn neg(self) -> Fe {
+        Fe((P - self.0) % P)
+    }
+}
+
+impl From<u64> for Fe {
+    fn from(n: u64) -> Self {
+        Fe(n % P)
+    }
+}
+
+impl PrimeField for Fe {
+    type Repr = [u8; 8];
+
+    const NUM_BITS: u32 = 20;
+    const CAPACITY: u32 = 19;
+    const S: u32 = 1;
+
+    fn from_repr(repr: [u8; 8]) -> CtOption<Self> {
+        let n = u64::from_le_bytes(repr);
+        CtOption::new(Fe(n), Choice::from((n < P) as u8))
+    }
+
+    fn to_repr(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.0 & 1) as u8)
+    }
+
+    fn multiplicative_generator() -> Self {
+        Fe(2)
+    }
+
+    fn root_of_unity() -> Self {
+        Fe(P - 1)
+    }
+}
+
+/// Toy group: additive group of the toy field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Pt(Fe);
+
+impl Group for Pt {
+    type Scalar = Fe;
+
+    fn random(rng: impl RngCore) -> Self {
+        Pt(Fe::random(rng))
+    }
+
+    fn identity() -> Self {
+        Pt(Fe(0))
+    }
+
+    fn generator() -> Self {
+        Pt(Fe(1))
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    fn double(&self) -> Self {
+        Pt(self.0.double())
+    }
+}
+
+impl LinearCombination for Pt {}
+
+macro_rules! impl_pt_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $rhs:ty, $f:expr) => {
+        impl $op<$rhs> for Pt {
+            type Output = Pt;
+
+            fn $op_fn(self, rhs: $rhs) -> Pt {
+                Pt($f(self.0, rhs))
+            }
+        }
+
+        impl $op<&$rhs> for Pt {
+            type Output = Pt;
+
+            fn $op_fn(self, rhs: &$rhs) -> Pt {
+                self.$op_fn(*rhs)
+            }
+        }
+
+        impl $op_assign<$rhs> for Pt {
+            fn $op_assign_fn(&mut self, rhs: $rhs) {
+                *self = self.$op_fn(rhs);
+            }
+        }
+
+        impl $op_assign<&$rhs> for Pt {
+            fn $op_assign_fn(&mut self, rhs: &$rhs) {
+                *self = self.$op_fn(*rhs);
+            }
+        }
+    };
+}
+
+impl_pt_op!(Add, add, AddAssign, add_assign, Pt, |a, b: Pt| a + b.0);
+impl_pt_op!(Sub, sub, SubAssign, sub_assign, Pt, |a, b: Pt| a - b.0);
+impl_pt_op!(Mul, mul, MulAssign, mul_assign, Fe, |a, b: Fe| a * b);
+
+impl Neg for Pt {
+    type Output = Pt;
+
+    fn neg(self) -> Pt {
+        Pt(-self.0)
+    }
+}
+
+impl Sum for Pt {
+    fn sum<I: Iterator<Item = Pt>>(iter: I) -> Pt {
+        iter.fold(Pt::identity(), |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Pt> for Pt {
+    fn sum<I: Iterator<Item = &'a Pt>>(iter: I) -> Pt {
+        iter.copied().sum()
+    }
+}
+
+impl Reduce<U64> for Fe {
+    fn from_uint_reduced(n: U64) -> Self {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&n.to_be_byte_array());
+        Fe(u64::from_be_bytes(bytes) % P)
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn batch_invert_fallible() {
+    let batches: &[&[u64]] = &[
+        &[],
+        &[0],
+        &[0, 0, 0],
+        &[7],
+        &[2, 3, 5, 7, 11],
+        &[0, 2, 0, 0, 3, 999_999, 0],
+        &[1, P - 1, 0, 123_456],
+    ];
+
+    for batch in batches {
+        let mut elements: Vec<Fe> = batch.iter().map(|&x| Fe(x)).collect();
+
+        let before = inversions();
+        let choices = Fe::batch_invert_fallible(&mut elements);
+        assert_eq!(inversions() - before, 1);
+
+        assert_eq!(choices.len(), batch.len());
+        for ((&x, e), choice) in batch.iter().zip(&elements).zip(&choices) {
+            if x == 0 {
+                assert_eq!(choice.unwrap_u8(), 0);
+                assert_eq!(*e, Fe(0));
+            } else {
+                assert_eq!(choice.unwrap_u8(), 1);
+                assert_eq!(*e * Fe(x), Fe(1));
+                assert_eq!(Some(*e), Invert::invert(&Fe(x)).into());
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn batch_invert_uses_single_inversion() {
+    let mut elements: Vec<Fe> = (0..100).map(|i| Fe(i * 37 % 11)).collect();
+    let before = inversions();
+    let choices = Fe::batch_invert_fallible(&mut elements);
+    assert_eq!(inversions() - before, 1);
+    assert_eq!(choices.iter().filter(|c| c.unwrap_u8() == 0).count(), 10);
+}
+
+#[test]
+fn invert_or_zero() {
+    assert_eq!(Fe(0).invert_or_zero(), Fe(0));
+    for &x in &[1, 2, 3, 12345, P - 1] {
+        let inv = Fe(x).invert_or_zero();
+        assert_eq!(Some(inv), Invert::invert(&Fe(x)).into());
+        assert_eq!(inv * Fe(x), Fe(1));
+    }
+}
+
+/// Reference reduction performed byte-by-byte.
+fn reduce_ref(bytes: &[u8]) -> Fe {
+    Fe(bytes.iter().fold(0, |acc, &b| (acc * 256 + b as u64) % P))
+}
+
+#[test]
+fn from_slice_reduced() {
+    let data: Vec<u8> = (0..100u32).map(|i| (i * 151 + 7) as u8).collect();
+    for len in 0..data.len() {
+        let bytes = &data[..len];
+        assert_eq!(
+            Fe::from_slice_reduced(bytes),
+            reduce_ref(bytes),
+            "len {}",
+            len
+        );
+
+        let ones = vec![0xff; len];
+        assert_eq!(
+            Fe::from_slice_reduced(&ones),
+            reduce_ref(&ones),
+            "len {}",
+            len
+        );
+    }
+
+    // agrees with the fixed-size method
+    let bytes = data[..8].iter().copied().collect();
+    assert_eq!(
+        Fe::from_slice_reduced(&data[..8]),
+        Fe::from_be_bytes_reduced(bytes)
+    );
+}
+
+#[test]
+fn lincomb_array() {
+    let (x, k) = ([3, 10, 999_999, 42], [7, P - 1, 12345, 0]);
+    let pairs: Vec<(Pt, Fe)> = x
+        .iter()
+        .zip(&k)
+        .map(|(&x, &k)| (Pt(Fe(x)), Fe(k)))
+        .collect();
+    let naive = |n: usize| {
+        let sum = (0..n).fold(0, |acc, i| (acc + x[i] * k[i]) % P);
+        Pt(Fe(sum))
+    };
+
+    assert_eq!(Pt::lincomb_array(&[pairs[0]]), naive(1));
+    assert_eq!(Pt::lincomb_array(&[pairs[0], pairs[1]]), naive(2));
+    assert_eq!(Pt::lincomb_array(&[pairs[0], pairs[1], pairs[2]]), naive(3));
+    assert_eq!(
+        Pt::lincomb_array(&[pairs[0], pairs[1], pairs[2], pairs[3]]),
+        naive(4)
+    );
+    assert_eq!(
+        Pt::lincomb(&pairs[0].0, &pairs[0].1, &pairs[1].0, &pairs[1].1),
+        naive(2)
+    );
+    assert_eq!(Pt::lincomb_array(&[]), Pt::identity());
+}
+
+/// Toy curve `y² = x³ + 3x + 5` over the toy field.
+struct ToyCurve;
+
+impl WeierstrassCoefficients for ToyCurve {
+    type FieldElement = Fe;
+
+    const EQUATION_A: Fe = Fe(3);
+    const EQUATION_B: Fe = Fe(5);
+}
+
+#[test]
+fn decompress_y_known_points() {
+    for &(x, y) in &[(1, 3), (4, 9), (11, 37), (2, 927_878)] {
+        let odd = Choice::from((y & 1) as u8);
+        let even = !odd;
+        assert_eq!(decompress_y::<ToyCurve>(&Fe(x), odd).unwrap(), Fe(y));
+        assert_eq!(decompress_y::<ToyCurve>(&Fe(x), even).unwrap(), Fe(P - y));
+    }
+
+    // 3³ + 3 * 3 + 5 = 41 is not a square
+    assert!(bool::from(
+        decompress_y::<ToyCurve>(&Fe(3), Choice::from(0)).is_none()
+    ));
+}
+
+#[test]
+fn decompress_y_round_trip() {
+    let mut points = 0;
+    for x in 0..1000 {
+        let x = Fe(x);
+        let rhs = x.square() * x + Fe(3) * x + Fe(5);
+        let y = match Option::<Fe>::from(rhs.sqrt()) {
+            Some(y) => y,
+            None => continue,
+        };
+        for &y in &[y, -y] {
+            // compress to (x, y_is_odd) and decompress
+            let y_is_odd = y.is_odd();
+            assert_eq!(decompress_y::<ToyCurve>(&x, y_is_odd).unwrap(), y);
+        }
+        points += 1;
+    }
+    assert!(points > 400);
+}
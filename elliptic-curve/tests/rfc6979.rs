@@ -0,0 +1,58 @@
+//! Deterministic nonce tests using the RFC 6979 test vectors for P-256.
+
+#![cfg(all(feature = "dev", feature = "digest"))]
+
+use elliptic_curve::{
+    dev::{MockCurve, Scalar},
+    ff::PrimeField,
+    ops::rfc6979_nonce,
+};
+use hex_literal::hex;
+use hmac::{
+    digest::{core_api::BlockSizeUser, Digest},
+    SimpleHmac,
+};
+use sha2::{Sha256, Sha512};
+
+/// Secret key from RFC 6979 Appendix A.2.5.
+const SECRET: [u8; 32] = hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+fn nonce<D: Digest + BlockSizeUser>(msg: &[u8], extra: &[u8]) -> [u8; 32] {
+    let k: Scalar =
+        rfc6979_nonce::<MockCurve, SimpleHmac<D>, _>(&SECRET.into(), &D::digest(msg), extra);
+    k.to_repr().into()
+}
+
+#[test]
+fn p256_sha256() {
+    assert_eq!(
+        nonce::<Sha256>(b"sample", b""),
+        hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60")
+    );
+    assert_eq!(
+        nonce::<Sha256>(b"test", b""),
+        hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0")
+    );
+}
+
+#[test]
+fn p256_sha512_truncated() {
+    assert_eq!(
+        nonce::<Sha512>(b"sample", b""),
+        hex!("5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5")
+    );
+    assert_eq!(
+        nonce::<Sha512>(b"test", b""),
+        hex!("6915D11632ACA3C40D5D51C08DAF9C555933819548784480E93499000D9F0B7F")
+    );
+}
+
+#[test]
+fn extra_data() {
+    let k = nonce::<Sha256>(b"sample", b"");
+    let k1 = nonce::<Sha256>(b"sample", b"extra entropy");
+    let k2 = nonce::<Sha256>(b"sample", b"other entropy");
+    assert_ne!(k, k1);
+    assert_ne!(k1, k2);
+    assert_eq!(k1, nonce::<Sha256>(b"sample", b"extra entropy"));
+}